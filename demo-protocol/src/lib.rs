@@ -0,0 +1,83 @@
+//! The messages `demo-server` and the frontend exchange, shared so both ends agree on them.
+//!
+//! They travel as JSON: over the chat WebSocket, and as the data of the metrics events.
+
+use serde::{Deserialize, Serialize};
+
+/// What a client sends over the chat WebSocket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatRequest {
+    Say { text: String },
+}
+
+/// What the server sends over the chat WebSocket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatEvent {
+    Said {
+        /// Unique per server run, so a client can tell replayed messages from new ones.
+        id: u64,
+        from: String,
+        text: String,
+        /// Milliseconds since the Unix epoch.
+        at_ms: u64,
+    },
+}
+
+/// One second of server metrics, the data of a `metrics` event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metrics {
+    pub cpu_percent: f64,
+    pub memory_mb: f64,
+    pub requests_per_second: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn chat_requests_are_tagged() {
+        let request = ChatRequest::Say {
+            text: "hi".to_string(),
+        };
+        let value = json!({ "type": "say", "text": "hi" });
+        assert_eq!(serde_json::to_value(&request).unwrap(), value);
+        assert_eq!(
+            serde_json::from_value::<ChatRequest>(value).unwrap(),
+            request
+        );
+    }
+
+    #[test]
+    fn chat_events_are_tagged() {
+        let event = ChatEvent::Said {
+            id: 7,
+            from: "alice".to_string(),
+            text: "hi".to_string(),
+            at_ms: 1_700_000_000_000,
+        };
+        let value = json!({
+            "type": "said",
+            "id": 7,
+            "from": "alice",
+            "text": "hi",
+            "at_ms": 1_700_000_000_000u64,
+        });
+        assert_eq!(serde_json::to_value(&event).unwrap(), value);
+        assert_eq!(serde_json::from_value::<ChatEvent>(value).unwrap(), event);
+    }
+
+    #[test]
+    fn metrics_round_trip() {
+        let metrics = Metrics {
+            cpu_percent: 42.5,
+            memory_mb: 512.0,
+            requests_per_second: 200,
+        };
+        let json = serde_json::to_string(&metrics).unwrap();
+        assert_eq!(serde_json::from_str::<Metrics>(&json).unwrap(), metrics);
+    }
+}
@@ -0,0 +1,453 @@
+//! A small local backend for the frontend demos.
+//!
+//! `GET /chat/:contact` upgrades to a WebSocket chat with that contact, who answers every
+//! message. Everyone connected to the same contact shares the conversation, so open it in two
+//! tabs to chat with yourself.
+//!
+//! `GET /events/metrics` is a stream of Server-Sent Events with made up server metrics, one a
+//! second. A client reconnecting with the id of the last event it got gets the ones it missed.
+//!
+//! Trunk proxies `/chat` and `/events` here, see `frontend/Trunk.toml`.
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use demo_protocol::{ChatEvent, ChatRequest, Metrics};
+use futures_util::stream::{self, Stream};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:3010";
+/// Messages a newcomer gets to catch up with the conversation.
+const HISTORY_LEN: usize = 50;
+/// How long a contact "types" before answering.
+const REPLY_DELAY: Duration = Duration::from_millis(700);
+/// Seconds of metrics a reconnecting client can catch up on.
+const METRICS_BACKLOG: u64 = 60;
+/// Seconds of metrics sent per response. The stream then ends, as it would behind a proxy with a
+/// timeout, and the browser reconnects with the id of the last event it got.
+const METRICS_PER_RESPONSE: u64 = 30;
+/// How long the browser waits before it reconnects.
+const METRICS_RETRY: Duration = Duration::from_secs(1);
+
+struct Room {
+    history: Vec<ChatEvent>,
+    sender: broadcast::Sender<ChatEvent>,
+}
+
+/// The metrics of a second, made up from the second itself, so a replay sends the same numbers
+/// again.
+fn metrics_at(second: u64) -> Metrics {
+    let t = second as f64;
+    Metrics {
+        cpu_percent: (50.0 + 30.0 * (t / 10.0).sin() + 10.0 * (t * 1.7).sin()).clamp(0.0, 100.0),
+        memory_mb: 512.0 + 64.0 * (t / 45.0).sin() + (second % 7) as f64 * 4.0,
+        requests_per_second: (200.0 + 120.0 * (t / 15.0).cos() + 40.0 * (t * 0.9).sin()) as u32,
+    }
+}
+
+#[derive(Clone)]
+struct AppState {
+    rooms: Arc<Mutex<HashMap<String, Room>>>,
+    next_id: Arc<AtomicU64>,
+    /// Metrics event `n` is for `n` seconds after this.
+    started: Instant,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        AppState {
+            rooms: Arc::default(),
+            next_id: Arc::default(),
+            started: Instant::now(),
+        }
+    }
+}
+
+impl AppState {
+    /// The conversation so far, and a receiver for what is said from now on.
+    fn join(&self, contact: &str) -> (Vec<ChatEvent>, broadcast::Receiver<ChatEvent>) {
+        let mut rooms = self.rooms.lock().expect("rooms lock poisoned");
+        let room = rooms.entry(contact.to_string()).or_insert_with(|| Room {
+            history: Vec::new(),
+            sender: broadcast::channel(HISTORY_LEN).0,
+        });
+        (room.history.clone(), room.sender.subscribe())
+    }
+
+    fn say(&self, contact: &str, from: &str, text: String) {
+        let at_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_millis() as u64);
+        let message = ChatEvent::Said {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            from: from.to_string(),
+            text,
+            at_ms,
+        };
+        let mut rooms = self.rooms.lock().expect("rooms lock poisoned");
+        if let Some(room) = rooms.get_mut(contact) {
+            if room.history.len() == HISTORY_LEN {
+                room.history.remove(0);
+            }
+            room.history.push(message.clone());
+            // nobody listening is fine, the message is in the history
+            _ = room.sender.send(message);
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ChatParams {
+    #[serde(default = "default_name")]
+    name: String,
+}
+
+fn default_name() -> String {
+    "you".to_string()
+}
+
+async fn chat(
+    upgrade: WebSocketUpgrade,
+    Path(contact): Path<String>,
+    Query(params): Query<ChatParams>,
+    State(state): State<AppState>,
+) -> Response {
+    upgrade.on_upgrade(move |socket| converse(socket, contact, params.name, state))
+}
+
+async fn converse(mut socket: WebSocket, contact: String, name: String, state: AppState) {
+    let (history, mut said) = state.join(&contact);
+    for message in history {
+        if send(&mut socket, &message).await.is_err() {
+            return;
+        }
+    }
+
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => {
+                let text = match incoming {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None | Some(Err(_)) => return,
+                    Some(Ok(_)) => continue,
+                };
+                match serde_json::from_str::<ChatRequest>(&text) {
+                    Ok(ChatRequest::Say { text }) => {
+                        state.say(&contact, &name, text.clone());
+                        let (state, contact) = (state.clone(), contact.clone());
+                        tokio::spawn(async move {
+                            tokio::time::sleep(REPLY_DELAY).await;
+                            state.say(&contact, &contact, format!("You said: {text}"));
+                        });
+                    }
+                    Err(error) => eprintln!("ignoring message {text:?}: {error}"),
+                }
+            }
+            message = said.recv() => match message {
+                Ok(message) => {
+                    if send(&mut socket, &message).await.is_err() {
+                        return;
+                    }
+                }
+                // a slow client missed some messages; it gets the rest
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+        }
+    }
+}
+
+async fn send(socket: &mut WebSocket, message: &ChatEvent) -> Result<(), axum::Error> {
+    let json = serde_json::to_string(message).expect("server messages serialize");
+    socket.send(Message::Text(json)).await
+}
+
+#[derive(Deserialize)]
+struct MetricsParams {
+    /// For clients that can't send the `Last-Event-ID` header, like a new `EventSource`.
+    last_event_id: Option<u64>,
+}
+
+async fn metrics(
+    headers: HeaderMap,
+    Query(params): Query<MetricsParams>,
+    State(state): State<AppState>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let now = state.started.elapsed().as_secs();
+    let last_seen = headers
+        .get("last-event-id")
+        .and_then(|id| id.to_str().ok()?.parse::<u64>().ok())
+        .or(params.last_event_id);
+    let first = match last_seen {
+        Some(id) if id <= now => (id + 1).max(now.saturating_sub(METRICS_BACKLOG)),
+        // a new client, or one from before a restart
+        _ => now,
+    };
+    let last = now + METRICS_PER_RESPONSE;
+
+    let started = state.started;
+    let events = stream::unfold(first, move |second| async move {
+        if second > last {
+            return None;
+        }
+        // the missed ones go out right away
+        tokio::time::sleep_until((started + Duration::from_secs(second)).into()).await;
+        let event = Event::default()
+            .event("metrics")
+            .id(second.to_string())
+            .retry(METRICS_RETRY)
+            .json_data(metrics_at(second))
+            .expect("metrics serialize");
+        Some((Ok(event), second + 1))
+    });
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
+fn app(state: AppState) -> Router {
+    Router::new()
+        .route("/chat/:contact", get(chat))
+        .route("/events/metrics", get(metrics))
+        .with_state(state)
+}
+
+#[tokio::main]
+async fn main() {
+    let address =
+        std::env::var("DEMO_SERVER_ADDRESS").unwrap_or_else(|_| DEFAULT_ADDRESS.to_string());
+    let app = app(AppState::default());
+
+    let listener = tokio::net::TcpListener::bind(&address)
+        .await
+        .unwrap_or_else(|error| panic!("cannot listen on {address}: {error}"));
+    println!("demo server listening on {address}");
+    axum::serve(listener, app).await.expect("server error");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use futures_util::{SinkExt, StreamExt};
+    use http_body_util::BodyExt;
+    use std::net::SocketAddr;
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::time::timeout;
+    use tokio_tungstenite::tungstenite;
+    use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+    use tower::ServiceExt;
+
+    const WAIT: Duration = Duration::from_secs(5);
+
+    type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    async fn serve() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app(AppState::default())).await });
+        address
+    }
+
+    async fn connect(address: SocketAddr, path: &str) -> Client {
+        let (client, _) = connect_async(format!("ws://{address}{path}"))
+            .await
+            .unwrap();
+        client
+    }
+
+    async fn say(client: &mut Client, text: &str) {
+        let request = ChatRequest::Say {
+            text: text.to_string(),
+        };
+        let json = serde_json::to_string(&request).unwrap();
+        client.send(tungstenite::Message::Text(json)).await.unwrap();
+    }
+
+    /// The sender and text of the next message.
+    async fn said(client: &mut Client) -> (u64, String, String) {
+        loop {
+            let message = timeout(WAIT, client.next())
+                .await
+                .expect("a message in time")
+                .expect("the socket open")
+                .unwrap();
+            if let tungstenite::Message::Text(json) = message {
+                let ChatEvent::Said { id, from, text, .. } = serde_json::from_str(&json).unwrap();
+                return (id, from, text);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn contacts_answer_what_is_said() {
+        let address = serve().await;
+        let mut client = connect(address, "/chat/alice?name=bob").await;
+        say(&mut client, "hi").await;
+
+        let (_, from, text) = said(&mut client).await;
+        assert_eq!((from.as_str(), text.as_str()), ("bob", "hi"));
+        let (_, from, text) = said(&mut client).await;
+        assert_eq!((from.as_str(), text.as_str()), ("alice", "You said: hi"));
+    }
+
+    #[tokio::test]
+    async fn newcomers_get_the_conversation_so_far() {
+        let address = serve().await;
+        let mut first = connect(address, "/chat/alice").await;
+        say(&mut first, "hi").await;
+        let conversation = vec![said(&mut first).await, said(&mut first).await];
+
+        let mut second = connect(address, "/chat/alice").await;
+        let replayed = vec![said(&mut second).await, said(&mut second).await];
+        assert_eq!(replayed, conversation);
+        assert_eq!(conversation[0].1, "you");
+    }
+
+    #[tokio::test]
+    async fn contacts_keep_their_conversations_apart() {
+        let address = serve().await;
+        let mut alice = connect(address, "/chat/alice").await;
+        let mut carol = connect(address, "/chat/carol").await;
+        say(&mut alice, "hi").await;
+        say(&mut carol, "hello").await;
+
+        assert_eq!(said(&mut carol).await.2, "hello");
+        assert_eq!(said(&mut carol).await.2, "You said: hello");
+    }
+
+    #[tokio::test]
+    async fn contacts_are_decoded_from_the_path() {
+        let address = serve().await;
+        let mut client = connect(address, "/chat/a%2Fb%3Fc").await;
+        say(&mut client, "hi").await;
+        said(&mut client).await;
+        assert_eq!(said(&mut client).await.1, "a/b?c");
+    }
+
+    #[tokio::test]
+    async fn messages_that_dont_parse_are_ignored() {
+        let address = serve().await;
+        let mut client = connect(address, "/chat/alice").await;
+        client
+            .send(tungstenite::Message::Text("nonsense".to_string()))
+            .await
+            .unwrap();
+        say(&mut client, "hi").await;
+        assert_eq!(said(&mut client).await.2, "hi");
+    }
+
+    /// A server that started `seconds_ago`.
+    fn started(seconds_ago: u64) -> AppState {
+        AppState {
+            started: Instant::now()
+                .checked_sub(Duration::from_secs(seconds_ago))
+                .unwrap(),
+            ..AppState::default()
+        }
+    }
+
+    /// The first `count` metrics events of a response, as their ids and data.
+    async fn metrics_events(
+        state: AppState,
+        request: Request<Body>,
+        count: usize,
+    ) -> Vec<(u64, Metrics)> {
+        let response = app(state).oneshot(request).await.unwrap();
+        let mut body = response.into_body();
+        let mut text = String::new();
+        let mut events = Vec::new();
+        while events.len() < count {
+            let frame = timeout(WAIT, body.frame())
+                .await
+                .expect("an event in time")
+                .expect("more events")
+                .unwrap();
+            if let Ok(data) = frame.into_data() {
+                text.push_str(std::str::from_utf8(&data).unwrap());
+            }
+            // events end with an empty line
+            while let Some(end) = text.find("\n\n") {
+                let event = text[..end].to_string();
+                text.drain(..end + 2);
+                let field = |name: &str| {
+                    event
+                        .lines()
+                        .find_map(|line| line.strip_prefix(name))
+                        .map(str::to_string)
+                };
+                if let (Some(id), Some(data)) = (field("id: "), field("data: ")) {
+                    let metrics = serde_json::from_str(&data).unwrap();
+                    events.push((id.parse().unwrap(), metrics));
+                }
+            }
+        }
+        events.truncate(count);
+        events
+    }
+
+    fn metrics_request(uri: &str) -> Request<Body> {
+        Request::get(uri).body(Body::empty()).unwrap()
+    }
+
+    fn ids(events: &[(u64, Metrics)]) -> Vec<u64> {
+        events.iter().map(|(id, _)| *id).collect()
+    }
+
+    #[tokio::test]
+    async fn a_new_client_starts_at_the_current_second() {
+        let events = metrics_events(started(5), metrics_request("/events/metrics"), 1).await;
+        assert_eq!(ids(&events), [5]);
+        assert_eq!(events[0].1, metrics_at(5));
+    }
+
+    #[tokio::test]
+    async fn a_reconnecting_client_gets_what_it_missed() {
+        let request = metrics_request("/events/metrics?last_event_id=2");
+        let events = metrics_events(started(5), request, 3).await;
+        assert_eq!(ids(&events), [3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn the_last_event_id_header_wins_over_the_query() {
+        let request = Request::get("/events/metrics?last_event_id=1")
+            .header("last-event-id", "3")
+            .body(Body::empty())
+            .unwrap();
+        let events = metrics_events(started(5), request, 1).await;
+        assert_eq!(ids(&events), [4]);
+    }
+
+    #[tokio::test]
+    async fn catching_up_goes_back_no_further_than_the_backlog() {
+        let request = metrics_request("/events/metrics?last_event_id=10");
+        let events = metrics_events(started(100), request, 1).await;
+        assert_eq!(ids(&events), [100 - METRICS_BACKLOG]);
+    }
+
+    #[tokio::test]
+    async fn ids_from_before_a_restart_start_over() {
+        let request = metrics_request("/events/metrics?last_event_id=500");
+        let events = metrics_events(started(5), request, 1).await;
+        assert_eq!(ids(&events), [5]);
+    }
+
+    #[test]
+    fn metrics_stay_in_range() {
+        for second in 0..1_000 {
+            let metrics = metrics_at(second);
+            assert!((0.0..=100.0).contains(&metrics.cpu_percent));
+            assert!(metrics.memory_mb > 0.0);
+            assert_eq!(metrics, metrics_at(second));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Checks the translations in `locales/`, which `src/i18n.rs` embeds: every file parses, every
+//! locale has every message any of them has, and every key passed to `t!`, `t_split!` or
+//! `t_string!` exists.
+
+use fluent_syntax::ast::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+const LOCALES: &str = "locales";
+const SOURCES: &str = "src";
+
+fn main() {
+    println!("cargo:rerun-if-changed={LOCALES}");
+    println!("cargo:rerun-if-changed={SOURCES}");
+    let mut problems = Vec::new();
+
+    // the message ids of every locale
+    let mut locales = BTreeMap::new();
+    for path in files(Path::new(LOCALES), "ftl") {
+        let source = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("reading {}: {error}", path.display()));
+        let resource = match fluent_syntax::parser::parse(source.as_str()) {
+            Ok(resource) => resource,
+            Err((resource, errors)) => {
+                for error in errors {
+                    problems.push(format!("{}: {error:?}", path.display()));
+                }
+                resource
+            }
+        };
+        let ids = resource
+            .body
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Message(message) => Some(message.id.name.to_string()),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>();
+        locales.insert(path.display().to_string(), ids);
+    }
+
+    let all = locales.values().flatten().cloned().collect::<BTreeSet<_>>();
+    for (path, ids) in &locales {
+        for missing in all.difference(ids) {
+            problems.push(format!("{path}: no message `{missing}`"));
+        }
+    }
+
+    for path in files(Path::new(SOURCES), "rs") {
+        let source = std::fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("reading {}: {error}", path.display()));
+        for (line, key) in used_keys(&source) {
+            match key {
+                Some(key) if !all.contains(key) => problems.push(format!(
+                    "{}:{line}: `{key}` is in no locale",
+                    path.display()
+                )),
+                Some(_) => {}
+                None => problems.push(format!(
+                    "{}:{line}: the key isn't a string literal, so it can't be checked",
+                    path.display()
+                )),
+            }
+        }
+    }
+
+    if !problems.is_empty() {
+        eprintln!("the translations are incomplete:");
+        for problem in &problems {
+            eprintln!("  {problem}");
+        }
+        std::process::exit(1);
+    }
+}
+
+/// The files under `dir` with extension `extension`, sorted.
+fn files(dir: &Path, extension: &str) -> Vec<std::path::PathBuf> {
+    let mut files = Vec::new();
+    let entries =
+        std::fs::read_dir(dir).unwrap_or_else(|error| panic!("reading {}: {error}", dir.display()));
+    for entry in entries {
+        let path = entry.expect("directory entry").path();
+        if path.is_dir() {
+            files.extend(self::files(&path, extension));
+        } else if path.extension().is_some_and(|found| found == extension) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+/// The keys of the `t!`, `t_split!` and `t_string!` calls in `source`, with the line numbers of
+/// the calls; `None` for a call whose key isn't a string literal. The key may be on a line of its
+/// own, as rustfmt puts it in a long call. Comments are skipped, so docs can show made up keys.
+fn used_keys(source: &str) -> Vec<(usize, Option<&str>)> {
+    const MACROS: [&str; 3] = ["t!(", "t_split!(", "t_string!("];
+    let lines = source.lines().collect::<Vec<_>>();
+    let mut keys = Vec::new();
+    for call in MACROS {
+        let mut offset = 0;
+        while let Some(found) = source[offset..].find(call) {
+            let start = offset + found;
+            offset = start + call.len();
+            // not the end of another macro, like `format!(` or `t_split!(` for `t!(`
+            let is_call = source[..start]
+                .chars()
+                .next_back()
+                .map_or(true, |before| !before.is_alphanumeric() && before != '_');
+            let line = source[..start].matches('\n').count();
+            if !is_call || lines[line].trim_start().starts_with("//") {
+                continue;
+            }
+            let mut arguments = &source[offset..];
+            // `t_string!` takes the `I18n` first
+            if call == "t_string!(" {
+                arguments = arguments.split_once(',').map_or("", |(_, rest)| rest);
+            }
+            let arguments = arguments.trim_start();
+            let key = arguments
+                .strip_prefix('"')
+                .and_then(|literal| literal.split_once('"'))
+                .map(|(key, _)| key);
+            keys.push((line + 1, key));
+        }
+    }
+    keys.sort();
+    keys
+}
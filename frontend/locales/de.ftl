@@ -0,0 +1,457 @@
+## Navbar
+
+nav-home = Start
+nav-demos = Leptos-Frontend-Demos
+nav-more = Mehr
+nav-about = Über uns
+nav-jobs = Jobs
+nav-contact = Kontakt
+nav-report-issue = Problem melden
+nav-sign-up = Registrieren
+nav-log-in = Anmelden
+nav-language = Sprache
+nav-main = Hauptnavigation
+nav-menu = Menü
+
+## Accessibility
+
+a11y-skip-link = Zum Inhalt springen
+a11y-navigated = Navigiert zu { $page }
+a11y-page-changed = Seite gewechselt
+
+## Pages
+
+home-title = Startseite
+home-playground = Bulma-Spielwiese
+demos-default = Das ist die Standardansicht von /demos
+demos-select = Wähle eine Demo, um mehr zu sehen
+demos-last-visit = Zuletzt warst du bei { $demo }.
+demos-sub-route-default = Standardansicht der anderen Unterrouten
+demos-nested-route-default = Standardansicht von demos/demo_nested_route
+demos-nested-route-select = Wähle etwas aus, um weiterzugehen
+not-found = Seite nicht gefunden
+
+update-available = Eine neue Version der App ist verfügbar.
+update-reload = Neu laden
+
+## Demo menu
+
+menu-title = Demo-Übersicht
+menu-basic-component = Einfache Komponenten
+menu-components-and-props = Komponenten und Props
+menu-basic-iteration = Einfache Iteration
+menu-form-and-input = Formulare und Eingaben
+menu-error-handling = Fehlerbehandlung
+menu-reactivity = Reaktivität
+menu-parent-child-communication = Eltern-Kind-Kommunikation
+menu-async = Asynchrones
+menu-control-flow = Kontrollfluss
+menu-nested-route = Verschachtelte Routen
+menu-modal = Dialoge
+menu-virtual-list = Virtuelle Liste
+menu-benchmark = Listen-Benchmark
+menu-data-table = Datentabelle
+menu-paging = Seitenweise laden
+menu-server-push = Server-Push
+menu-worker = Web Worker
+menu-i18n = Sprachen
+
+## Lists
+
+list-loading = Wird geladen …
+list-end = Das war alles.
+
+## Demo paging
+
+paging-title = Demo: seitenweise laden
+paging-intro = Der simulierte Server hat { $total } Einträge und gibt sie zu je { $per_page } heraus, über das simulierte Netzwerk.
+paging-pagination = Seitennavigation
+paging-name = Name
+paging-price = Preis
+paging-price-value = { NUMBER($price, style: "currency", currency: "EUR") }
+paging-infinite-scroll = Endloses Scrollen
+paging-loaded = { $loaded } von { $total } geladen
+paging-nothing-loaded = Noch nichts geladen
+paging-start-over = Von vorn
+
+## Demo languages
+
+i18n-title = Demo: Sprachen
+i18n-intro = Jeder Text auf dieser Seite stammt aus der Fluent-Datei der in der Navigationsleiste gewählten Sprache, { $file }.
+i18n-plurals = Pluralformen
+i18n-items = Artikel
+i18n-cart =
+    { $count ->
+        [0] Dein Warenkorb ist leer.
+        [one] In deinem Warenkorb liegt ein Artikel.
+       *[other] In deinem Warenkorb liegen { $count } Artikel.
+    }
+i18n-numbers = Zahlen
+i18n-amount = Betrag
+i18n-number = Zahl: { $amount }
+i18n-currency = Preis: { NUMBER($amount, style: "currency", currency: "EUR") }
+i18n-percent = Anteil an tausend: { NUMBER($share, style: "percent", maximumFractionDigits: 1) }
+i18n-rounded = Gerundet: { NUMBER($amount, maximumFractionDigits: 0) }
+
+## Failures
+
+error-unavailable = der Server ist nicht erreichbar
+error-timeout = die Anfrage hat zu lange gedauert
+error-not-found = nicht gefunden
+error-offline = du bist offline
+error-aborted = die Anfrage wurde abgebrochen
+failures-inject = Fehler einstreuen
+failures-never = nie
+failures-sometimes = manchmal
+failures-always = immer
+failures-load-failed = Laden fehlgeschlagen: { $error }
+failures-title = Laden fehlgeschlagen
+failures-retry = Erneut versuchen
+
+## Network
+
+network-summary = Netzwerk: { $latency } ms (+ bis zu { $jitter } ms), { $failures } % Fehler
+network-offline-summary = Netzwerk: offline
+network-fast = schnell
+network-normal = normal
+network-slow = langsam
+network-flaky = wackelig
+network-latency = Latenz (ms)
+network-jitter = Schwankung (ms)
+network-failure-rate = Fehlerquote ({ $failures } %)
+network-offline = offline
+
+## Query cache
+
+query-cache = Abfrage-Cache
+query-key = Schlüssel
+query-status = Status
+query-age = Alter
+query-age-seconds = { NUMBER($seconds, maximumFractionDigits: 0) } s
+query-fetching = wird geladen
+query-fresh = frisch
+query-stale = veraltet
+query-invalidate = Verwerfen
+query-invalidate-all = Alle verwerfen
+
+## Timeline
+
+timeline-loading = lädt
+timeline-fallback = Platzhalter angezeigt
+timeline-stale = vorheriger Inhalt bleibt
+timeline-span = { $phase }: { NUMBER($start, maximumFractionDigits: 0) } ms bis { NUMBER($end, maximumFractionDigits: 0) } ms ({ NUMBER($duration, maximumFractionDigits: 0) } ms)
+timeline-clear = Leeren
+timeline-seconds = { NUMBER($seconds, maximumFractionDigits: 1) } s
+
+## Shared controls
+
+history-undo = Rückgängig
+history-redo = Wiederholen
+reorder-hint = Ziehen oder mit den Pfeiltasten umsortieren
+reorder-move = Zeile { $row } von { $rows } verschieben
+confirm-title = Bitte bestätigen
+close = schließen
+confirm-ok = Bestätigen
+confirm-cancel = Abbrechen
+
+## Data table
+
+table-filter-placeholder = filtern
+table-filter = { $column } filtern
+table-select-row = Zeile auswählen
+table-select-visible = Alle sichtbaren Zeilen auswählen
+
+## Widgets
+
+socket-connecting = verbindet
+socket-connected = verbunden
+socket-reconnecting = verbindet neu (Versuch { $attempt })
+socket-disconnected = getrennt
+pagination = Seitennavigation
+pagination-previous = Zurück
+pagination-next = Weiter
+pagination-page = Seite { $page }
+
+## Bulma playground
+
+playground-layout = Layout
+playground-columns = Spalten
+playground-gap = Abstand
+playground-breakpoint = Nebeneinander ab
+playground-screen = Größen für
+playground-all-screens = alle Bildschirme
+playground-default = Standard
+playground-size = Größe
+playground-offset = Versatz
+playground-color = Farbe
+playground-column-size = Größe von Spalte { $column }
+playground-column-offset = Versatz von Spalte { $column }
+playground-column-color = Farbe von Spalte { $column }
+playground-preview = Vorschau
+playground-export = view!-Markup exportieren
+playground-exported = Exportiertes Markup
+
+## Iteration demo
+
+iteration-title = Demo Iteration: statische und dynamische Ansichten
+iteration-static = Statische Liste
+iteration-wrap = wir können sie in <li> verpacken
+iteration-static-interface = Nur weil die Liste statisch ist, muss die Oberfläche nicht statisch sein.
+iteration-dynamic = Dynamische Liste
+iteration-dynamic-intro = Dieses Muster passt, wenn sich die Zeilen der Liste ändern.
+iteration-reorder = Zieh eine Zeile an ihrem Griff, oder fokussiere den Griff und nutze die Pfeiltasten. Jeder Zähler behält beim Verschieben seinen Stand, weil seine Zeile an der ID des Zählers hängt, nicht an ihrer Position.
+iteration-add = Zähler hinzufügen
+iteration-remove = Entfernen
+iteration-remove-question = Den Zähler bei { $count } entfernen?
+iteration-complex-title = Demo Iteration über komplexere Daten
+iteration-update = Werte aktualisieren
+iteration-not-reactive = Das klappt nicht, weil each.value kein reaktiver Typ ist
+iteration-memo = Mit `create_memo` entsteht eine abgeleitete Berechnung, die nur dann reaktiv aktualisiert, wenn sich ihr Wert geändert hat
+table-title = Sortier- und filterbare Datentabelle
+table-intro = Klick auf eine Überschrift sortiert, Umschalt+Klick sortiert nach mehreren Spalten. Einen Wert zu verdoppeln rendert nur diese Zeile neu.
+table-double-random = Eine zufällige Zeile verdoppeln
+table-double-selected = Die ausgewählten Zeilen verdoppeln
+table-key-contains = Schlüssel enthält
+table-value-at-least = Wert mindestens
+table-url = Die Suche landet in der URL, sobald du aufhörst zu tippen, und ersetzt den Verlaufseintrag; jeder Mindestwert ist ein neuer Verlaufseintrag, Zurück geht sie also einzeln durch.
+table-selected = { $count } ausgewählt
+table-key = Schlüssel
+table-value = Wert
+table-parity = Parität
+table-even = gerade
+table-odd = ungerade
+
+## Nested route demo
+
+nested-select = Wähle etwas aus, um mehr zu sehen
+nested-home = Startseite der verschachtelten Route
+nested-contact-info-placeholder = (Kontaktdaten)
+nested-select-user = Wähle eine Person aus, um ihre Kontaktdaten zu sehen.
+nested-title = Demo verschachtelte Route
+nested-route-home = Start der Demo-Route
+nested-contacts = Kontakte
+nested-form-example = Formularbeispiel
+nested-manual = Manuelles Absenden
+nested-automatic = Automatisches Absenden
+nested-name = Name
+nested-number = Zahl
+nested-option = Auswahl
+nested-submit = Absenden
+nested-filter = Filtern
+nested-filter-contacts = Kontakte filtern
+nested-not-found = Person nicht gefunden.
+nested-contact-info = Kontaktdaten
+nested-conversations = Unterhaltungen
+chat-unreachable = Der Chat-Server ist nicht erreichbar. Starte ihn mit { $command } und verbinde dich dann neu.
+reconnect = Neu verbinden
+chat-placeholder = Sag etwas
+chat-message = Nachricht
+chat-send = Senden
+
+## Worker demo
+
+worker-crashed = der Worker ist abgestürzt
+worker-cancelled = die Aufgabe wurde abgebrochen
+worker-rejected = der Worker hat die Aufgabe abgelehnt: { $error }
+worker-title = Demo Web Worker
+worker-intro = Die Arbeit unten läuft in einem Web Worker, der aus diesem Crate gebaut ist, damit die Seite derweil bedienbar bleibt. Die Uhr steht, sobald die Seite selbst beschäftigt ist.
+worker-order = Aufgaben laufen nacheinander, in der Reihenfolge ihrer Anfrage. Eine Aufgabe, die vor ihrem Ende überholt wird, wird abgebrochen.
+worker-cancel-all = Alle abbrechen
+worker-clock = Uhr: { NUMBER($seconds, minimumFractionDigits: 1, maximumFractionDigits: 1) } s
+worker-primes = { $count } Primzahlen unter { $below }, die größte { $largest }
+worker-no-primes = Keine Primzahlen unter { $below }
+worker-primes-title = Primzahlen zählen
+worker-count-below = Primzahlen zählen unter
+worker-below = unter { $limit }
+worker-count-in-worker = Im Worker zählen
+worker-count-on-main = Im Hauptthread zählen
+worker-on-main = Im Hauptthread: { $result } in { NUMBER($ms, maximumFractionDigits: 0) } ms, während die Seite eingefroren war.
+worker-mandelbrot = Mandelbrot-Menge
+worker-iterations-label = Iterationen
+worker-iterations = { $iterations } Iterationen
+worker-zoom-out = Herauszoomen
+worker-reset = Zurücksetzen
+worker-zoom = { NUMBER($zoom, maximumFractionDigits: 0) }×
+worker-canvas = Die Mandelbrot-Menge. Klicke, um dort hineinzuzoomen.
+
+## Basics, control flow and error handling demos
+
+basics-title = Einfache Komponente (3.1 und 3.2)
+basics-click-button = Einfacher Klick-Button
+basics-click-me-count = Klick mich: { $count }
+basics-dynamic-classes = Dynamische Klassen
+basics-progress-hint = klicke den Button unten, um den Fortschrittsbalken zu ändern
+basics-change-progress = Klick mich, um den Fortschrittsbalken zu ändern: { $count }
+basics-dynamic-style = Dynamischer Stil
+basics-move = Klicken zum Verschieben
+basics-dynamic-attributes = Dynamische Attribute
+basics-derived = Abgeleitete Signale
+basics-derived-intro = Es zeigt, dass ein Signal von einem anderen abgeleitet werden kann
+basics-double-count = Doppelter Zähler: { $count }
+props-title = Komponenten und Props
+props-intro = Das folgende Beispiel zeigt, wie wir die Prop progress übergeben
+props-click-me = Klick mich
+props-into = Zeigt den Einsatz von #[prop(into)]
+control-title = Demo Kontrollfluss
+control-intro = Damit wird entschieden, ob dieser Teil der Ansicht gerendert wird oder nicht
+control-demo-01 = Demo 01
+control-ding = Ding ding ding!
+control-big-component = große Komponente
+control-small-component = kleine Komponente
+control-demo-02 = Demo 02: bedingtes Rendern
+control-careful = Vorsicht mit ReadSignal<i32> in der Bedingung, es löst wegen der Reaktivität ein erneutes Rendern aus
+control-click-me = klick mich: { $count }
+control-big = Groß
+control-small = Klein
+control-show = die Lösung ist <Show/>
+control-demo-03 = Demo03: Typumwandlung für match
+control-one = Eins
+control-two = Zwei
+control-value = Wert
+errors-title = Demo Fehlerbehandlung
+errors-type-number = Gib eine Zahl ein (oder nicht!)
+errors-type-not-number = Gib eine Zahl ein (oder etwas, das keine Zahl ist!)
+errors-you-entered = Du hast eingegeben
+errors-not-a-number = Keine Zahl!
+errors-list = Fehler:
+
+## Form and modal demos
+
+form-title = Demo Formular und Eingabe
+form-controlled = Kontrollierte Eingabe
+form-name = Name
+form-name-is = Der Name ist: { $name }
+form-settled-name = Name, sobald du aufhörst zu tippen: { $name }
+form-uncontrolled = Unkontrollierte Eingabe
+form-submit = Absenden
+form-text-area = Textbereich
+form-text = Text
+form-text-is = der Text ist: { $text }
+form-words = Wörter (höchstens alle 250 ms neu gezählt): { $count }
+form-select = Demo Auswahl
+form-letter = Buchstabe
+form-selection-is = Deine Auswahl ist: { $value }
+modal-title = Demo Modal
+modal-intro = Modals werden über ein <Portal/> auf Ebene des body gerendert. Der Fokus wandert in den Dialog, Tab bleibt darin, Escape oder ein Klick auf den Hintergrund schließt ihn, und der Fokus kehrt zum Button zurück, der ihn geöffnet hat.
+modal-plain = Ein einfaches Modal
+modal-open = Modal öffnen
+modal-hello = Hallo aus dem Portal
+modal-try = Probier Tab, Umschalt+Tab und Escape.
+modal-focus = Der Fokus bleibt hier drin
+modal-close = Schließen
+modal-confirm-remove = Vor dem Entfernen bestätigen
+
+## Parent and children, virtual list demos
+
+parent-title = Demo Kommunikation zwischen Eltern und Kindern
+parent-down = Für die Eltern ist es leicht, mit dem Kind zu reden: ein ReadSignal, ein Signal oder sogar ein MaybeSignal als Prop übergeben
+parent-up = Wie kann ein Kind Ereignisse oder Zustandsänderungen an die Eltern zurückmelden?
+parent-approach = Ansatz { $number }
+parent-approach-version = Ansatz { $number }, Version { $version }
+parent-write-signal = Ein WriteSignal von den Eltern an das Kind übergeben
+parent-callback = Callback oder Closure verwenden
+parent-event-listener = Event-Listener verwenden
+parent-context = Einen Kontext bereitstellen
+parent-context-intro = Kontexte werden am Typ der bereitgestellten Daten erkannt und leben in einem Baum von oben nach unten, der den Umrissen deines UI-Baums folgt.
+parent-toggled = Umgeschaltet? { $toggled ->
+        [true] ja
+       *[other] nein
+    }
+parent-toggle = Umschalten
+parent-layout = Mein Layout
+virtual-title = Demo virtuelle Liste
+virtual-intro = Beide Listen haben 100 000 Zeilen, aber nur die Zeilen im sichtbaren Bereich (plus ein paar darüber und darunter) sind eingehängt. Zeilen haben Schlüssel wie in <For/>: klick einen Zähler, tausche die ersten beiden Zeilen, und der Stand wandert mit seiner Zeile. Weit aus dem Blick gescrollte Zeilen werden ausgehängt, ihr lokaler Zustand ist also weg, wenn sie zurückkommen.
+virtual-fixed = Feste Zeilenhöhe
+virtual-swap = Die ersten beiden Zeilen tauschen
+virtual-measured = Gemessene Zeilenhöhe
+virtual-clicked = { $count }-mal geklickt
+
+## Server push and reactivity demos
+
+push-title = Demo Server-Push
+push-intro = Der Demo-Server schickt jede Sekunde erfundene Servermetriken als Server-Sent Events. Er beendet den Stream alle 30 Sekunden, wie es ein Proxy mit Timeout täte; der Browser verbindet sich neu, und der Server schickt anhand der ID des letzten Events alles Verpasste nach. Halte den Server an und starte ihn neu, um zu sehen, wie der Stream es von selbst erneut versucht.
+push-last-event = ID des letzten Events: { $id }
+push-no-event = keine
+push-unreachable = Der Metrik-Feed ist nicht erreichbar. Starte ihn mit { $command } und verbinde dich dann neu.
+push-cpu = CPU
+push-memory = Speicher
+push-requests = Anfragen
+reactivity-title = Demo Reaktivität
+reactivity-with-update = Demo01: with und update verwenden
+reactivity-with-macro = Demo02: das Makro with!
+reactivity-name = der Name ist: { $name }
+reactivity-depend = Signale voneinander abhängig machen
+reactivity-version = { $code }-Version
+reactivity-first-name = Vorname
+reactivity-last-name = Nachname
+reactivity-show-last-name = Nachname anzeigen
+reactivity-manual = Manuelle Version
+reactivity-is = { $code } ist
+reactivity-value = Wert
+
+## Benchmark demo
+
+benchmark-fine = Schlüssel ID, Label als Signal
+benchmark-coarse = Schlüssel (ID, Label), einfache Daten
+benchmark-index = Schlüssel Index, Memo pro Zeile
+benchmark-create-1k = 1.000 Zeilen erzeugen
+benchmark-create-10k = 10.000 Zeilen erzeugen
+benchmark-append-1k = 1.000 Zeilen anhängen
+benchmark-update = Jede 10. Zeile aktualisieren
+benchmark-swap = Zeilen 2 und 999 tauschen
+benchmark-select = Zeile 2 auswählen
+benchmark-remove = Zeile 2 entfernen
+benchmark-clear = Leeren
+benchmark-title = Benchmark für Listen mit Schlüsseln
+benchmark-intro = Die Operationen von js-framework-benchmark auf einer <For/>-Tabelle, gemessen mit der Performance API. Wechsle die Strategie, um Schlüssel per ID mit einem Signal pro Label, per ganzer Zeile und per Index zu vergleichen.
+benchmark-reset = Ergebnisse zurücksetzen
+benchmark-strategy = Strategie
+benchmark-operation = Operation
+benchmark-rows-after = Zeilen danach
+benchmark-sync = Synchron (ms)
+benchmark-frame = Nächster Frame (ms)
+benchmark-ms = { NUMBER($ms, minimumFractionDigits: 1, maximumFractionDigits: 1) }
+
+## Async demo
+
+async-title = Demo Async
+async-returned = Der Server lieferte { $value } nach { $attempts } Versuch(en)
+async-loading = Lädt...
+async-idle = Untätig.
+async-resource = Daten mit einer Resource laden
+async-click-me = Klick mich
+async-two-v1 = Demo: Auf zwei Resources warten v1
+async-two-v2 = Demo: Auf zwei Resources warten v2
+async-my-data = Meine Daten
+async-some-a = ein A: { $value }
+async-some-b = ein B: { $value }
+async-reload-a = A neu laden
+async-reload-b = B neu laden
+async-await = Demo: mit <Await/> auf ein Future warten, bevor gerendert wird
+async-monkeys = { $monkeys } kleine Affen hüpfen auf dem Bett.
+async-transition = Demo Transition
+async-tab = Tab { $tab }
+async-loading-initial = Lädt die ersten Daten...
+async-hang-on = Einen Moment...
+async-loads = { $started } Ladevorgänge gestartet, { $cancelled } abgebrochen
+async-todo-added = Todo mit der ID { $id } hinzugefügt
+async-todo-failed = Das Todo ließ sich nicht hinzufügen: { $error }
+async-action = Demo Action
+async-adding = „{ $todo }“ wird hinzugefügt...
+async-what-to-do = Was musst du erledigen?
+async-add-todo = Todo hinzufügen
+async-submitted = Abgeschickt:
+async-pending = Ausstehend:
+async-todo-id = Todo-ID:
+async-todos = Todos
+async-todos-refreshing = Todos (werden aktualisiert...)
+async-notifications = Demo Benachrichtigungen
+async-info = Info
+async-success = Erfolg
+async-warning = Warnung
+async-danger = Gefahr
+async-notification = Das ist eine Benachrichtigung der Stufe { $level }
+async-search = Demo entprellte Suche
+async-search-fruits = Obst suchen
+async-searches = { $count } Suchen gesendet
+async-searching = Sucht...
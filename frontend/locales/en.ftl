@@ -0,0 +1,457 @@
+## Navbar
+
+nav-home = Home
+nav-demos = Leptos frontend demos
+nav-more = More
+nav-about = About
+nav-jobs = Jobs
+nav-contact = Contact
+nav-report-issue = Report an issue
+nav-sign-up = Sign up
+nav-log-in = Log in
+nav-language = Language
+nav-main = Main navigation
+nav-menu = Menu
+
+## Accessibility
+
+a11y-skip-link = Skip to content
+a11y-navigated = Navigated to { $page }
+a11y-page-changed = Page changed
+
+## Pages
+
+home-title = Home Page
+home-playground = Bulma playground
+demos-default = This is the default view for /demos
+demos-select = Select a demo to see detail
+demos-last-visit = Last time you were at { $demo }.
+demos-sub-route-default = default view for other sub-routes
+demos-nested-route-default = default view for demos/demo_nested_route
+demos-nested-route-select = select one to go further route
+not-found = Route Not Found
+
+update-available = A new version of the app is available.
+update-reload = Reload
+
+## Demo menu
+
+menu-title = Demo index
+menu-basic-component = basic components
+menu-components-and-props = components and props
+menu-basic-iteration = basic iterator
+menu-form-and-input = form and input
+menu-error-handling = error handling
+menu-reactivity = reactivity
+menu-parent-child-communication = parent child communication
+menu-async = demo async
+menu-control-flow = demo control flow
+menu-nested-route = demo nested route
+menu-modal = demo modal
+menu-virtual-list = demo virtual list
+menu-benchmark = keyed list benchmark
+menu-data-table = data table
+menu-paging = paging
+menu-server-push = server push
+menu-worker = web worker
+menu-i18n = languages
+
+## Lists
+
+list-loading = Loading...
+list-end = That's all.
+
+## Demo paging
+
+paging-title = Demo paging
+paging-intro = The fake server holds { $total } items and hands them out { $per_page } at a time, through the simulated network.
+paging-pagination = Pagination
+paging-name = Name
+paging-price = Price
+paging-price-value = { NUMBER($price, style: "currency", currency: "EUR") }
+paging-infinite-scroll = Infinite scroll
+paging-loaded = { $loaded } of { $total } loaded
+paging-nothing-loaded = Nothing loaded yet
+paging-start-over = Start over
+
+## Demo languages
+
+i18n-title = Demo languages
+i18n-intro = Every text on this page comes from the Fluent file of the language picked in the navbar, { $file }.
+i18n-plurals = Plurals
+i18n-items = Items
+i18n-cart =
+    { $count ->
+        [0] Your cart is empty.
+        [one] There is one item in your cart.
+       *[other] There are { $count } items in your cart.
+    }
+i18n-numbers = Numbers
+i18n-amount = Amount
+i18n-number = Number: { $amount }
+i18n-currency = Price: { NUMBER($amount, style: "currency", currency: "EUR") }
+i18n-percent = Share of a thousand: { NUMBER($share, style: "percent", maximumFractionDigits: 1) }
+i18n-rounded = Rounded: { NUMBER($amount, maximumFractionDigits: 0) }
+
+## Failures
+
+error-unavailable = the server is unavailable
+error-timeout = the request timed out
+error-not-found = not found
+error-offline = you are offline
+error-aborted = the request was cancelled
+failures-inject = Inject failures
+failures-never = never
+failures-sometimes = sometimes
+failures-always = always
+failures-load-failed = Failed to load: { $error }
+failures-title = Failed to load
+failures-retry = Retry
+
+## Network
+
+network-summary = Network: { $latency } ms (+ up to { $jitter } ms), { $failures } % failures
+network-offline-summary = Network: offline
+network-fast = fast
+network-normal = normal
+network-slow = slow
+network-flaky = flaky
+network-latency = Latency (ms)
+network-jitter = Jitter (ms)
+network-failure-rate = Failure rate ({ $failures } %)
+network-offline = offline
+
+## Query cache
+
+query-cache = Query cache
+query-key = Key
+query-status = Status
+query-age = Age
+query-age-seconds = { NUMBER($seconds, maximumFractionDigits: 0) } s
+query-fetching = fetching
+query-fresh = fresh
+query-stale = stale
+query-invalidate = Invalidate
+query-invalidate-all = Invalidate all
+
+## Timeline
+
+timeline-loading = loading
+timeline-fallback = fallback shown
+timeline-stale = previous content kept
+timeline-span = { $phase }: { NUMBER($start, maximumFractionDigits: 0) } ms to { NUMBER($end, maximumFractionDigits: 0) } ms ({ NUMBER($duration, maximumFractionDigits: 0) } ms)
+timeline-clear = Clear
+timeline-seconds = { NUMBER($seconds, maximumFractionDigits: 1) } s
+
+## Shared controls
+
+history-undo = Undo
+history-redo = Redo
+reorder-hint = Drag, or use the arrow keys, to reorder
+reorder-move = Move row { $row } of { $rows }
+confirm-title = Please confirm
+close = close
+confirm-ok = Confirm
+confirm-cancel = Cancel
+
+## Data table
+
+table-filter-placeholder = filter
+table-filter = Filter { $column }
+table-select-row = Select row
+table-select-visible = Select all visible rows
+
+## Widgets
+
+socket-connecting = connecting
+socket-connected = connected
+socket-reconnecting = reconnecting (attempt { $attempt })
+socket-disconnected = disconnected
+pagination = pagination
+pagination-previous = Previous
+pagination-next = Next
+pagination-page = Page { $page }
+
+## Bulma playground
+
+playground-layout = Layout
+playground-columns = Columns
+playground-gap = Gap
+playground-breakpoint = Side by side from
+playground-screen = Sizes for
+playground-all-screens = all screens
+playground-default = default
+playground-size = Size
+playground-offset = Offset
+playground-color = Color
+playground-column-size = Size of column { $column }
+playground-column-offset = Offset of column { $column }
+playground-column-color = Color of column { $column }
+playground-preview = Preview
+playground-export = Export view! markup
+playground-exported = Exported markup
+
+## Iteration demo
+
+iteration-title = Demo iteration: static views and dynamic views
+iteration-static = Static List
+iteration-wrap = we can wrap them in <li>
+iteration-static-interface = The fact that the list is static doesn’t mean the interface needs to be static.
+iteration-dynamic = Dynamic List
+iteration-dynamic-intro = Use this pattern if the rows in your list will change.
+iteration-reorder = Drag a row by its handle, or focus the handle and use the arrow keys. Each counter keeps its count when it moves, because its row is keyed by the counter's id, not by its position.
+iteration-add = Add Counter
+iteration-remove = Remove
+iteration-remove-question = Remove the counter at { $count }?
+iteration-complex-title = Demo iterating over more complex data
+iteration-update = Update Values
+iteration-not-reactive = This won't work because each.value is not reactive type
+iteration-memo = Use `create_memo` to create a derived computation that only triggers a reactive update when its value has changed
+table-title = Sortable, filterable data table
+table-intro = Click a header to sort, shift+click to sort by several columns. Doubling a value only re-renders that row.
+table-double-random = Double one random row
+table-double-selected = Double the selected rows
+table-key-contains = Key contains
+table-value-at-least = Value at least
+table-url = The search is written to the URL once you stop typing, replacing the history entry; each minimum value is a new history entry, so Back steps through them.
+table-selected = { $count } selected
+table-key = Key
+table-value = Value
+table-parity = Parity
+table-even = even
+table-odd = odd
+
+## Nested route demo
+
+nested-select = Select to see more
+nested-home = Nested Route Home
+nested-contact-info-placeholder = (Contact Info)
+nested-select-user = Select a user to view contact info.
+nested-title = Demo nested route
+nested-route-home = Demo route home
+nested-contacts = Contacts
+nested-form-example = Form Example
+nested-manual = Manual Submission
+nested-automatic = Automatic Submission
+nested-name = Name
+nested-number = Number
+nested-option = Select
+nested-submit = Submit
+nested-filter = Filter
+nested-filter-contacts = Filter contacts
+nested-not-found = User not found.
+nested-contact-info = Contact Info
+nested-conversations = Conversations
+chat-unreachable = The chat server can't be reached. Start it with { $command }, then reconnect.
+reconnect = Reconnect
+chat-placeholder = Say something
+chat-message = Message
+chat-send = Send
+
+## Worker demo
+
+worker-crashed = the worker crashed
+worker-cancelled = the task was cancelled
+worker-rejected = the worker rejected the task: { $error }
+worker-title = Demo web worker
+worker-intro = The work below runs in a web worker, built from this crate, so the page stays responsive meanwhile. The clock stops whenever the page itself is busy.
+worker-order = Tasks run one after the other, in the order they were asked for. A task that is superseded before it is done is cancelled.
+worker-cancel-all = Cancel all
+worker-clock = Clock: { NUMBER($seconds, minimumFractionDigits: 1, maximumFractionDigits: 1) } s
+worker-primes = { $count } primes below { $below }, the largest { $largest }
+worker-no-primes = No primes below { $below }
+worker-primes-title = Counting primes
+worker-count-below = Count primes below
+worker-below = below { $limit }
+worker-count-in-worker = Count in the worker
+worker-count-on-main = Count on the main thread
+worker-on-main = On the main thread: { $result } in { NUMBER($ms, maximumFractionDigits: 0) } ms, with the page frozen meanwhile.
+worker-mandelbrot = Mandelbrot set
+worker-iterations-label = Iterations
+worker-iterations = { $iterations } iterations
+worker-zoom-out = Zoom out
+worker-reset = Reset
+worker-zoom = { NUMBER($zoom, maximumFractionDigits: 0) }×
+worker-canvas = The Mandelbrot set. Click to zoom in there.
+
+## Basics, control flow and error handling demos
+
+basics-title = Basic Component (3.1 and 3.2)
+basics-click-button = Simple click button
+basics-click-me-count = Click me: { $count }
+basics-dynamic-classes = Dynamic Classes
+basics-progress-hint = click the below button to change the progress bar
+basics-change-progress = Click me to change progress bar: { $count }
+basics-dynamic-style = Dynamic Style
+basics-move = Click to Move
+basics-dynamic-attributes = Dynamic Attributes
+basics-derived = Derived Signals
+basics-derived-intro = It shows a signal could be derived from another signal
+basics-double-count = Double Count: { $count }
+props-title = Components And Props
+props-intro = The following example shows how we pass the progress prop
+props-click-me = Click me
+props-into = Show the use of #[prop(into)]
+control-title = Demo control flow
+control-intro = It is used for should I render this part of the view or not
+control-demo-01 = demo 01
+control-ding = Ding ding ding!
+control-big-component = big component
+control-small-component = small component
+control-demo-02 = demo 02: conditional render
+control-careful = Be careful when use ReadSignal<i32> in the condition because it will trigger rerender due to reactive
+control-click-me = click me: { $count }
+control-big = Big
+control-small = Small
+control-show = solution is to use <Show/>
+control-demo-03 = Demo03: Type conversion for match
+control-one = One
+control-two = Two
+control-value = value
+errors-title = Demo error handling
+errors-type-number = Type a number (or not!)
+errors-type-not-number = Type a number (or something that's not a number!)
+errors-you-entered = You entered
+errors-not-a-number = Not a number!
+errors-list = Errors:
+
+## Form and modal demos
+
+form-title = Demo form and input
+form-controlled = Controlled input
+form-name = Name
+form-name-is = Name is: { $name }
+form-settled-name = Name once you stop typing: { $name }
+form-uncontrolled = Uncontrolled input
+form-submit = Submit
+form-text-area = Text area
+form-text = Text
+form-text-is = text is: { $text }
+form-words = words (recounted at most every 250 ms): { $count }
+form-select = Demo select
+form-letter = Letter
+form-selection-is = Your selection is: { $value }
+modal-title = Demo modal
+modal-intro = Modals are rendered through a <Portal/> at body level. Focus moves into the dialog, Tab stays inside it, Escape or a click on the backdrop closes it, and focus goes back to the button that opened it.
+modal-plain = A plain modal
+modal-open = Open modal
+modal-hello = Hello from the portal
+modal-try = Try Tab, Shift+Tab and Escape.
+modal-focus = Focus stays in here
+modal-close = Close
+modal-confirm-remove = Confirm before removing
+
+## Parent and children, virtual list demos
+
+parent-title = Demo parent children communication
+parent-down = It is easy for the parent to communicate to the child: pass ReadSignal, or a Signal, or even a MaybeSignal as a prop
+parent-up = How can a child send notifications about events or state changes back up to the parent?
+parent-approach = approach { $number }
+parent-approach-version = Approach { $number }, version { $version }
+parent-write-signal = Pass WriteSignal from parent down to the child
+parent-callback = Use callback or closure
+parent-event-listener = Use event listener
+parent-context = Providing a context
+parent-context-intro = Contexts are identified by the type of the data you provide and they exist in a top-down tree that follows the contours of your UI tree.
+parent-toggled = Toggled? { $toggled ->
+        [true] yes
+       *[other] no
+    }
+parent-toggle = Toggle
+parent-layout = My layout
+virtual-title = Demo virtual list
+virtual-intro = Both lists hold 100 000 rows, but only the rows in the viewport (plus a few above and below) are mounted. Rows are keyed like in <For/>: click a counter, swap the first two rows and the count moves with its row. Rows scrolled far out of view are unmounted, so their local state is gone when they come back.
+virtual-fixed = Fixed row height
+virtual-swap = Swap first two rows
+virtual-measured = Measured row height
+virtual-clicked = Clicked { $count } times
+
+## Server push and reactivity demos
+
+push-title = Demo server push
+push-intro = The demo server sends made up server metrics every second as Server-Sent Events. It ends the stream every 30 seconds, as a proxy with a timeout would; the browser reconnects and the server sends whatever was missed, going by the id of the last event. Stop and restart the server to see the stream retry on its own.
+push-last-event = Last event id: { $id }
+push-no-event = none
+push-unreachable = The metrics feed can't be reached. Start it with { $command }, then reconnect.
+push-cpu = CPU
+push-memory = Memory
+push-requests = Requests
+reactivity-title = Demo Reactivity
+reactivity-with-update = Demo01: usage of with and update
+reactivity-with-macro = Demo02: with! macro
+reactivity-name = name is: { $name }
+reactivity-depend = Making signals depend on each other
+reactivity-version = { $code } Version
+reactivity-first-name = First Name
+reactivity-last-name = Last Name
+reactivity-show-last-name = Show Last Name
+reactivity-manual = Manual Version
+reactivity-is = { $code } is
+reactivity-value = Value
+
+## Benchmark demo
+
+benchmark-fine = keyed by id, label signal
+benchmark-coarse = keyed by (id, label), plain data
+benchmark-index = keyed by index, memo per row
+benchmark-create-1k = Create 1,000 rows
+benchmark-create-10k = Create 10,000 rows
+benchmark-append-1k = Append 1,000 rows
+benchmark-update = Update every 10th row
+benchmark-swap = Swap rows 2 and 999
+benchmark-select = Select row 2
+benchmark-remove = Remove row 2
+benchmark-clear = Clear
+benchmark-title = Keyed list benchmark
+benchmark-intro = The operations of js-framework-benchmark on a <For/> table, timed with the Performance API. Switch strategy to compare keying by id with a signal per label, keying by the whole row, and keying by index.
+benchmark-reset = Reset results
+benchmark-strategy = Strategy
+benchmark-operation = Operation
+benchmark-rows-after = Rows after
+benchmark-sync = Sync (ms)
+benchmark-frame = Next frame (ms)
+benchmark-ms = { NUMBER($ms, minimumFractionDigits: 1, maximumFractionDigits: 1) }
+
+## Async demo
+
+async-title = Demo Async
+async-returned = Server returned { $value } after { $attempts } attempt(s)
+async-loading = Loading...
+async-idle = Idle.
+async-resource = Load data with resource
+async-click-me = Click me
+async-two-v1 = Demo: Wait two resources v1
+async-two-v2 = Demo: Wait two resources v2
+async-my-data = My Data
+async-some-a = some A: { $value }
+async-some-b = some B: { $value }
+async-reload-a = Reload A
+async-reload-b = Reload B
+async-await = Demo: use <Await/> for some future to resolve before rendering
+async-monkeys = { $monkeys } little monkeys, jumping on the bed.
+async-transition = Demo Transition
+async-tab = Tab { $tab }
+async-loading-initial = Loading initial data...
+async-hang-on = Hang on...
+async-loads = { $started } loads started, { $cancelled } cancelled
+async-todo-added = Todo added with id { $id }
+async-todo-failed = Could not add the todo: { $error }
+async-action = Demo Action
+async-adding = Adding “{ $todo }”...
+async-what-to-do = What do you need to do?
+async-add-todo = Add Todo
+async-submitted = Submitted:
+async-pending = Pending:
+async-todo-id = Todo ID:
+async-todos = Todos
+async-todos-refreshing = Todos (refreshing...)
+async-notifications = Demo Notifications
+async-info = Info
+async-success = Success
+async-warning = Warning
+async-danger = Danger
+async-notification = This is a { $level } notification
+async-search = Demo debounced search
+async-search-fruits = Search fruits
+async-searches = { $count } searches sent
+async-searching = Searching...
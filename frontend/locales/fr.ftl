@@ -0,0 +1,457 @@
+## Navbar
+
+nav-home = Accueil
+nav-demos = Démos frontend Leptos
+nav-more = Plus
+nav-about = À propos
+nav-jobs = Emplois
+nav-contact = Contact
+nav-report-issue = Signaler un problème
+nav-sign-up = S’inscrire
+nav-log-in = Se connecter
+nav-language = Langue
+nav-main = Navigation principale
+nav-menu = Menu
+
+## Accessibility
+
+a11y-skip-link = Aller au contenu
+a11y-navigated = Navigation vers { $page }
+a11y-page-changed = Page changée
+
+## Pages
+
+home-title = Page d’accueil
+home-playground = Bac à sable Bulma
+demos-default = Ceci est la vue par défaut de /demos
+demos-select = Choisissez une démo pour en voir le détail
+demos-last-visit = La dernière fois, vous étiez sur { $demo }.
+demos-sub-route-default = vue par défaut des autres sous-routes
+demos-nested-route-default = vue par défaut de demos/demo_nested_route
+demos-nested-route-select = choisissez-en une pour aller plus loin
+not-found = Page introuvable
+
+update-available = Une nouvelle version de l’application est disponible.
+update-reload = Recharger
+
+## Demo menu
+
+menu-title = Index des démos
+menu-basic-component = composants de base
+menu-components-and-props = composants et props
+menu-basic-iteration = itération simple
+menu-form-and-input = formulaires et saisie
+menu-error-handling = gestion des erreurs
+menu-reactivity = réactivité
+menu-parent-child-communication = communication parent-enfant
+menu-async = asynchrone
+menu-control-flow = flux de contrôle
+menu-nested-route = routes imbriquées
+menu-modal = dialogues
+menu-virtual-list = liste virtuelle
+menu-benchmark = benchmark de liste
+menu-data-table = tableau de données
+menu-paging = pagination
+menu-server-push = push serveur
+menu-worker = web worker
+menu-i18n = langues
+
+## Lists
+
+list-loading = Chargement…
+list-end = C’est tout.
+
+## Demo paging
+
+paging-title = Démo pagination
+paging-intro = Le faux serveur contient { $total } articles et les envoie par { $per_page }, à travers le réseau simulé.
+paging-pagination = Pagination
+paging-name = Nom
+paging-price = Prix
+paging-price-value = { NUMBER($price, style: "currency", currency: "EUR") }
+paging-infinite-scroll = Défilement infini
+paging-loaded = { $loaded } sur { $total } chargés
+paging-nothing-loaded = Rien de chargé pour l’instant
+paging-start-over = Recommencer
+
+## Demo languages
+
+i18n-title = Démo langues
+i18n-intro = Chaque texte de cette page vient du fichier Fluent de la langue choisie dans la barre de navigation, { $file }.
+i18n-plurals = Pluriels
+i18n-items = Articles
+i18n-cart =
+    { $count ->
+        [0] Votre panier est vide.
+        [one] Il y a { $count } article dans votre panier.
+       *[other] Il y a { $count } articles dans votre panier.
+    }
+i18n-numbers = Nombres
+i18n-amount = Montant
+i18n-number = Nombre : { $amount }
+i18n-currency = Prix : { NUMBER($amount, style: "currency", currency: "EUR") }
+i18n-percent = Part de mille : { NUMBER($share, style: "percent", maximumFractionDigits: 1) }
+i18n-rounded = Arrondi : { NUMBER($amount, maximumFractionDigits: 0) }
+
+## Failures
+
+error-unavailable = le serveur est indisponible
+error-timeout = la requête a expiré
+error-not-found = introuvable
+error-offline = vous êtes hors ligne
+error-aborted = la requête a été annulée
+failures-inject = Provoquer des erreurs
+failures-never = jamais
+failures-sometimes = parfois
+failures-always = toujours
+failures-load-failed = Échec du chargement : { $error }
+failures-title = Échec du chargement
+failures-retry = Réessayer
+
+## Network
+
+network-summary = Réseau : { $latency } ms (+ jusqu’à { $jitter } ms), { $failures } % d’erreurs
+network-offline-summary = Réseau : hors ligne
+network-fast = rapide
+network-normal = normal
+network-slow = lent
+network-flaky = instable
+network-latency = Latence (ms)
+network-jitter = Gigue (ms)
+network-failure-rate = Taux d’erreurs ({ $failures } %)
+network-offline = hors ligne
+
+## Query cache
+
+query-cache = Cache des requêtes
+query-key = Clé
+query-status = État
+query-age = Âge
+query-age-seconds = { NUMBER($seconds, maximumFractionDigits: 0) } s
+query-fetching = en chargement
+query-fresh = à jour
+query-stale = périmé
+query-invalidate = Invalider
+query-invalidate-all = Tout invalider
+
+## Timeline
+
+timeline-loading = chargement
+timeline-fallback = remplacement affiché
+timeline-stale = contenu précédent conservé
+timeline-span = { $phase } : de { NUMBER($start, maximumFractionDigits: 0) } ms à { NUMBER($end, maximumFractionDigits: 0) } ms ({ NUMBER($duration, maximumFractionDigits: 0) } ms)
+timeline-clear = Effacer
+timeline-seconds = { NUMBER($seconds, maximumFractionDigits: 1) } s
+
+## Shared controls
+
+history-undo = Annuler
+history-redo = Rétablir
+reorder-hint = Faites glisser, ou utilisez les flèches, pour réordonner
+reorder-move = Déplacer la ligne { $row } sur { $rows }
+confirm-title = Veuillez confirmer
+close = fermer
+confirm-ok = Confirmer
+confirm-cancel = Annuler
+
+## Data table
+
+table-filter-placeholder = filtrer
+table-filter = Filtrer { $column }
+table-select-row = Sélectionner la ligne
+table-select-visible = Sélectionner toutes les lignes visibles
+
+## Widgets
+
+socket-connecting = connexion
+socket-connected = connecté
+socket-reconnecting = reconnexion (tentative { $attempt })
+socket-disconnected = déconnecté
+pagination = pagination
+pagination-previous = Précédent
+pagination-next = Suivant
+pagination-page = Page { $page }
+
+## Bulma playground
+
+playground-layout = Mise en page
+playground-columns = Colonnes
+playground-gap = Espacement
+playground-breakpoint = Côte à côte à partir de
+playground-screen = Tailles pour
+playground-all-screens = tous les écrans
+playground-default = par défaut
+playground-size = Taille
+playground-offset = Décalage
+playground-color = Couleur
+playground-column-size = Taille de la colonne { $column }
+playground-column-offset = Décalage de la colonne { $column }
+playground-column-color = Couleur de la colonne { $column }
+playground-preview = Aperçu
+playground-export = Exporter le balisage view!
+playground-exported = Balisage exporté
+
+## Iteration demo
+
+iteration-title = Démo itération : vues statiques et vues dynamiques
+iteration-static = Liste statique
+iteration-wrap = on peut les envelopper dans des <li>
+iteration-static-interface = Une liste statique n’oblige pas l’interface à être statique.
+iteration-dynamic = Liste dynamique
+iteration-dynamic-intro = Utilisez ce modèle si les lignes de votre liste changent.
+iteration-reorder = Faites glisser une ligne par sa poignée, ou donnez le focus à la poignée et utilisez les flèches. Chaque compteur garde sa valeur en bougeant, car sa ligne est identifiée par l’id du compteur, pas par sa position.
+iteration-add = Ajouter un compteur
+iteration-remove = Supprimer
+iteration-remove-question = Supprimer le compteur à { $count } ?
+iteration-complex-title = Démo itération sur des données plus complexes
+iteration-update = Mettre à jour les valeurs
+iteration-not-reactive = Cela ne marche pas, car each.value n’est pas un type réactif
+iteration-memo = Utilisez `create_memo` pour créer un calcul dérivé qui ne déclenche une mise à jour réactive que lorsque sa valeur change
+table-title = Tableau de données triable et filtrable
+table-intro = Cliquez sur un en-tête pour trier, Maj+clic pour trier sur plusieurs colonnes. Doubler une valeur ne redessine que cette ligne.
+table-double-random = Doubler une ligne au hasard
+table-double-selected = Doubler les lignes sélectionnées
+table-key-contains = La clé contient
+table-value-at-least = Valeur minimale
+table-url = La recherche est écrite dans l’URL dès que vous arrêtez de taper, en remplaçant l’entrée d’historique ; chaque valeur minimale est une nouvelle entrée, donc Retour les parcourt une à une.
+table-selected = { $count } sélectionnée(s)
+table-key = Clé
+table-value = Valeur
+table-parity = Parité
+table-even = pair
+table-odd = impair
+
+## Nested route demo
+
+nested-select = Choisissez pour en voir plus
+nested-home = Accueil de la route imbriquée
+nested-contact-info-placeholder = (Coordonnées)
+nested-select-user = Choisissez une personne pour voir ses coordonnées.
+nested-title = Démo route imbriquée
+nested-route-home = Accueil de la route de démo
+nested-contacts = Contacts
+nested-form-example = Exemple de formulaire
+nested-manual = Envoi manuel
+nested-automatic = Envoi automatique
+nested-name = Nom
+nested-number = Nombre
+nested-option = Choix
+nested-submit = Envoyer
+nested-filter = Filtrer
+nested-filter-contacts = Filtrer les contacts
+nested-not-found = Personne introuvable.
+nested-contact-info = Coordonnées
+nested-conversations = Conversations
+chat-unreachable = Le serveur de discussion est injoignable. Lancez-le avec { $command }, puis reconnectez-vous.
+reconnect = Se reconnecter
+chat-placeholder = Dites quelque chose
+chat-message = Message
+chat-send = Envoyer
+
+## Worker demo
+
+worker-crashed = le worker a planté
+worker-cancelled = la tâche a été annulée
+worker-rejected = le worker a refusé la tâche : { $error }
+worker-title = Démo web worker
+worker-intro = Le travail ci-dessous tourne dans un web worker, construit à partir de ce crate, pour que la page reste réactive pendant ce temps. L’horloge s’arrête dès que la page elle-même est occupée.
+worker-order = Les tâches s’exécutent l’une après l’autre, dans l’ordre des demandes. Une tâche remplacée avant d’être terminée est annulée.
+worker-cancel-all = Tout annuler
+worker-clock = Horloge : { NUMBER($seconds, minimumFractionDigits: 1, maximumFractionDigits: 1) } s
+worker-primes = { $count } nombres premiers sous { $below }, le plus grand { $largest }
+worker-no-primes = Aucun nombre premier sous { $below }
+worker-primes-title = Compter les nombres premiers
+worker-count-below = Compter les nombres premiers sous
+worker-below = sous { $limit }
+worker-count-in-worker = Compter dans le worker
+worker-count-on-main = Compter dans le thread principal
+worker-on-main = Dans le thread principal : { $result } en { NUMBER($ms, maximumFractionDigits: 0) } ms, la page figée pendant ce temps.
+worker-mandelbrot = Ensemble de Mandelbrot
+worker-iterations-label = Itérations
+worker-iterations = { $iterations } itérations
+worker-zoom-out = Dézoomer
+worker-reset = Réinitialiser
+worker-zoom = { NUMBER($zoom, maximumFractionDigits: 0) }×
+worker-canvas = L’ensemble de Mandelbrot. Cliquez pour zoomer à cet endroit.
+
+## Basics, control flow and error handling demos
+
+basics-title = Composant de base (3.1 et 3.2)
+basics-click-button = Bouton cliquable simple
+basics-click-me-count = Cliquez-moi : { $count }
+basics-dynamic-classes = Classes dynamiques
+basics-progress-hint = cliquez sur le bouton ci-dessous pour changer la barre de progression
+basics-change-progress = Cliquez-moi pour changer la barre de progression : { $count }
+basics-dynamic-style = Style dynamique
+basics-move = Cliquez pour déplacer
+basics-dynamic-attributes = Attributs dynamiques
+basics-derived = Signaux dérivés
+basics-derived-intro = Montre qu’un signal peut être dérivé d’un autre signal
+basics-double-count = Double du compteur : { $count }
+props-title = Composants et props
+props-intro = L’exemple suivant montre comment passer la prop progress
+props-click-me = Cliquez-moi
+props-into = Montre l’usage de #[prop(into)]
+control-title = Démo flux de contrôle
+control-intro = Sert à décider si cette partie de la vue doit être rendue ou non
+control-demo-01 = démo 01
+control-ding = Ding ding ding !
+control-big-component = grand composant
+control-small-component = petit composant
+control-demo-02 = démo 02 : rendu conditionnel
+control-careful = Attention à ReadSignal<i32> dans la condition, car la réactivité déclenche un nouveau rendu
+control-click-me = cliquez-moi : { $count }
+control-big = Grand
+control-small = Petit
+control-show = la solution est d’utiliser <Show/>
+control-demo-03 = Démo03 : conversion de type pour match
+control-one = Un
+control-two = Deux
+control-value = valeur
+errors-title = Démo gestion des erreurs
+errors-type-number = Tapez un nombre (ou pas !)
+errors-type-not-number = Tapez un nombre (ou quelque chose qui n’en est pas un !)
+errors-you-entered = Vous avez saisi
+errors-not-a-number = Pas un nombre !
+errors-list = Erreurs :
+
+## Form and modal demos
+
+form-title = Démo formulaire et saisie
+form-controlled = Saisie contrôlée
+form-name = Nom
+form-name-is = Le nom est : { $name }
+form-settled-name = Nom une fois la saisie arrêtée : { $name }
+form-uncontrolled = Saisie non contrôlée
+form-submit = Envoyer
+form-text-area = Zone de texte
+form-text = Texte
+form-text-is = le texte est : { $text }
+form-words = mots (recomptés au plus toutes les 250 ms) : { $count }
+form-select = Démo sélection
+form-letter = Lettre
+form-selection-is = Votre sélection est : { $value }
+modal-title = Démo modale
+modal-intro = Les modales sont rendues via un <Portal/> au niveau du body. Le focus entre dans la boîte de dialogue, Tab y reste, Échap ou un clic sur l’arrière-plan la ferme, et le focus revient au bouton qui l’a ouverte.
+modal-plain = Une modale simple
+modal-open = Ouvrir la modale
+modal-hello = Bonjour depuis le portail
+modal-try = Essayez Tab, Maj+Tab et Échap.
+modal-focus = Le focus reste ici
+modal-close = Fermer
+modal-confirm-remove = Confirmer avant de supprimer
+
+## Parent and children, virtual list demos
+
+parent-title = Démo communication parent enfants
+parent-down = Le parent communique facilement avec l’enfant : il passe un ReadSignal, un Signal ou même un MaybeSignal en prop
+parent-up = Comment un enfant peut-il signaler des événements ou des changements d’état au parent ?
+parent-approach = approche { $number }
+parent-approach-version = Approche { $number }, version { $version }
+parent-write-signal = Passer un WriteSignal du parent à l’enfant
+parent-callback = Utiliser un callback ou une closure
+parent-event-listener = Utiliser un écouteur d’événements
+parent-context = Fournir un contexte
+parent-context-intro = Les contextes sont identifiés par le type des données fournies et vivent dans un arbre descendant qui suit les contours de votre arbre d’interface.
+parent-toggled = Basculé ? { $toggled ->
+        [true] oui
+       *[other] non
+    }
+parent-toggle = Basculer
+parent-layout = Ma mise en page
+virtual-title = Démo liste virtuelle
+virtual-intro = Les deux listes contiennent 100 000 lignes, mais seules les lignes visibles (plus quelques-unes au-dessus et en dessous) sont montées. Les lignes ont une clé comme dans <For/> : cliquez sur un compteur, échangez les deux premières lignes et le compte suit sa ligne. Les lignes défilées loin hors de vue sont démontées, leur état local est donc perdu à leur retour.
+virtual-fixed = Hauteur de ligne fixe
+virtual-swap = Échanger les deux premières lignes
+virtual-measured = Hauteur de ligne mesurée
+virtual-clicked = Cliqué { $count } fois
+
+## Server push and reactivity demos
+
+push-title = Démo push serveur
+push-intro = Le serveur de démo envoie chaque seconde des métriques inventées sous forme de Server-Sent Events. Il termine le flux toutes les 30 secondes, comme le ferait un proxy avec un délai d’expiration ; le navigateur se reconnecte et le serveur renvoie ce qui a été manqué, d’après l’id du dernier événement. Arrêtez puis relancez le serveur pour voir le flux réessayer tout seul.
+push-last-event = Id du dernier événement : { $id }
+push-no-event = aucun
+push-unreachable = Le flux de métriques est injoignable. Lancez-le avec { $command }, puis reconnectez-vous.
+push-cpu = Processeur
+push-memory = Mémoire
+push-requests = Requêtes
+reactivity-title = Démo réactivité
+reactivity-with-update = Démo01 : utiliser with et update
+reactivity-with-macro = Démo02 : la macro with!
+reactivity-name = le nom est : { $name }
+reactivity-depend = Rendre des signaux dépendants les uns des autres
+reactivity-version = Version { $code }
+reactivity-first-name = Prénom
+reactivity-last-name = Nom
+reactivity-show-last-name = Afficher le nom
+reactivity-manual = Version manuelle
+reactivity-is = { $code } vaut
+reactivity-value = Valeur
+
+## Benchmark demo
+
+benchmark-fine = clé id, libellé en signal
+benchmark-coarse = clé (id, libellé), données simples
+benchmark-index = clé index, mémo par ligne
+benchmark-create-1k = Créer 1 000 lignes
+benchmark-create-10k = Créer 10 000 lignes
+benchmark-append-1k = Ajouter 1 000 lignes
+benchmark-update = Mettre à jour une ligne sur 10
+benchmark-swap = Échanger les lignes 2 et 999
+benchmark-select = Sélectionner la ligne 2
+benchmark-remove = Supprimer la ligne 2
+benchmark-clear = Vider
+benchmark-title = Banc d’essai des listes à clés
+benchmark-intro = Les opérations de js-framework-benchmark sur un tableau <For/>, chronométrées avec la Performance API. Changez de stratégie pour comparer la clé par id avec un signal par libellé, la clé par ligne entière et la clé par index.
+benchmark-reset = Réinitialiser les résultats
+benchmark-strategy = Stratégie
+benchmark-operation = Opération
+benchmark-rows-after = Lignes après
+benchmark-sync = Synchrone (ms)
+benchmark-frame = Image suivante (ms)
+benchmark-ms = { NUMBER($ms, minimumFractionDigits: 1, maximumFractionDigits: 1) }
+
+## Async demo
+
+async-title = Démo async
+async-returned = Le serveur a renvoyé { $value } après { $attempts } tentative(s)
+async-loading = Chargement...
+async-idle = Inactif.
+async-resource = Charger des données avec une resource
+async-click-me = Cliquez-moi
+async-two-v1 = Démo : attendre deux resources v1
+async-two-v2 = Démo : attendre deux resources v2
+async-my-data = Mes données
+async-some-a = un A : { $value }
+async-some-b = un B : { $value }
+async-reload-a = Recharger A
+async-reload-b = Recharger B
+async-await = Démo : utiliser <Await/> pour attendre un future avant le rendu
+async-monkeys = { $monkeys } petits singes sautent sur le lit.
+async-transition = Démo Transition
+async-tab = Onglet { $tab }
+async-loading-initial = Chargement des données initiales...
+async-hang-on = Un instant...
+async-loads = { $started } chargements lancés, { $cancelled } annulés
+async-todo-added = Tâche ajoutée avec l’id { $id }
+async-todo-failed = Impossible d’ajouter la tâche : { $error }
+async-action = Démo Action
+async-adding = Ajout de « { $todo } »...
+async-what-to-do = Que devez-vous faire ?
+async-add-todo = Ajouter la tâche
+async-submitted = Envoyé :
+async-pending = En attente :
+async-todo-id = Id de la tâche :
+async-todos = Tâches
+async-todos-refreshing = Tâches (actualisation...)
+async-notifications = Démo notifications
+async-info = Info
+async-success = Succès
+async-warning = Avertissement
+async-danger = Danger
+async-notification = Ceci est une notification { $level }
+async-search = Démo recherche temporisée
+async-search-fruits = Chercher des fruits
+async-searches = { $count } recherches envoyées
+async-searching = Recherche...
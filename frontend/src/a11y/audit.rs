@@ -0,0 +1,476 @@
+//! An accessibility audit for tests: lists what keeps a page from working with a keyboard or a
+//! screen reader.
+//!
+//! The checks run on HTML, natively. The pages themselves are audited in a headless browser, see
+//! `pages_pass`: the app is mounted the way it ships, and goes through its routes. The audit can
+//! only see a page as it first shows, so a demo has to show what matters without any clicking.
+
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+
+/// Elements that make up a paragraph; a `<label>` holds nothing else.
+const PHRASING: &[&str] = &[
+    "a", "abbr", "b", "br", "button", "code", "em", "i", "img", "input", "kbd", "meter", "output",
+    "progress", "q", "s", "select", "small", "span", "strong", "sub", "sup", "svg", "textarea",
+    "time", "u",
+];
+/// Roles with a meaning of their own, which need a name to tell them apart.
+const LANDMARKS: &[(&str, &str)] = &[
+    ("nav", "navigation"),
+    ("aside", "complementary"),
+    ("section[aria-label], section[aria-labelledby]", "region"),
+];
+
+/// What is wrong with the page `html` for keyboards and screen readers, one line each.
+pub fn audit(html: &str) -> Vec<String> {
+    let page = Html::parse_document(html);
+    let mut problems = Vec::new();
+    duplicate_ids(&page, &mut problems);
+    unlabelled_controls(&page, &mut problems);
+    crowded_labels(&page, &mut problems);
+    missing_alt_text(&page, &mut problems);
+    unnamed_actions(&page, &mut problems);
+    landmarks(&page, &mut problems);
+    headings(&page, &mut problems);
+    problems
+}
+
+fn select<'a>(page: &'a Html, selectors: &str) -> impl Iterator<Item = ElementRef<'a>> + 'a {
+    let selector = Selector::parse(selectors).expect("valid selector");
+    page.select(&selector).collect::<Vec<_>>().into_iter()
+}
+
+/// How `element` shows in a problem: its tag, and its id or class.
+fn describe(element: ElementRef) -> String {
+    let value = element.value();
+    match (value.id(), value.attr("class")) {
+        (Some(id), _) => format!("<{} id=\"{id}\">", value.name()),
+        (None, Some(class)) => format!("<{} class=\"{class}\">", value.name()),
+        (None, None) => format!("<{}>", value.name()),
+    }
+}
+
+fn text(element: ElementRef) -> String {
+    element.text().collect::<String>().trim().to_string()
+}
+
+/// Whether `element` is named by an attribute, rather than by its content.
+fn has_aria_name(element: ElementRef) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|name| {
+            element
+                .value()
+                .attr(name)
+                .is_some_and(|value| !value.trim().is_empty())
+        })
+}
+
+fn ancestors_named<'a>(
+    element: ElementRef<'a>,
+    tag: &'a str,
+) -> impl Iterator<Item = ElementRef<'a>> {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .filter(move |ancestor| ancestor.value().name() == tag)
+}
+
+/// Ids on more than one element: a `<label for>` or `aria-labelledby` only finds the first.
+fn duplicate_ids(page: &Html, problems: &mut Vec<String>) {
+    let mut counts = HashMap::<&str, usize>::new();
+    for element in select(page, "[id]") {
+        if let Some(id) = element.value().id() {
+            *counts.entry(id).or_default() += 1;
+        }
+    }
+    let mut duplicates = counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .collect::<Vec<_>>();
+    duplicates.sort();
+    for (id, count) in duplicates {
+        problems.push(format!("{count} elements with id \"{id}\""));
+    }
+}
+
+/// Inputs, selects and text areas that nothing tells what they are for.
+fn unlabelled_controls(page: &Html, problems: &mut Vec<String>) {
+    let labelled = select(page, "label[for]")
+        .filter_map(|label| label.value().attr("for"))
+        .collect::<Vec<_>>();
+    let controls = select(
+        page,
+        // an image input is a button, named by its alt text
+        "input:not([type=hidden]):not([type=submit]):not([type=button]):not([type=reset]):not([type=image]), select, textarea",
+    );
+    for control in controls {
+        let wrapped = ancestors_named(control, "label").next().is_some();
+        let pointed_at = control
+            .value()
+            .id()
+            .is_some_and(|id| labelled.contains(&id));
+        if !(wrapped || pointed_at || has_aria_name(control)) {
+            problems.push(format!("{} has no label", describe(control)));
+        }
+    }
+}
+
+/// Labels holding more than a control and its text: a screen reader reads all of it as the
+/// control's name, and a click anywhere on it lands on the control.
+fn crowded_labels(page: &Html, problems: &mut Vec<String>) {
+    for label in select(page, "label") {
+        let inside = label
+            .descendants()
+            .filter_map(ElementRef::wrap)
+            .skip(1)
+            .map(|element| element.value().name())
+            .collect::<Vec<_>>();
+        if let Some(block) = inside.iter().find(|name| !PHRASING.contains(name)) {
+            problems.push(format!("{} holds a <{block}>", describe(label)));
+        }
+        let controls = inside
+            .iter()
+            .filter(|name| matches!(**name, "input" | "select" | "textarea"))
+            .count();
+        if controls > 1 {
+            problems.push(format!("{} holds {controls} controls", describe(label)));
+        }
+    }
+}
+
+/// Images without a text alternative. `alt=""` is fine: it marks an image as decoration.
+fn missing_alt_text(page: &Html, problems: &mut Vec<String>) {
+    for image in select(page, "img:not([alt]), input[type=image]:not([alt])") {
+        problems.push(format!("{} has no alt text", describe(image)));
+    }
+    for svg in select(page, "svg:not([aria-hidden=true])") {
+        let titled = svg
+            .children()
+            .filter_map(ElementRef::wrap)
+            .any(|child| child.value().name() == "title");
+        if svg.value().attr("role") != Some("img") || !(titled || has_aria_name(svg)) {
+            problems.push(format!(
+                "{} is neither role=\"img\" with a name, nor aria-hidden",
+                describe(svg)
+            ));
+        }
+    }
+}
+
+/// Buttons and links with nothing to read out, like an icon alone.
+fn unnamed_actions(page: &Html, problems: &mut Vec<String>) {
+    for action in select(page, "button, a[href], [role=button]") {
+        let image_named = select_in(action, "img[alt]").any(|image| {
+            !image
+                .value()
+                .attr("alt")
+                .unwrap_or_default()
+                .trim()
+                .is_empty()
+        });
+        if text(action).is_empty() && !has_aria_name(action) && !image_named {
+            problems.push(format!("{} has no name", describe(action)));
+        }
+    }
+}
+
+fn select_in<'a>(
+    element: ElementRef<'a>,
+    selectors: &str,
+) -> impl Iterator<Item = ElementRef<'a>> + 'a {
+    let selector = Selector::parse(selectors).expect("valid selector");
+    element.select(&selector).collect::<Vec<_>>().into_iter()
+}
+
+/// The page's landmarks, which screen readers list to jump between: one `<main>`, one
+/// `<header>` at the top, and a name for each of the others that sets it apart.
+fn landmarks(page: &Html, problems: &mut Vec<String>) {
+    let mains = select(page, "main, [role=main]").count();
+    if mains != 1 {
+        problems.push(format!("{mains} main landmarks, instead of 1"));
+    }
+    // only a `<header>` outside of the page's sections is the banner
+    let banners = select(page, "header, [role=banner]")
+        .filter(|header| {
+            ["main", "nav", "section", "article", "aside"]
+                .iter()
+                .all(|tag| ancestors_named(*header, tag).next().is_none())
+        })
+        .count();
+    if banners != 1 {
+        problems.push(format!("{banners} banner landmarks, instead of 1"));
+    }
+
+    for (selectors, role) in LANDMARKS {
+        let selectors = format!("{selectors}, [role={role}]");
+        let mut names = HashMap::<String, usize>::new();
+        for landmark in select(page, &selectors) {
+            let name = landmark_name(page, landmark);
+            if name.is_empty() {
+                problems.push(format!(
+                    "{} is a {role} landmark without a name",
+                    describe(landmark)
+                ));
+            } else {
+                *names.entry(name).or_default() += 1;
+            }
+        }
+        for (name, count) in names {
+            if count > 1 {
+                problems.push(format!("{count} {role} landmarks named \"{name}\""));
+            }
+        }
+    }
+}
+
+fn landmark_name(page: &Html, landmark: ElementRef) -> String {
+    let value = landmark.value();
+    if let Some(label) = value.attr("aria-label") {
+        return label.trim().to_string();
+    }
+    value
+        .attr("aria-labelledby")
+        .map(|ids| {
+            ids.split_whitespace()
+                .filter_map(|id| select(page, &format!("#{id}")).next())
+                .map(text)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default()
+}
+
+/// One `<h1>` in `<main>`, which focus moves to on navigation.
+fn headings(page: &Html, problems: &mut Vec<String>) {
+    let headings = select(page, "main h1").count();
+    if headings != 1 {
+        problems.push(format!("{headings} <h1> in <main>, instead of 1"));
+    }
+}
+
+/// The pages to audit, by path: home, every demo of the demo menu, and the routes of the nested
+/// route demo. A new demo goes in this list.
+#[cfg(target_arch = "wasm32")]
+const PAGES: &[&str] = &[
+    "/",
+    "/demos/basic_component",
+    "/demos/components_and_pros",
+    "/demos/demo_basic_iteration",
+    "/demos/demo_form_and_input",
+    "/demos/demo_error_handling",
+    "/demos/demo_reactivity",
+    "/demos/demo_parent_children_communication",
+    "/demos/demo_async",
+    "/demos/control_flow",
+    "/demos/demo_nested_route",
+    "/demos/demo_nested_route/home",
+    "/demos/demo_nested_route/contacts",
+    "/demos/demo_nested_route/contacts/alice",
+    "/demos/demo_nested_route/contacts/alice/conversations",
+    "/demos/demo_nested_route/form_example",
+    "/demos/demo_modal",
+    "/demos/demo_virtual_list",
+    "/demos/demo_benchmark",
+    "/demos/demo_data_table",
+    "/demos/demo_paging",
+    "/demos/demo_server_push",
+    "/demos/demo_worker",
+    "/demos/demo_i18n",
+];
+
+/// Run with `cargo test --target wasm32-unknown-unknown`, see the README.
+#[cfg(target_arch = "wasm32")]
+mod in_browser {
+    use super::{audit, PAGES};
+    use crate::App;
+    use gloo_timers::future::TimeoutFuture;
+    use leptos::*;
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// How long a page gets to show after navigating to it.
+    const RENDER_MS: u32 = 100;
+
+    /// Mounts `App` once, and goes to each of `PAGES` the way the back and forward buttons do.
+    #[wasm_bindgen_test]
+    async fn pages_pass() {
+        let root = document().create_element("div").expect("a <div>");
+        document()
+            .body()
+            .expect("the page has a <body>")
+            .append_child(&root)
+            .expect("a <div> fits in the <body>");
+        mount_to(root.clone().unchecked_into(), App);
+
+        let mut failures = Vec::new();
+        for path in PAGES {
+            window()
+                .history()
+                .expect("history is supported")
+                .push_state_with_url(&JsValue::NULL, "", Some(path))
+                .expect("a path on the page's origin");
+            // the router follows the URL on `popstate`, which `pushState` doesn't fire itself
+            let popstate = web_sys::Event::new("popstate").expect("a plain event");
+            _ = window().dispatch_event(&popstate);
+            TimeoutFuture::new(RENDER_MS).await;
+
+            failures.extend(
+                audit(&root.inner_html())
+                    .into_iter()
+                    .map(|problem| format!("{path}: {problem}")),
+            );
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
+
+/// A page that passes, with `body` after the `<h1>` of its `<main>`.
+fn page(body: &str) -> String {
+    format!(
+        r#"<header><nav aria-label="Main"><a href="/">Home</a></nav></header><main><h1>Title</h1>{body}</main>"#
+    )
+}
+
+#[test]
+fn a_sound_page_passes() {
+    let body = r#"
+        <label for="name">Name</label><input id="name">
+        <label>Age <input type="number"></label>
+        <select aria-label="Size"><option>S</option></select>
+        <input type="hidden" name="token">
+        <img src="logo.png" alt="">
+        <svg aria-hidden="true"></svg>
+        <svg role="img"><title>Chart</title></svg>
+        <button><img src="x.png" alt="Close"></button>
+        <a href="/more" aria-label="More"></a>
+    "#;
+    assert_eq!(audit(&page(body)), Vec::<String>::new());
+}
+
+#[test]
+fn finds_duplicate_ids() {
+    let body = r#"
+        <label for="name">Name</label><input id="name">
+        <label for="name">Other name</label><input id="name">
+    "#;
+    assert_eq!(audit(&page(body)), ["2 elements with id \"name\""]);
+}
+
+#[test]
+fn finds_unlabelled_controls() {
+    let body = r#"
+        <input id="name"><label for="other">Name</label>
+        <select class="size"></select>
+        <textarea aria-label=" "></textarea>
+    "#;
+    assert_eq!(
+        audit(&page(body)),
+        [
+            "<input id=\"name\"> has no label",
+            "<select class=\"size\"> has no label",
+            "<textarea> has no label",
+        ]
+    );
+}
+
+#[test]
+fn finds_crowded_labels() {
+    let body = r#"
+        <label id="block">Name <div><input></div></label>
+        <label id="two">From <input> to <input></label>
+    "#;
+    assert_eq!(
+        audit(&page(body)),
+        [
+            "<label id=\"block\"> holds a <div>",
+            "<label id=\"two\"> holds 2 controls",
+        ]
+    );
+}
+
+#[test]
+fn finds_images_without_alt_text() {
+    let body = r#"
+        <img src="chart.png">
+        <input type="image" src="go.png">
+        <svg class="icon"></svg>
+        <svg role="img" class="unnamed"></svg>
+    "#;
+    assert_eq!(
+        audit(&page(body)),
+        [
+            "<img> has no alt text",
+            "<input> has no alt text",
+            "<svg class=\"icon\"> is neither role=\"img\" with a name, nor aria-hidden",
+            "<svg class=\"unnamed\"> is neither role=\"img\" with a name, nor aria-hidden",
+        ]
+    );
+}
+
+#[test]
+fn finds_unnamed_buttons_and_links() {
+    let body = r#"
+        <button class="delete"><span class="icon"></span></button>
+        <a href="/settings"><img src="gear.png" alt=""></a>
+        <span role="button" id="toggle"></span>
+    "#;
+    assert_eq!(
+        audit(&page(body)),
+        [
+            "<button class=\"delete\"> has no name",
+            "<a> has no name",
+            "<span id=\"toggle\"> has no name",
+        ]
+    );
+}
+
+#[test]
+fn finds_missing_and_extra_main_and_banner_landmarks() {
+    assert_eq!(
+        audit(&page("<main><p>Again</p></main>")),
+        ["2 main landmarks, instead of 1"]
+    );
+    assert_eq!(
+        audit(&page("<header>Second banner</header>")),
+        Vec::<String>::new(),
+        "a header inside <main> is no banner"
+    );
+    assert_eq!(
+        audit("<div><h1>Title</h1></div>"),
+        [
+            "0 main landmarks, instead of 1",
+            "0 banner landmarks, instead of 1",
+            "0 <h1> in <main>, instead of 1",
+        ]
+    );
+}
+
+#[test]
+fn finds_landmarks_without_telling_names() {
+    let body = r#"
+        <nav class="pages"></nav>
+        <aside aria-label="Main"></aside>
+        <section aria-labelledby="filters-title"><h2 id="filters-title">Filters</h2></section>
+        <section aria-label="Filters"></section>
+        <nav aria-label="Main"></nav>
+    "#;
+    assert_eq!(
+        audit(&page(body)),
+        [
+            "<nav class=\"pages\"> is a navigation landmark without a name",
+            "2 navigation landmarks named \"Main\"",
+            "2 region landmarks named \"Filters\"",
+        ]
+    );
+}
+
+#[test]
+fn finds_pages_without_one_h1() {
+    assert_eq!(
+        audit(&page("<h1>Another</h1>")),
+        ["2 <h1> in <main>, instead of 1"]
+    );
+}
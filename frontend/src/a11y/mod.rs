@@ -0,0 +1,132 @@
+//! Keyboard and screen reader support that spans pages: the skip link, and moving focus to
+//! the new page on navigation, announcing it.
+
+#[cfg(test)]
+pub mod audit;
+
+use crate::i18n::t;
+use leptos::*;
+use leptos_router::use_location;
+use std::cell::Cell;
+use wasm_bindgen::JsCast;
+
+/// The id of the page's `<main>`, which the skip link goes to.
+pub const MAIN_ID: &str = "main-content";
+
+thread_local! {
+    static NEXT_ID: Cell<u32> = const { Cell::new(0) };
+}
+
+/// An element id, `prefix` and a number no other call gets: for the `<label for>` of a control
+/// in a component a page can show more than once.
+pub fn unique_id(prefix: &str) -> String {
+    let id = NEXT_ID.get();
+    NEXT_ID.set(id.wrapping_add(1));
+    format!("{prefix}-{id}")
+}
+
+/// "Skip to content", out of sight until tabbed to. Put it first in the page, so it is the first
+/// thing a keyboard gets to.
+#[component]
+pub fn SkipLink() -> impl IntoView {
+    let (focused, set_focused) = create_signal(false);
+
+    view! {
+        <a
+            href=format!("#{MAIN_ID}")
+            class="button is-primary"
+            class:is-sr-only=move || !focused()
+            style="position: absolute; top: 0.5rem; left: 0.5rem; z-index: 50;"
+            on:focus=move |_| set_focused(true)
+            on:blur=move |_| set_focused(false)
+            // the router only updates the hash, and browsers don't all move focus on their own
+            on:click=move |_| {
+                if let Some(main) = document().get_element_by_id(MAIN_ID) {
+                    focus(&main);
+                }
+            }
+        >
+
+            {t!("a11y-skip-link")}
+        </a>
+    }
+}
+
+/// Moves focus to the heading of the new page on navigation, and announces it. Put it once
+/// inside the `<Router>`.
+///
+/// Otherwise focus stays on the link that was followed, and nothing tells a screen reader that
+/// anything happened. Sub-routes of a demo, like the contacts of the nested route demo, leave
+/// focus where it is: they only change part of the page.
+#[component]
+pub fn RouteAnnouncer() -> impl IntoView {
+    let location = use_location();
+    // the heading of the page navigated to, empty for a page without one
+    let (heading, set_heading) = create_signal(None::<String>);
+
+    create_effect(move |previous: Option<String>| {
+        let page = page_of(&location.pathname.get());
+        if previous.is_some_and(|previous| previous != page) {
+            // the new page renders after this effect, so it is there by the next frame
+            request_animation_frame(move || set_heading(Some(focus_page())));
+        }
+        page
+    });
+
+    view! {
+        <div class="is-sr-only" role="status" aria-live="polite" aria-atomic="true">
+            {move || {
+                heading
+                    .get()
+                    .map(|page| {
+                        if page.is_empty() {
+                            t!("a11y-page-changed")
+                        } else {
+                            t!("a11y-navigated", page = page.clone())
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+/// The part of `path` that makes the page: up to the demo, under `/demos`.
+fn page_of(path: &str) -> String {
+    path.trim_matches('/')
+        .split('/')
+        .take(2)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Focuses the first `<h1>` in `<main>`, or `<main>` itself on a page without one, and returns
+/// the text of the heading.
+fn focus_page() -> String {
+    let document = document();
+    if let Some(heading) = document
+        .query_selector(&format!("#{MAIN_ID} h1"))
+        .ok()
+        .flatten()
+    {
+        focus(&heading);
+        return heading
+            .text_content()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+    }
+    if let Some(main) = document.get_element_by_id(MAIN_ID) {
+        focus(&main);
+    }
+    String::new()
+}
+
+/// Focuses `element`, making it focusable from script first if it isn't already.
+fn focus(element: &web_sys::Element) {
+    if !element.has_attribute("tabindex") {
+        _ = element.set_attribute("tabindex", "-1");
+    }
+    if let Some(element) = element.dyn_ref::<web_sys::HtmlElement>() {
+        _ = element.focus();
+    }
+}
@@ -0,0 +1,97 @@
+use crate::fallible::ApiError;
+use crate::query_cache::{create_query, Version};
+use futures::future::{select, Either};
+use gloo_timers::future::TimeoutFuture;
+use leptos::*;
+use std::fmt::Display;
+use std::future::Future;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{AbortController, AbortSignal, AddEventListenerOptions};
+
+/// A `create_query` whose loader can stop working once its result is no longer wanted.
+///
+/// Every load gets its own `AbortSignal`. Moving on to another key aborts the load still running
+/// for the previous one, and so does disposing of the owner. Pass the signal on to whatever does
+/// the waiting: `sleep` below, or `RequestInit::set_signal` for a `fetch`. An aborted load ends
+/// in `ApiError::Aborted`, which like any failure isn't cached.
+pub fn create_abortable_query<K, T, Fut>(
+    name: &'static str,
+    key: impl Fn() -> K + 'static,
+    fetcher: impl Fn(K, AbortSignal) -> Fut + 'static,
+) -> Resource<(K, Version), Result<T, ApiError>>
+where
+    K: Display + Clone + PartialEq + 'static,
+    T: Clone + 'static,
+    Fut: Future<Output = Result<T, ApiError>> + 'static,
+{
+    let key = create_memo(move |_| key());
+    // the running load, and the key it loads
+    let current = store_value(None::<(K, AbortController)>);
+    let abort_current = move || {
+        if let Some((_, controller)) = current.try_update_value(Option::take).flatten() {
+            controller.abort();
+        }
+    };
+    on_cleanup(abort_current);
+
+    // a key that comes from the cache starts no load, so it can't abort the previous one itself
+    create_effect(move |_| {
+        let superseded = key.with(|key| {
+            current
+                .with_value(|current| current.as_ref().is_some_and(|(loading, _)| loading != key))
+        });
+        if superseded {
+            abort_current();
+        }
+    });
+
+    create_query(name, key, move |key: K| {
+        abort_current();
+        let controller = AbortController::new().expect("AbortController is supported");
+        let signal = controller.signal();
+        current.set_value(Some((key.clone(), controller)));
+        fetcher(key, signal)
+    })
+}
+
+/// Removes the `abort` listener `aborted` added once it stops waiting, aborted or not.
+struct AbortListener<'a> {
+    signal: &'a AbortSignal,
+    listener: js_sys::Function,
+}
+
+impl Drop for AbortListener<'_> {
+    fn drop(&mut self) {
+        _ = self
+            .signal
+            .remove_event_listener_with_callback("abort", &self.listener);
+    }
+}
+
+/// Resolves once `signal` is aborted.
+async fn aborted(signal: &AbortSignal) {
+    if signal.aborted() {
+        return;
+    }
+    let mut listener = None;
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        _ = signal.add_event_listener_with_callback_and_add_event_listener_options(
+            "abort",
+            &resolve,
+            AddEventListenerOptions::new().once(true),
+        );
+        listener = Some(resolve);
+    });
+    let _listener = listener.map(|listener| AbortListener { signal, listener });
+    _ = JsFuture::from(promise).await;
+}
+
+/// Waits `ms`, unless `signal` is aborted first. Then the timer is dropped, which clears it.
+pub async fn sleep(ms: u32, signal: &AbortSignal) -> Result<(), ApiError> {
+    let timer = TimeoutFuture::new(ms);
+    let abort = Box::pin(aborted(signal));
+    match select(timer, abort).await {
+        Either::Left(_) => Ok(()),
+        Either::Right(_) => Err(ApiError::Aborted),
+    }
+}
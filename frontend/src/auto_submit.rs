@@ -0,0 +1,43 @@
+use crate::timing::create_debounced_callback;
+use leptos::*;
+use leptos_router::{Form, ToHref};
+
+/// A router `<Form/>` that submits itself whenever one of its fields changes.
+///
+/// The listeners are attached from Rust, so unlike `oninput="this.form.requestSubmit()"`
+/// it works under a Content-Security-Policy without `'unsafe-inline'` scripts. Submissions
+/// wait until the fields have been still for `debounce_ms`, so typing a word navigates once.
+#[component]
+pub fn AutoSubmitForm<A>(
+    #[prop(default = "GET")] method: &'static str,
+    action: A,
+    #[prop(default = 300)] debounce_ms: u32,
+    /// Push a history entry for every submission instead of replacing the current one.
+    #[prop(optional)]
+    push_history: bool,
+    children: Children,
+) -> impl IntoView
+where
+    A: ToHref + 'static,
+{
+    let form_ref = create_node_ref::<html::Form>();
+    let schedule_submit = create_debounced_callback(debounce_ms, move |()| {
+        if let Some(form) = form_ref.get_untracked() {
+            // goes through the submit event, so `<Form/>` turns it into a client-side navigation
+            _ = form.request_submit();
+        }
+    });
+
+    form_ref.on_load(move |form| {
+        // text fields report every keystroke as `input`; selects and checkboxes report `change`
+        _ = form
+            .on(ev::input, move |_| schedule_submit(()))
+            .on(ev::change, move |_| schedule_submit(()));
+    });
+
+    view! {
+        <Form method=method action=action node_ref=form_ref replace=!push_history>
+            {children()}
+        </Form>
+    }
+}
@@ -0,0 +1,180 @@
+//! Trunk post-build hook: puts the hashes of the page's inline scripts into its CSP.
+//!
+//! Trunk loads the app with an inline `<script type="module">` that changes with every build,
+//! and `trunk serve` adds an inline auto-reload script. This writes the `'sha256-...'` source of
+//! each in place of `{{CSP_SCRIPT_HASHES}}` in the `Content-Security-Policy` meta tag of
+//! `index.html`. A nonce in a static page would be the same for every visitor, and allow any
+//! script that carries it; a hash only allows the script it was taken of.
+//!
+//! It fails, and with it the build, when the page has no placeholder to fill in, so a policy
+//! that still reads `{{CSP_SCRIPT_HASHES}}` doesn't ship.
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+const PLACEHOLDER: &str = "{{CSP_SCRIPT_HASHES}}";
+
+fn main() {
+    let dist = std::env::var_os("TRUNK_STAGING_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("dist"));
+    let index = dist.join("index.html");
+    let html = std::fs::read_to_string(&index)
+        .unwrap_or_else(|error| panic!("reading {}: {error}", index.display()));
+
+    let html = fill_in(&html).unwrap_or_else(|error| panic!("{}: {error}", index.display()));
+
+    std::fs::write(&index, html)
+        .unwrap_or_else(|error| panic!("writing {}: {error}", index.display()));
+}
+
+/// The page with the hashes of its inline scripts in place of the placeholder.
+fn fill_in(html: &str) -> Result<String, String> {
+    if !html.contains(PLACEHOLDER) {
+        return Err(format!("no {PLACEHOLDER} in the CSP to fill in"));
+    }
+    let sources = inline_scripts(html)
+        .into_iter()
+        .map(|script| {
+            format!(
+                "'sha256-{}'",
+                BASE64_STANDARD.encode(Sha256::digest(script))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    Ok(html.replace(PLACEHOLDER, &sources))
+}
+
+/// The text of every script without a `src`, as the browser hashes it: all between its tags.
+fn inline_scripts(html: &str) -> Vec<&str> {
+    let mut scripts = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<script") {
+        let Some(length) = rest[start..].find('>') else {
+            break;
+        };
+        let end = start + length;
+        let tag = &rest[start..end];
+        rest = &rest[end + 1..];
+        // `<script ... />` is empty
+        let text = if tag.ends_with('/') {
+            ""
+        } else {
+            let Some(close) = rest.find("</script") else {
+                break;
+            };
+            let text = &rest[..close];
+            rest = &rest[close..];
+            text
+        };
+        if attribute(tag, "src").is_none() {
+            scripts.push(text);
+        }
+    }
+
+    scripts
+}
+
+/// The value of `name` in an opening tag, quoted or not, as a minifier may leave it.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{name}=");
+    let start = tag
+        .match_indices(&pattern)
+        .map(|(index, _)| index)
+        .find(|&index| tag[..index].ends_with(char::is_whitespace))?;
+    let value = &tag[start + pattern.len()..];
+    let value = match value.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let value = &value[1..];
+            &value[..value.find(quote)?]
+        }
+        _ => {
+            &value[..value
+                .find(|c: char| c.is_whitespace() || c == '>')
+                .unwrap_or(value.len())]
+        }
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_quoted_and_unquoted_attributes() {
+        assert_eq!(attribute(r#"<script nonce="a b">"#, "nonce"), Some("a b"));
+        assert_eq!(attribute("<script nonce='ab'>", "nonce"), Some("ab"));
+        assert_eq!(
+            attribute("<script nonce=ab type=module>", "nonce"),
+            Some("ab")
+        );
+        assert_eq!(attribute("<script\n  nonce=ab>", "nonce"), Some("ab"));
+        assert_eq!(attribute("<script nonce=>", "nonce"), Some(""));
+    }
+
+    #[test]
+    fn skips_attributes_that_only_end_in_the_name() {
+        assert_eq!(attribute(r#"<script data-nonce="a">"#, "nonce"), None);
+        assert_eq!(
+            attribute(r#"<script data-nonce="a" nonce="b">"#, "nonce"),
+            Some("b")
+        );
+        assert_eq!(attribute(r#"<script nonce="unclosed>"#, "nonce"), None);
+    }
+
+    #[test]
+    fn finds_inline_scripts() {
+        let page =
+            r#"<p></p><script type="module" nonce="trunk">init()</script><script>run()</script>"#;
+        assert_eq!(inline_scripts(page), ["init()", "run()"]);
+    }
+
+    #[test]
+    fn leaves_external_scripts_out() {
+        let page = r#"<script src="/app.js"></script><script src=/sw.js /><script>run()</script>"#;
+        assert_eq!(inline_scripts(page), ["run()"]);
+    }
+
+    #[test]
+    fn reads_self_closing_scripts_as_empty() {
+        assert_eq!(
+            inline_scripts("<script/><script>run()</script>"),
+            ["", "run()"]
+        );
+    }
+
+    #[test]
+    fn keeps_the_text_of_scripts_as_it_is() {
+        let page = "<script>\n  if (a > b) run();\n</script>";
+        assert_eq!(inline_scripts(page), ["\n  if (a > b) run();\n"]);
+    }
+
+    #[test]
+    fn finds_no_scripts_in_a_page_without() {
+        let page = "<html><body>no scripts, not even <scrip></body></html>";
+        assert!(inline_scripts(page).is_empty());
+    }
+
+    #[test]
+    fn fills_in_the_hashes_of_inline_scripts() {
+        let page = format!(
+            r#"<meta content="script-src 'self' {PLACEHOLDER}"><script>run()</script><script></script>"#
+        );
+        assert_eq!(
+            fill_in(&page).unwrap(),
+            "<meta content=\"script-src 'self' \
+             'sha256-AvyuiL0SD1mVY3NNxR+V2uo+lhk6RMFrytWmRt6CrJQ=' \
+             'sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU='\">\
+             <script>run()</script><script></script>"
+        );
+    }
+
+    #[test]
+    fn fails_without_a_placeholder() {
+        assert!(fill_in("<meta content=\"script-src 'self'\"><script>run()</script>").is_err());
+    }
+}
@@ -0,0 +1,188 @@
+//! Trunk post-build hook: fills in what the service worker, `sw.js`, pre-caches.
+//!
+//! Trunk names most files it builds after a hash of their content, so which files make up the
+//! app is only known after the build. This writes their URLs in place of `{{PRECACHE_URLS}}` in
+//! `sw.js`, and a hash of their content in place of `{{BUILD_VERSION}}`: a build that changes
+//! anything changes the service worker, which is how browsers find out about a new version.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+const SERVICE_WORKER: &str = "sw.js";
+/// Changes on every build, see `csp-hashes`, which hooks in at the same time as this.
+const INDEX: &str = "index.html";
+
+fn main() {
+    let dist = std::env::var_os("TRUNK_STAGING_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("dist"));
+    let public_url = std::env::var("TRUNK_PUBLIC_URL").unwrap_or_else(|_| "/".to_string());
+
+    let path = dist.join(SERVICE_WORKER);
+    let script = std::fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("reading {}: {error}", path.display()));
+    let script = fill_in(&dist, &public_url, &script);
+    std::fs::write(&path, script)
+        .unwrap_or_else(|error| panic!("writing {}: {error}", path.display()));
+}
+
+/// `script` with the URLs of the files in `dist` and the version of the build filled in.
+fn fill_in(dist: &Path, public_url: &str, script: &str) -> String {
+    let files = precached(dist);
+    let version = build_version(dist, &files);
+    let urls = files
+        .iter()
+        .map(|file| format!("{public_url}{file}"))
+        .collect::<Vec<_>>();
+    script.replace("{{BUILD_VERSION}}", &version).replace(
+        "{{PRECACHE_URLS}}",
+        &serde_json::to_string(&urls).expect("URLs serialize"),
+    )
+}
+
+/// The files in `dist` the service worker pre-caches, in order: all of them but itself.
+fn precached(dist: &Path) -> Vec<String> {
+    let mut files = Vec::new();
+    list_files(dist, dist, &mut files);
+    files.sort();
+    files.retain(|file| file != SERVICE_WORKER);
+    files
+}
+
+/// A hash of the names and content of `files`, except for the page.
+///
+/// Trunk gives the page new nonces on every build, and the app only changes when the other files
+/// do; hashing the page would also race with `csp-hashes`, which rewrites it.
+fn build_version(dist: &Path, files: &[String]) -> String {
+    let mut hasher = DefaultHasher::new();
+    for file in files.iter().filter(|file| *file != INDEX) {
+        file.hash(&mut hasher);
+        std::fs::read(dist.join(file))
+            .unwrap_or_else(|error| panic!("reading {file}: {error}"))
+            .hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+/// Adds the paths of the files under `dir` to `files`, relative to `root` and with `/` between
+/// directories, as in URLs.
+fn list_files(root: &Path, dir: &Path, files: &mut Vec<String>) {
+    let entries =
+        std::fs::read_dir(dir).unwrap_or_else(|error| panic!("reading {}: {error}", dir.display()));
+    for entry in entries {
+        let path = entry.expect("directory entry").path();
+        if path.is_dir() {
+            list_files(root, &path, files);
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let parts = relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>();
+            files.push(parts.join("/"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh `dist` directory holding `files`, given as path and content.
+    fn dist(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dist =
+            std::env::temp_dir().join(format!("service-worker-test-{}-{name}", std::process::id()));
+        _ = std::fs::remove_dir_all(&dist);
+        for (file, content) in files {
+            let path = dist.join(file);
+            std::fs::create_dir_all(path.parent().expect("a parent directory"))
+                .expect("creating the directory");
+            std::fs::write(path, content).expect("writing the file");
+        }
+        dist
+    }
+
+    const APP: &[(&str, &str)] = &[
+        ("index.html", "<html nonce=a>"),
+        (
+            "sw.js",
+            "const VERSION = '{{BUILD_VERSION}}'; const URLS = {{PRECACHE_URLS}};",
+        ),
+        ("frontend-abc_bg.wasm", "wasm"),
+        ("frontend-abc.js", "js"),
+        ("assets/icons/logo.svg", "<svg/>"),
+    ];
+
+    #[test]
+    fn lists_the_files_below_dist_as_urls_do() {
+        let dist = dist("lists", APP);
+        let mut files = Vec::new();
+        list_files(&dist, &dist, &mut files);
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                "assets/icons/logo.svg",
+                "frontend-abc.js",
+                "frontend-abc_bg.wasm",
+                "index.html",
+                "sw.js"
+            ]
+        );
+        _ = std::fs::remove_dir_all(dist);
+    }
+
+    #[test]
+    fn precaches_everything_but_the_service_worker() {
+        let dist = dist("precaches", APP);
+        assert_eq!(
+            precached(&dist),
+            [
+                "assets/icons/logo.svg",
+                "frontend-abc.js",
+                "frontend-abc_bg.wasm",
+                "index.html"
+            ]
+        );
+        _ = std::fs::remove_dir_all(dist);
+    }
+
+    #[test]
+    fn fills_in_the_urls_and_the_version() {
+        let dist = dist("fills_in", APP);
+        let script = fill_in(&dist, "/app/", APP[1].1);
+        let version = build_version(&dist, &precached(&dist));
+        assert_eq!(
+            script,
+            format!(
+                "const VERSION = '{version}'; const URLS = [\"/app/assets/icons/logo.svg\",\
+                 \"/app/frontend-abc.js\",\"/app/frontend-abc_bg.wasm\",\"/app/index.html\"];"
+            )
+        );
+        _ = std::fs::remove_dir_all(dist);
+    }
+
+    #[test]
+    fn the_version_follows_the_app_but_not_the_page() {
+        let version_of = |name, files: &[(&str, &str)]| {
+            let dist = dist(name, files);
+            let version = build_version(&dist, &precached(&dist));
+            _ = std::fs::remove_dir_all(dist);
+            version
+        };
+        let version = version_of("base", APP);
+        assert_eq!(version.len(), 16);
+        assert_eq!(version_of("same", APP), version);
+
+        let mut new_nonces = APP.to_vec();
+        new_nonces[0].1 = "<html nonce=b>";
+        assert_eq!(version_of("new_nonces", &new_nonces), version);
+
+        let mut new_content = APP.to_vec();
+        new_content[3].1 = "other js";
+        assert_ne!(version_of("new_content", &new_content), version);
+
+        let mut new_name = APP.to_vec();
+        new_name[3].0 = "frontend-def.js";
+        assert_ne!(version_of("new_name", &new_name), version);
+    }
+}
@@ -0,0 +1,67 @@
+//! The web worker of `crate::worker`: runs the tasks of `src/worker/tasks.rs` off the main thread.
+//!
+//! Trunk builds it from the `data-type="worker"` link in `index.html`, next to a
+//! `worker_loader.js` that starts it.
+
+#[path = "../worker/tasks.rs"]
+mod tasks;
+
+use tasks::{CountPrimes, Encoded, MandelbrotTile, Request, Response, Task};
+use wasm_bindgen::prelude::*;
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent};
+
+fn main() {
+    console_error_panic_hook::set_once();
+    let scope = js_sys::global().unchecked_into::<DedicatedWorkerGlobalScope>();
+
+    let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        let Some(text) = event.data().as_string() else {
+            return;
+        };
+        match serde_json::from_str::<Request>(&text) {
+            Ok(request) => handle(request),
+            Err(error) => leptos::logging::warn!("ignoring request {text:?}: {error}"),
+        }
+    });
+    scope.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    // the worker lives as long as the page keeps it
+    on_message.forget();
+
+    post(&Response::Ready);
+}
+
+fn handle(request: Request) {
+    let id = request.id;
+    let output = match request.task.as_str() {
+        CountPrimes::NAME => run::<CountPrimes>(id, &request.input),
+        MandelbrotTile::NAME => run::<MandelbrotTile>(id, &request.input),
+        task => Err(format!("there is no task {task:?}")),
+    };
+    match output {
+        Ok(Encoded::Json(output)) => post(&Response::Done { id, output }),
+        Ok(Encoded::Bytes(bytes)) => post_bytes(id, &bytes),
+        Err(error) => post(&Response::Failed { id, error }),
+    }
+}
+
+fn run<T: Task>(id: u32, input: &str) -> Result<Encoded, String> {
+    let task = serde_json::from_str::<T>(input).map_err(|error| error.to_string())?;
+    let output = task.run(&mut |done| post(&Response::Progress { id, done }));
+    Ok(T::encode(output))
+}
+
+fn post(response: &Response) {
+    let scope = js_sys::global().unchecked_into::<DedicatedWorkerGlobalScope>();
+    let json = serde_json::to_string(response).expect("responses serialize");
+    _ = scope.post_message(&JsValue::from_str(&json));
+}
+
+/// Posts `[response, buffer]`, handing the buffer over to the app instead of copying it.
+fn post_bytes(id: u32, bytes: &[u8]) {
+    let scope = js_sys::global().unchecked_into::<DedicatedWorkerGlobalScope>();
+    let json = serde_json::to_string(&Response::DoneBytes { id }).expect("responses serialize");
+    // wasm memory can't be transferred, so the bytes get a buffer of their own first
+    let buffer = js_sys::Uint8Array::from(bytes).buffer();
+    let message = js_sys::Array::of2(&JsValue::from_str(&json), &buffer);
+    _ = scope.post_message_with_transfer(&message, &js_sys::Array::of1(&buffer));
+}
@@ -0,0 +1,265 @@
+use super::ClassList;
+use leptos::*;
+
+/// Width of a `column`, either as a fraction or in twelfths of the row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnSize {
+    ThreeQuarters,
+    TwoThirds,
+    Half,
+    OneThird,
+    OneQuarter,
+    Full,
+    FourFifths,
+    ThreeFifths,
+    TwoFifths,
+    OneFifth,
+    Is1,
+    Is2,
+    Is3,
+    Is4,
+    Is5,
+    Is6,
+    Is7,
+    Is8,
+    Is9,
+    Is10,
+    Is11,
+    Is12,
+    /// Only as wide as its content.
+    Narrow,
+}
+
+impl ColumnSize {
+    pub const ALL: [ColumnSize; 23] = [
+        ColumnSize::ThreeQuarters,
+        ColumnSize::TwoThirds,
+        ColumnSize::Half,
+        ColumnSize::OneThird,
+        ColumnSize::OneQuarter,
+        ColumnSize::Full,
+        ColumnSize::FourFifths,
+        ColumnSize::ThreeFifths,
+        ColumnSize::TwoFifths,
+        ColumnSize::OneFifth,
+        ColumnSize::Is1,
+        ColumnSize::Is2,
+        ColumnSize::Is3,
+        ColumnSize::Is4,
+        ColumnSize::Is5,
+        ColumnSize::Is6,
+        ColumnSize::Is7,
+        ColumnSize::Is8,
+        ColumnSize::Is9,
+        ColumnSize::Is10,
+        ColumnSize::Is11,
+        ColumnSize::Is12,
+        ColumnSize::Narrow,
+    ];
+    /// The sizes an offset can be: all but `Narrow`, which comes last in `ALL`.
+    pub const OFFSETS: &'static [ColumnSize] = match ColumnSize::ALL.split_last() {
+        Some((_, offsets)) => offsets,
+        None => &[],
+    };
+
+    fn suffix(self) -> &'static str {
+        match self {
+            ColumnSize::ThreeQuarters => "three-quarters",
+            ColumnSize::TwoThirds => "two-thirds",
+            ColumnSize::Half => "half",
+            ColumnSize::OneThird => "one-third",
+            ColumnSize::OneQuarter => "one-quarter",
+            ColumnSize::Full => "full",
+            ColumnSize::FourFifths => "four-fifths",
+            ColumnSize::ThreeFifths => "three-fifths",
+            ColumnSize::TwoFifths => "two-fifths",
+            ColumnSize::OneFifth => "one-fifth",
+            ColumnSize::Is1 => "1",
+            ColumnSize::Is2 => "2",
+            ColumnSize::Is3 => "3",
+            ColumnSize::Is4 => "4",
+            ColumnSize::Is5 => "5",
+            ColumnSize::Is6 => "6",
+            ColumnSize::Is7 => "7",
+            ColumnSize::Is8 => "8",
+            ColumnSize::Is9 => "9",
+            ColumnSize::Is10 => "10",
+            ColumnSize::Is11 => "11",
+            ColumnSize::Is12 => "12",
+            ColumnSize::Narrow => "narrow",
+        }
+    }
+
+    pub fn class(self) -> String {
+        format!("is-{}", self.suffix())
+    }
+
+    /// The size on `breakpoint`'s screens only, like `is-half-tablet`.
+    pub fn class_at(self, breakpoint: Breakpoint) -> String {
+        format!("is-{}-{}", self.suffix(), breakpoint.suffix())
+    }
+
+    /// Bulma has no offset for `narrow`, so that one yields no class.
+    pub fn offset_class(self) -> Option<String> {
+        (self != ColumnSize::Narrow).then(|| format!("is-offset-{}", self.suffix()))
+    }
+}
+
+/// Space between columns, from none (`Gap0`) to 2rem (`Gap8`). Bulma's default is `Gap3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnGap {
+    Gap0,
+    Gap1,
+    Gap2,
+    Gap3,
+    Gap4,
+    Gap5,
+    Gap6,
+    Gap7,
+    Gap8,
+}
+
+impl ColumnGap {
+    pub const ALL: [ColumnGap; 9] = [
+        ColumnGap::Gap0,
+        ColumnGap::Gap1,
+        ColumnGap::Gap2,
+        ColumnGap::Gap3,
+        ColumnGap::Gap4,
+        ColumnGap::Gap5,
+        ColumnGap::Gap6,
+        ColumnGap::Gap7,
+        ColumnGap::Gap8,
+    ];
+
+    pub fn class(self) -> &'static str {
+        match self {
+            ColumnGap::Gap0 => "is-variable is-0",
+            ColumnGap::Gap1 => "is-variable is-1",
+            ColumnGap::Gap2 => "is-variable is-2",
+            ColumnGap::Gap3 => "is-variable is-3",
+            ColumnGap::Gap4 => "is-variable is-4",
+            ColumnGap::Gap5 => "is-variable is-5",
+            ColumnGap::Gap6 => "is-variable is-6",
+            ColumnGap::Gap7 => "is-variable is-7",
+            ColumnGap::Gap8 => "is-variable is-8",
+        }
+    }
+}
+
+/// The screen widths a `Column` can have a size of its own on. Each but `Mobile` is that wide
+/// and up; `Mobile` is phones only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    Mobile,
+    Tablet,
+    Desktop,
+    Widescreen,
+    FullHd,
+}
+
+impl Breakpoint {
+    pub const ALL: [Breakpoint; 5] = [
+        Breakpoint::Mobile,
+        Breakpoint::Tablet,
+        Breakpoint::Desktop,
+        Breakpoint::Widescreen,
+        Breakpoint::FullHd,
+    ];
+
+    fn suffix(self) -> &'static str {
+        match self {
+            Breakpoint::Mobile => "mobile",
+            Breakpoint::Tablet => "tablet",
+            Breakpoint::Desktop => "desktop",
+            Breakpoint::Widescreen => "widescreen",
+            Breakpoint::FullHd => "fullhd",
+        }
+    }
+}
+
+/// From which screen width the columns sit side by side.
+/// Without one they stack below tablet width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnsBreakpoint {
+    /// Side by side on every screen, phones included.
+    Mobile,
+    /// Stacked until desktop width.
+    Desktop,
+}
+
+impl ColumnsBreakpoint {
+    pub const ALL: [ColumnsBreakpoint; 2] = [ColumnsBreakpoint::Mobile, ColumnsBreakpoint::Desktop];
+
+    pub fn class(self) -> &'static str {
+        match self {
+            ColumnsBreakpoint::Mobile => "is-mobile",
+            ColumnsBreakpoint::Desktop => "is-desktop",
+        }
+    }
+}
+
+/// `columns`, the flexbox row the `Column`s go in.
+#[component]
+pub fn Columns(
+    /// Wrap onto new lines instead of squeezing every column into one row.
+    #[prop(into, optional)]
+    multiline: MaybeSignal<bool>,
+    #[prop(into, optional)] centered: MaybeSignal<bool>,
+    #[prop(into, optional)] vcentered: MaybeSignal<bool>,
+    #[prop(into, optional)] gapless: MaybeSignal<bool>,
+    #[prop(into, optional)] gap: MaybeProp<ColumnGap>,
+    #[prop(into, optional)] breakpoint: MaybeProp<ColumnsBreakpoint>,
+    #[prop(into, optional)] class: String,
+    children: Children,
+) -> impl IntoView {
+    let class = move || {
+        ClassList::new("columns")
+            .add(breakpoint.get().map(ColumnsBreakpoint::class))
+            .add(gap.get().map(ColumnGap::class))
+            .add_if(multiline.get(), "is-multiline")
+            .add_if(centered.get(), "is-centered")
+            .add_if(vcentered.get(), "is-vcentered")
+            .add_if(gapless.get(), "is-gapless")
+            .add_str(&class)
+            .build()
+    };
+
+    view! { <div class=class>{children()}</div> }
+}
+
+#[component]
+pub fn Column(
+    #[prop(into, optional)] size: MaybeProp<ColumnSize>,
+    /// Empty space left of the column.
+    #[prop(into, optional)]
+    offset: MaybeProp<ColumnSize>,
+    /// Sizes on some screens only, in place of `size`.
+    #[prop(into, optional)]
+    mobile: MaybeProp<ColumnSize>,
+    #[prop(into, optional)] tablet: MaybeProp<ColumnSize>,
+    #[prop(into, optional)] desktop: MaybeProp<ColumnSize>,
+    #[prop(into, optional)] widescreen: MaybeProp<ColumnSize>,
+    #[prop(into, optional)] fullhd: MaybeProp<ColumnSize>,
+    #[prop(into, optional)] class: String,
+    children: Children,
+) -> impl IntoView {
+    let class = move || {
+        let size = size.get().map(ColumnSize::class);
+        let offset = offset.get().and_then(ColumnSize::offset_class);
+        let mut list = ClassList::new("column")
+            .add_str(size.as_deref().unwrap_or_default())
+            .add_str(offset.as_deref().unwrap_or_default());
+        for (breakpoint, size) in Breakpoint::ALL
+            .into_iter()
+            .zip([mobile, tablet, desktop, widescreen, fullhd])
+        {
+            if let Some(size) = size.get() {
+                list = list.add_str(&size.class_at(breakpoint));
+            }
+        }
+        list.add_str(&class).build()
+    };
+
+    view! { <div class=class>{children()}</div> }
+}
@@ -0,0 +1,336 @@
+use super::{BSize, ClassList, Color};
+use crate::a11y::unique_id;
+use crate::i18n::t;
+use leptos::*;
+use wasm_bindgen::JsCast;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabsStyle {
+    Boxed,
+    Toggle,
+}
+
+impl TabsStyle {
+    fn class(self) -> &'static str {
+        match self {
+            TabsStyle::Boxed => "is-boxed",
+            TabsStyle::Toggle => "is-toggle",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabsAlignment {
+    Centered,
+}
+
+impl TabsAlignment {
+    fn class(self) -> &'static str {
+        match self {
+            TabsAlignment::Centered => "is-centered",
+        }
+    }
+}
+
+/// `tabs`. Put one `Tab` per entry inside.
+#[component]
+pub fn Tabs(
+    #[prop(optional)] style: Option<TabsStyle>,
+    #[prop(optional)] alignment: Option<TabsAlignment>,
+    #[prop(optional)] size: Option<BSize>,
+    #[prop(optional)] fullwidth: bool,
+    children: Children,
+) -> impl IntoView {
+    let class = ClassList::new("tabs")
+        .add(style.map(TabsStyle::class))
+        .add(alignment.map(TabsAlignment::class))
+        .add_size(size)
+        .add_if(fullwidth, "is-fullwidth")
+        .build();
+
+    view! {
+        <div class=class>
+            <ul>{children()}</ul>
+        </div>
+    }
+}
+
+/// One entry of `Tabs`. The child should be the link, e.g. an `<A/>`, or a `<button>` with
+/// `aria-pressed` for a tab that switches what the page shows in place.
+#[component]
+pub fn Tab(#[prop(into, optional)] active: MaybeSignal<bool>, children: Children) -> impl IntoView {
+    view! { <li class:is-active=move || active.get()>{children()}</li> }
+}
+
+/// `message`, a colored block with an optional header.
+#[component]
+pub fn Message(
+    #[prop(into, optional)] color: MaybeProp<Color>,
+    #[prop(into, optional)] size: MaybeProp<BSize>,
+    #[prop(into, optional)] title: Option<MaybeSignal<String>>,
+    children: Children,
+) -> impl IntoView {
+    let class = move || {
+        ClassList::new("message")
+            .add(color.get().map(Color::class))
+            .add_size(size.get())
+            .build()
+    };
+
+    view! {
+        <article class=class>
+            {title
+                .map(|title| {
+                    view! {
+                        <div class="message-header">
+                            <p>{title}</p>
+                        </div>
+                    }
+                })}
+            <div class="message-body">{children()}</div>
+        </article>
+    }
+}
+
+/// `card`, with an optional header title around `card-content`.
+#[component]
+pub fn Card(
+    #[prop(into, optional)] title: Option<MaybeSignal<String>>,
+    #[prop(into, optional)] class: String,
+    children: Children,
+) -> impl IntoView {
+    let class = ClassList::new("card").add_str(&class).build();
+
+    view! {
+        <div class=class>
+            {title
+                .map(|title| {
+                    view! {
+                        <header class="card-header">
+                            <p class="card-header-title">{title}</p>
+                        </header>
+                    }
+                })}
+            <div class="card-content">
+                <div class="content">{children()}</div>
+            </div>
+        </div>
+    }
+}
+
+/// `panel`, a heading followed by `PanelBlock` rows. It is a `<nav>`, named by its heading.
+#[component]
+pub fn Panel(
+    #[prop(into)] heading: MaybeSignal<String>,
+    #[prop(into, optional)] color: MaybeProp<Color>,
+    children: Children,
+) -> impl IntoView {
+    let class = move || {
+        ClassList::new("panel")
+            .add(color.get().map(Color::class))
+            .build()
+    };
+    let heading_id = unique_id("panel-heading");
+
+    view! {
+        <nav class=class aria-labelledby=heading_id.clone()>
+            <p class="panel-heading" id=heading_id>
+                {heading}
+            </p>
+            {children()}
+        </nav>
+    }
+}
+
+#[component]
+pub fn PanelBlock(
+    #[prop(into, optional)] active: MaybeSignal<bool>,
+    children: Children,
+) -> impl IntoView {
+    view! { <div class="panel-block" class:is-active=move || active.get()>{children()}</div> }
+}
+
+/// The pages `Pagination` links to, from 1: the first and last page, and the pages around
+/// `current`. `None` stands for an ellipsis over the pages left out.
+fn pagination_pages(current: usize, total: usize) -> Vec<Option<usize>> {
+    let mut pages = Vec::new();
+    for page in 1..=total {
+        let near = page.abs_diff(current) <= 1;
+        if page == 1 || page == total || near {
+            pages.push(Some(page));
+        } else if pages.last() != Some(&None) {
+            pages.push(None);
+        }
+    }
+    pages
+}
+
+/// `pagination`, with previous and next buttons. Pages count from 1.
+#[component]
+pub fn Pagination(
+    #[prop(into)] current: Signal<usize>,
+    #[prop(into)] total: Signal<usize>,
+    #[prop(into)] on_change: Callback<usize>,
+    #[prop(optional)] size: Option<BSize>,
+    #[prop(optional)] centered: bool,
+) -> impl IntoView {
+    let class = ClassList::new("pagination")
+        .add_size(size)
+        .add_if(centered, "is-centered")
+        .build();
+
+    view! {
+        <nav class=class role="navigation" aria-label=t!("pagination")>
+            <button
+                class="pagination-previous"
+                disabled=move || current.get() <= 1
+                on:click=move |_| on_change(current() - 1)
+            >
+                {t!("pagination-previous")}
+            </button>
+            <button
+                class="pagination-next"
+                disabled=move || current.get() >= total.get()
+                on:click=move |_| on_change(current() + 1)
+            >
+                {t!("pagination-next")}
+            </button>
+            <ul class="pagination-list">
+                {move || {
+                    pagination_pages(current(), total())
+                        .into_iter()
+                        .map(|page| match page {
+                            Some(page) => {
+                                let is_current = page == current.get_untracked();
+                                view! {
+                                    <li>
+                                        <button
+                                            class="pagination-link"
+                                            class:is-current=is_current
+                                            aria-label=t!("pagination-page", page = page)
+                                            aria-current=is_current.then_some("page")
+                                            on:click=move |_| on_change(page)
+                                        >
+                                            {page}
+                                        </button>
+                                    </li>
+                                }
+                            }
+                            None => {
+                                view! {
+                                    <li>
+                                        <span class="pagination-ellipsis">"…"</span>
+                                    </li>
+                                }
+                            }
+                        })
+                        .collect_view()
+                }}
+            </ul>
+        </nav>
+    }
+}
+
+/// `navbar-item has-dropdown`, with `label` on its `navbar-link` and `navbar-item`s inside.
+/// The link only shows and hides them, so they are tabbed to like the rest of the navbar.
+///
+/// Opens on hover, and on click, Enter or Space for keyboards. Escape closes it, back to its
+/// link, and so does focus leaving it.
+#[component]
+pub fn NavbarDropdown(
+    #[prop(into)] label: MaybeSignal<String>,
+    children: Children,
+) -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+    let container = create_node_ref::<html::Div>();
+    let link = create_node_ref::<html::A>();
+    let toggle = move || set_open.update(|open| *open = !*open);
+
+    let on_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() == "Escape" && open.get_untracked() {
+            set_open(false);
+            if let Some(link) = link.get_untracked() {
+                _ = link.focus();
+            }
+        }
+    };
+    let on_focusout = move |ev: ev::FocusEvent| {
+        let to = ev
+            .related_target()
+            .and_then(|to| to.dyn_into::<web_sys::Node>().ok());
+        let inside = container
+            .get_untracked()
+            .is_some_and(|container| container.contains(to.as_ref()));
+        if !inside {
+            set_open(false);
+        }
+    };
+
+    view! {
+        <div
+            node_ref=container
+            class="navbar-item has-dropdown is-hoverable"
+            class:is-active=open
+            on:keydown=on_keydown
+            on:focusout=on_focusout
+        >
+            <a
+                node_ref=link
+                class="navbar-link"
+                role="button"
+                tabindex="0"
+                aria-expanded=move || open.get().to_string()
+                on:click=move |_| toggle()
+                on:keydown=move |ev| {
+                    if ev.key() == "Enter" || ev.key() == " " {
+                        ev.prevent_default();
+                        toggle();
+                    }
+                }
+            >
+                {label}
+            </a>
+            <div class="navbar-dropdown">{children()}</div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_pages() {
+        assert_eq!(pagination_pages(1, 0), []);
+    }
+
+    #[test]
+    fn one_or_two_pages() {
+        assert_eq!(pagination_pages(1, 1), [Some(1)]);
+        assert_eq!(pagination_pages(1, 2), [Some(1), Some(2)]);
+        assert_eq!(pagination_pages(2, 2), [Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn current_at_either_end() {
+        assert_eq!(pagination_pages(1, 10), [Some(1), Some(2), None, Some(10)]);
+        assert_eq!(pagination_pages(10, 10), [Some(1), None, Some(9), Some(10)]);
+    }
+
+    #[test]
+    fn current_in_the_middle() {
+        assert_eq!(
+            pagination_pages(5, 10),
+            [Some(1), None, Some(4), Some(5), Some(6), None, Some(10)]
+        );
+        assert_eq!(
+            pagination_pages(3, 10),
+            [Some(1), Some(2), Some(3), Some(4), None, Some(10)]
+        );
+    }
+
+    #[test]
+    fn current_past_the_end() {
+        assert_eq!(pagination_pages(99, 3), [Some(1), None, Some(3)]);
+    }
+}
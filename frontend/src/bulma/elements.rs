@@ -0,0 +1,62 @@
+use super::{BSize, ClassList, Color};
+use crate::i18n::t;
+use leptos::*;
+
+/// `notification`, with a close button when `on_close` is given.
+#[component]
+pub fn Notification(
+    #[prop(into, optional)] color: MaybeProp<Color>,
+    /// The light version of `color`.
+    #[prop(optional)]
+    light: bool,
+    #[prop(into, optional)] on_close: Option<Callback<()>>,
+    children: Children,
+) -> impl IntoView {
+    let class = move || {
+        ClassList::new("notification")
+            .add(color.get().map(Color::class))
+            .add_if(light, "is-light")
+            .build()
+    };
+
+    view! {
+        <div class=class role="status">
+            {on_close
+                .map(|on_close| {
+                    view! {
+                        <button class="delete" aria-label=t!("close") on:click=move |_| on_close(())></button>
+                    }
+                })}
+            {children()}
+        </div>
+    }
+}
+
+/// `progress`, `value` of the way full, 0.0 to 1.0. Without a value it only shows that
+/// something is going on.
+#[component]
+pub fn Progress(
+    #[prop(into, optional)] value: MaybeProp<f64>,
+    #[prop(into, optional)] color: MaybeProp<Color>,
+    #[prop(into, optional)] size: MaybeProp<BSize>,
+    #[prop(into, optional)] class: String,
+) -> impl IntoView {
+    let class = move || {
+        ClassList::new("progress")
+            .add(color.get().map(Color::class))
+            .add_size(size.get())
+            .add_str(&class)
+            .build()
+    };
+    let percent = move || {
+        value
+            .get()
+            .map(|value| (value * 100.0).clamp(0.0, 100.0).round())
+    };
+
+    view! {
+        <progress class=class max="100" value=percent>
+            {move || percent().map(|percent| format!("{percent}%"))}
+        </progress>
+    }
+}
@@ -0,0 +1,143 @@
+// Typed wrappers around the Bulma classes we use.
+// Modifiers are enums, so a typo like `is-primray` is a compile error instead of a silently unstyled element.
+//
+// Buttons, boxes and tags come from leptos-bulma, re-exported here. What's below is what it doesn't
+// have, or has without what the demos need: its `BColumns` takes sizes as free text and knows no gaps
+// or breakpoints, and its `BNotification` has no colors and a close button without a name.
+
+pub mod columns;
+pub mod components;
+pub mod elements;
+
+pub use columns::{Breakpoint, Column, ColumnGap, ColumnSize, Columns, ColumnsBreakpoint};
+pub use components::{
+    Card, Message, NavbarDropdown, Pagination, Panel, PanelBlock, Tab, Tabs, TabsAlignment,
+    TabsStyle,
+};
+pub use elements::{Notification, Progress};
+pub use leptos_bulma::elements::{BBox, BButton, BButtons, BTag, BTags};
+pub use leptos_bulma::enums::{BColor, BSize, BState};
+
+use leptos::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    White,
+    Black,
+    Light,
+    Dark,
+    Primary,
+    Link,
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl Color {
+    pub const ALL: [Color; 10] = [
+        Color::White,
+        Color::Black,
+        Color::Light,
+        Color::Dark,
+        Color::Primary,
+        Color::Link,
+        Color::Info,
+        Color::Success,
+        Color::Warning,
+        Color::Danger,
+    ];
+
+    pub fn class(self) -> &'static str {
+        match self {
+            Color::White => "is-white",
+            Color::Black => "is-black",
+            Color::Light => "is-light",
+            Color::Dark => "is-dark",
+            Color::Primary => "is-primary",
+            Color::Link => "is-link",
+            Color::Info => "is-info",
+            Color::Success => "is-success",
+            Color::Warning => "is-warning",
+            Color::Danger => "is-danger",
+        }
+    }
+}
+
+/// For leptos-bulma's components, which only know some of the colors by name.
+impl From<Color> for BColor {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Primary => BColor::Primary,
+            Color::Link => BColor::Link,
+            Color::Info => BColor::Info,
+            Color::Success => BColor::Success,
+            Color::Warning => BColor::Warning,
+            Color::Danger => BColor::Danger,
+            Color::White | Color::Black | Color::Light | Color::Dark => {
+                BColor::Custom(color.class().trim_start_matches("is-").to_string())
+            }
+        }
+    }
+}
+
+/// The `state` of a `BButton` that is disabled while `disabled` returns true.
+pub fn disabled_if(disabled: impl Fn() -> bool + 'static) -> Signal<BState> {
+    Signal::derive(move || {
+        if disabled() {
+            BState::Disabled
+        } else {
+            BState::Default
+        }
+    })
+}
+
+/// Builds a space separated class attribute out of a base class and optional modifiers.
+#[derive(Debug, Default)]
+pub(crate) struct ClassList(String);
+
+impl ClassList {
+    pub(crate) fn new(base: &str) -> Self {
+        ClassList(base.to_string())
+    }
+
+    pub(crate) fn add(self, class: impl Into<Option<&'static str>>) -> Self {
+        match class.into() {
+            Some(class) => self.add_str(class),
+            None => self,
+        }
+    }
+
+    /// Sizes are leptos-bulma's, so ours and its components share them.
+    pub(crate) fn add_size(self, size: Option<BSize>) -> Self {
+        match size {
+            Some(size) if size != BSize::Default => {
+                self.add_str(&format!("is-{}", String::from(size)))
+            }
+            _ => self,
+        }
+    }
+
+    pub(crate) fn add_if(self, condition: bool, class: &'static str) -> Self {
+        if condition {
+            self.add_str(class)
+        } else {
+            self
+        }
+    }
+
+    /// For the free-form `class` prop every component takes on top of its typed modifiers.
+    pub(crate) fn add_str(mut self, class: &str) -> Self {
+        if !class.is_empty() {
+            if !self.0.is_empty() {
+                self.0.push(' ');
+            }
+            self.0.push_str(class);
+        }
+        self
+    }
+
+    pub(crate) fn build(self) -> String {
+        self.0
+    }
+}
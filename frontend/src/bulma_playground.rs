@@ -1,18 +1,434 @@
+use crate::bulma::{
+    BButton, BColor, Breakpoint, Color, Column, ColumnGap, ColumnSize, Columns, ColumnsBreakpoint,
+    Notification,
+};
+use crate::i18n::t;
 use leptos::*;
+use std::fmt::Debug;
 
+/// Bulma rows are twelve units wide, so more columns than that stop being useful.
+const MAX_COLUMNS: usize = 12;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct ColumnSettings {
+    size: Option<ColumnSize>,
+    /// The sizes on some screens only, in the order of `Breakpoint::ALL`.
+    sizes_at: [Option<ColumnSize>; 5],
+    offset: Option<ColumnSize>,
+    color: Option<Color>,
+}
+
+impl ColumnSettings {
+    /// The size on `screen`, or on every screen for `None`.
+    fn size(&self, screen: Option<Breakpoint>) -> Option<ColumnSize> {
+        match screen {
+            Some(breakpoint) => self.sizes_at[breakpoint as usize],
+            None => self.size,
+        }
+    }
+
+    fn size_mut(&mut self, screen: Option<Breakpoint>) -> &mut Option<ColumnSize> {
+        match screen {
+            Some(breakpoint) => &mut self.sizes_at[breakpoint as usize],
+            None => &mut self.size,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct LayoutSettings {
+    gap: Option<ColumnGap>,
+    breakpoint: Option<ColumnsBreakpoint>,
+    multiline: bool,
+    gapless: bool,
+    centered: bool,
+    vcentered: bool,
+}
+
+/// A small layout lab: tweak the columns on the left, watch the preview,
+/// then export the `view!` markup to paste into a component.
 #[component]
 pub fn BulmaPlayground() -> impl IntoView {
+    let (count, set_count) = create_signal(5);
+    let (layout, set_layout) = create_signal(LayoutSettings::default());
+    // settings are kept for all twelve slots, so shrinking and growing the row doesn't lose them
+    let (columns, set_columns) = create_signal([ColumnSettings::default(); MAX_COLUMNS]);
+    // which screens the sizes in the table are for, all of them by default
+    let (screen, set_screen) = create_signal(None::<Breakpoint>);
+    let (exported, set_exported) = create_signal(None::<String>);
+    let size = move |index: usize, screen: Option<Breakpoint>| {
+        Signal::derive(move || columns.with(|columns| columns[index].size(screen)))
+    };
+
+    let on_count = move |ev| {
+        if let Ok(value) = event_target_value(&ev).parse::<usize>() {
+            set_count(value.clamp(1, MAX_COLUMNS));
+        }
+    };
+
+    let on_export = move |_| {
+        let markup = columns.with(|columns| export_markup(layout(), &columns[..count()]));
+        set_exported(Some(markup));
+    };
+
     view! {
-        <div>
-            <div class="container">
-                <div class="columns">
-                    <div class="column">1</div>
-                    <div class="column">2</div>
-                    <div class="column">3</div>
-                    <div class="column">4</div>
-                    <div class="column">5</div>
+        <div class="container">
+            <h3 class="subtitle">{t!("playground-layout")}</h3>
+            <div class="field is-grouped is-grouped-multiline">
+                <div class="control">
+                    <label class="label is-small" for="playground-columns">
+                        {t!("playground-columns")}
+                    </label>
+                    <input
+                        id="playground-columns"
+                        class="input is-small"
+                        type="number"
+                        min="1"
+                        max=MAX_COLUMNS
+                        prop:value=count
+                        on:input=on_count
+                    />
+                </div>
+                <div class="control">
+                    <label class="label is-small" for="playground-gap">
+                        {t!("playground-gap")}
+                    </label>
+                    <OptionSelect
+                        id="playground-gap"
+                        options=&ColumnGap::ALL
+                        value=Signal::derive(move || layout().gap)
+                        on_change=move |gap| set_layout.update(|layout| layout.gap = gap)
+                    />
+                </div>
+                <div class="control">
+                    <label class="label is-small" for="playground-breakpoint">
+                        {t!("playground-breakpoint")}
+                    </label>
+                    <OptionSelect
+                        id="playground-breakpoint"
+                        options=&ColumnsBreakpoint::ALL
+                        value=Signal::derive(move || layout().breakpoint)
+                        on_change=move |breakpoint| {
+                            set_layout.update(|layout| layout.breakpoint = breakpoint)
+                        }
+                    />
+                </div>
+                <div class="control">
+                    <label class="label is-small" for="playground-screen">
+                        {t!("playground-screen")}
+                    </label>
+                    <OptionSelect
+                        id="playground-screen"
+                        none_label=t!("playground-all-screens")
+                        options=&Breakpoint::ALL
+                        value=screen
+                        on_change=set_screen
+                    />
+                </div>
+                <div class="control">
+                    <label class="checkbox">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || layout().multiline
+                            on:change=move |ev| {
+                                set_layout.update(|layout| layout.multiline = event_target_checked(&ev))
+                            }
+                        />
+                        " multiline"
+                    </label>
+                    <label class="checkbox">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || layout().gapless
+                            on:change=move |ev| {
+                                set_layout.update(|layout| layout.gapless = event_target_checked(&ev))
+                            }
+                        />
+                        " gapless"
+                    </label>
+                    <label class="checkbox">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || layout().centered
+                            on:change=move |ev| {
+                                set_layout.update(|layout| layout.centered = event_target_checked(&ev))
+                            }
+                        />
+                        " centered"
+                    </label>
+                    <label class="checkbox">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || layout().vcentered
+                            on:change=move |ev| {
+                                set_layout.update(|layout| layout.vcentered = event_target_checked(&ev))
+                            }
+                        />
+                        " vcentered"
+                    </label>
                 </div>
             </div>
+
+            <table class="table is-narrow">
+                <thead>
+                    <tr>
+                        <th>"#"</th>
+                        <th>{t!("playground-size")}</th>
+                        <th>{t!("playground-offset")}</th>
+                        <th>{t!("playground-color")}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    <For
+                        each=move || 0..count()
+                        key=|index| *index
+                        children=move |index| {
+                            view! {
+                                <tr>
+                                    <td>{index + 1}</td>
+                                    <td>
+                                        <OptionSelect
+                                            label=t!("playground-column-size", column = index + 1)
+                                            options=&ColumnSize::ALL
+                                            value=Signal::derive(move || columns.with(|columns| columns[index].size(screen())))
+                                            on_change=move |size| {
+                                                set_columns
+                                                    .update(|columns| {
+                                                        *columns[index].size_mut(screen.get_untracked()) = size
+                                                    })
+                                            }
+                                        />
+                                    </td>
+                                    <td>
+                                        <OptionSelect
+                                            label=t!("playground-column-offset", column = index + 1)
+                                            options=ColumnSize::OFFSETS
+                                            value=Signal::derive(move || columns.with(|columns| columns[index].offset))
+                                            on_change=move |offset| {
+                                                set_columns.update(|columns| columns[index].offset = offset)
+                                            }
+                                        />
+                                    </td>
+                                    <td>
+                                        <OptionSelect
+                                            label=t!("playground-column-color", column = index + 1)
+                                            options=&Color::ALL
+                                            value=Signal::derive(move || columns.with(|columns| columns[index].color))
+                                            on_change=move |color| {
+                                                set_columns.update(|columns| columns[index].color = color)
+                                            }
+                                        />
+                                    </td>
+                                </tr>
+                            }
+                        }
+                    />
+
+                </tbody>
+            </table>
+
+            <h3 class="subtitle">{t!("playground-preview")}</h3>
+            <Columns
+                gap=Signal::derive(move || layout().gap)
+                breakpoint=Signal::derive(move || layout().breakpoint)
+                multiline=Signal::derive(move || layout().multiline)
+                gapless=Signal::derive(move || layout().gapless)
+                centered=Signal::derive(move || layout().centered)
+                vcentered=Signal::derive(move || layout().vcentered)
+            >
+                <For
+                    each=move || 0..count()
+                    key=|index| *index
+                    children=move |index| {
+                        view! {
+                            <Column
+                                size=size(index, None)
+                                mobile=size(index, Some(Breakpoint::Mobile))
+                                tablet=size(index, Some(Breakpoint::Tablet))
+                                desktop=size(index, Some(Breakpoint::Desktop))
+                                widescreen=size(index, Some(Breakpoint::Widescreen))
+                                fullhd=size(index, Some(Breakpoint::FullHd))
+                                offset=Signal::derive(move || columns.with(|columns| columns[index].offset))
+                            >
+                                <Notification color=Signal::derive(move || columns.with(|columns| columns[index].color))>
+                                    {index + 1}
+                                </Notification>
+                            </Column>
+                        }
+                    }
+                />
+
+            </Columns>
+
+            <BButton color=BColor::Primary on:click=on_export>
+                {t!("playground-export")}
+            </BButton>
+            {move || {
+                exported()
+                    .map(|markup| {
+                        view! {
+                            <textarea
+                                class="textarea is-family-monospace mt-3"
+                                rows="12"
+                                readonly
+                                aria-label=t!("playground-exported")
+                            >
+                                {markup}
+                            </textarea>
+                        }
+                    })
+            }}
+
         </div>
     }
 }
+
+/// A `<select>` over the variants of a modifier enum, plus an entry for none, "default" unless
+/// `none_label` says otherwise. The variants keep their names from the code.
+///
+/// Give it an `id` for a `<label for>` to point at, or a `label` where there is no room for one.
+#[component]
+fn OptionSelect<T>(
+    #[prop(optional)] id: Option<&'static str>,
+    #[prop(into, optional)] label: Option<Signal<String>>,
+    #[prop(into, optional)] none_label: Option<Signal<String>>,
+    options: &'static [T],
+    #[prop(into)] value: Signal<Option<T>>,
+    #[prop(into)] on_change: Callback<Option<T>>,
+) -> impl IntoView
+where
+    T: Debug + Copy + PartialEq + 'static,
+{
+    let none_label = none_label.unwrap_or_else(|| t!("playground-default"));
+    view! {
+        <div class="select is-small">
+            <select id=id aria-label=label on:change=move |ev| {
+                let selected = event_target_value(&ev)
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| options.get(index).copied());
+                on_change(selected);
+            }>
+                <option value="" selected=move || value().is_none()>
+                    {none_label}
+                </option>
+                {options
+                    .iter()
+                    .enumerate()
+                    .map(|(index, option)| {
+                        view! {
+                            <option value=index selected=move || value() == Some(*option)>
+                                {format!("{option:?}")}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
+
+/// The markup for the current preview, written with the typed components from `crate::bulma`.
+fn export_markup(layout: LayoutSettings, columns: &[ColumnSettings]) -> String {
+    let mut columns_attributes = String::new();
+    if let Some(gap) = layout.gap {
+        columns_attributes.push_str(&format!(" gap=ColumnGap::{gap:?}"));
+    }
+    if let Some(breakpoint) = layout.breakpoint {
+        columns_attributes.push_str(&format!(" breakpoint=ColumnsBreakpoint::{breakpoint:?}"));
+    }
+    for (enabled, flag) in [
+        (layout.multiline, "multiline"),
+        (layout.gapless, "gapless"),
+        (layout.centered, "centered"),
+        (layout.vcentered, "vcentered"),
+    ] {
+        if enabled {
+            columns_attributes.push_str(&format!(" {flag}=true"));
+        }
+    }
+
+    let mut markup = format!("view! {{\n    <Columns{columns_attributes}>\n");
+    for (index, column) in columns.iter().enumerate() {
+        let mut column_attributes = String::new();
+        if let Some(size) = column.size {
+            column_attributes.push_str(&format!(" size=ColumnSize::{size:?}"));
+        }
+        for breakpoint in Breakpoint::ALL {
+            if let Some(size) = column.size(Some(breakpoint)) {
+                // the props are named like the variants, in lower case
+                let prop = format!("{breakpoint:?}").to_lowercase();
+                column_attributes.push_str(&format!(" {prop}=ColumnSize::{size:?}"));
+            }
+        }
+        if let Some(offset) = column.offset {
+            column_attributes.push_str(&format!(" offset=ColumnSize::{offset:?}"));
+        }
+        let color = column
+            .color
+            .map(|color| format!(" color=Color::{color:?}"))
+            .unwrap_or_default();
+
+        markup.push_str(&format!("        <Column{column_attributes}>\n"));
+        markup.push_str(&format!(
+            "            <Notification{color}>\"{}\"</Notification>\n",
+            index + 1
+        ));
+        markup.push_str("        </Column>\n");
+    }
+    markup.push_str("    </Columns>\n}");
+    markup
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exports_plain_columns() {
+        let markup = export_markup(LayoutSettings::default(), &[ColumnSettings::default(); 2]);
+        assert_eq!(
+            markup,
+            "view! {\n    <Columns>\n        <Column>\n            <Notification>\"1\"</Notification>\n        </Column>\n        <Column>\n            <Notification>\"2\"</Notification>\n        </Column>\n    </Columns>\n}"
+        );
+    }
+
+    #[test]
+    fn exports_layout_settings() {
+        let layout = LayoutSettings {
+            gap: Some(ColumnGap::Gap2),
+            breakpoint: Some(ColumnsBreakpoint::Mobile),
+            multiline: true,
+            gapless: true,
+            centered: false,
+            vcentered: true,
+        };
+        let markup = export_markup(layout, &[ColumnSettings::default()]);
+        assert!(markup.contains(
+            "<Columns gap=ColumnGap::Gap2 breakpoint=ColumnsBreakpoint::Mobile multiline=true gapless=true vcentered=true>"
+        ));
+    }
+
+    #[test]
+    fn exports_column_settings() {
+        let mut column = ColumnSettings {
+            size: Some(ColumnSize::Half),
+            offset: Some(ColumnSize::Is2),
+            color: Some(Color::Info),
+            ..ColumnSettings::default()
+        };
+        *column.size_mut(Some(Breakpoint::Tablet)) = Some(ColumnSize::OneThird);
+        *column.size_mut(Some(Breakpoint::FullHd)) = Some(ColumnSize::Narrow);
+        let markup = export_markup(LayoutSettings::default(), &[column]);
+        assert!(markup.contains(
+            "<Column size=ColumnSize::Half tablet=ColumnSize::OneThird fullhd=ColumnSize::Narrow offset=ColumnSize::Is2>"
+        ));
+        assert!(markup.contains("<Notification color=Color::Info>\"1\"</Notification>"));
+    }
+
+    #[test]
+    fn offsets_leave_out_narrow() {
+        assert!(!ColumnSize::OFFSETS.contains(&ColumnSize::Narrow));
+        assert_eq!(ColumnSize::OFFSETS.len(), ColumnSize::ALL.len() - 1);
+    }
+}
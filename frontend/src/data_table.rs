@@ -0,0 +1,347 @@
+use crate::i18n::t;
+use leptos::ev::MouseEvent;
+use leptos::*;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// The rank of each row by a column's sort key, see `ranks`.
+type Rank<T> = Rc<dyn Fn(&[RwSignal<T>]) -> Vec<usize>>;
+
+/// One column of a `DataTable`: a header, the text shown in each cell, and how to sort by it.
+pub struct TableColumn<T: 'static> {
+    header: Signal<String>,
+    text: Rc<dyn Fn(&T) -> String>,
+    rank: Rank<T>,
+}
+
+impl<T: 'static> TableColumn<T> {
+    /// A column sorted by its text. Filters always match against the text.
+    pub fn new(header: Signal<String>, text: impl Fn(&T) -> String + 'static) -> Self {
+        let text = Rc::new(text);
+        let sort_text = Rc::clone(&text);
+        TableColumn {
+            header,
+            text,
+            rank: ranked_by(move |row| sort_text(row)),
+        }
+    }
+
+    /// Sorts by `sort_key` instead of the text, e.g. numbers that shouldn't sort as "10" < "9".
+    pub fn sort_by_key<O: Ord>(mut self, sort_key: impl Fn(&T) -> O + 'static) -> Self {
+        self.rank = ranked_by(sort_key);
+        self
+    }
+}
+
+/// Ranks rows by `sort_key`, made once per row and sort rather than on every comparison.
+fn ranked_by<T: 'static, O: Ord>(sort_key: impl Fn(&T) -> O + 'static) -> Rank<T> {
+    Rc::new(move |rows| {
+        let keys = rows
+            .iter()
+            .map(|row| row.with(|row| sort_key(row)))
+            .collect::<Vec<_>>();
+        ranks(&keys)
+    })
+}
+
+/// The rank of each key among `keys`, from 0: equal keys share a rank.
+fn ranks<O: Ord>(keys: &[O]) -> Vec<usize> {
+    let mut order = (0..keys.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| keys[*a].cmp(&keys[*b]));
+    let mut ranks = vec![0; keys.len()];
+    for position in 1..order.len() {
+        let (previous, current) = (order[position - 1], order[position]);
+        ranks[current] = if keys[previous] == keys[current] {
+            ranks[previous]
+        } else {
+            position
+        };
+    }
+    ranks
+}
+
+/// The order of `len` rows by the ranks of the sort columns, most significant first. Rows
+/// that tie on all of them keep their order.
+fn sorted_order(len: usize, sort: &[(Vec<usize>, SortDirection)]) -> Vec<usize> {
+    let mut order = (0..len).collect::<Vec<_>>();
+    order.sort_by(|a, b| {
+        sort.iter()
+            .map(|(ranks, direction)| {
+                let ordering = ranks[*a].cmp(&ranks[*b]);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+    order
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// A table over rows that are each their own signal.
+///
+/// Updating one row only re-renders that row's cells. Sorting and filtering reorder the
+/// keyed rows without rebuilding them. Click a header to sort by it, shift+click to add it
+/// as a further sort key.
+#[component]
+pub fn DataTable<T, K, KF>(
+    #[prop(into)] rows: Signal<Vec<RwSignal<T>>>,
+    columns: Vec<TableColumn<T>>,
+    /// Identifies a row in `selected`.
+    row_key: KF,
+    /// The keys of the checked rows, for the parent to act on.
+    #[prop(optional)]
+    selected: Option<RwSignal<HashSet<K>>>,
+) -> impl IntoView
+where
+    T: 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Copy + 'static,
+{
+    let columns: Rc<[TableColumn<T>]> = columns.into();
+    let selected = selected.unwrap_or_else(|| create_rw_signal(HashSet::new()));
+    // (column index, direction), most significant first
+    let (sort, set_sort) = create_signal(Vec::<(usize, SortDirection)>::new());
+    let filters = (0..columns.len())
+        .map(|_| create_rw_signal(String::new()))
+        .collect::<Vec<_>>();
+
+    let visible_rows = create_memo({
+        let columns = Rc::clone(&columns);
+        let filters = filters.clone();
+        move |_| {
+            let filters = filters
+                .iter()
+                .map(|filter| filter.get().to_lowercase())
+                .collect::<Vec<_>>();
+            let mut visible = rows.get();
+            visible.retain(|row| {
+                row.with(|row| {
+                    columns.iter().zip(&filters).all(|(column, filter)| {
+                        filter.is_empty() || (column.text)(row).to_lowercase().contains(filter)
+                    })
+                })
+            });
+            let ranks = sort.with(|sort| {
+                sort.iter()
+                    .map(|(index, direction)| ((columns[*index].rank)(&visible), *direction))
+                    .collect::<Vec<_>>()
+            });
+            if ranks.is_empty() {
+                return visible;
+            }
+            sorted_order(visible.len(), &ranks)
+                .into_iter()
+                .map(|index| visible[index])
+                .collect()
+        }
+    });
+
+    let on_header_click = move |index: usize, ev: MouseEvent| {
+        set_sort.update(|sort| {
+            let current = sort.iter().position(|(column, _)| *column == index);
+            // ascending, then descending, then not sorted by this column at all
+            let next = match current.map(|position| sort[position].1) {
+                None => Some(SortDirection::Ascending),
+                Some(SortDirection::Ascending) => Some(SortDirection::Descending),
+                Some(SortDirection::Descending) => None,
+            };
+            if !ev.shift_key() {
+                sort.clear();
+            } else if let Some(position) = current {
+                sort.remove(position);
+            }
+            if let Some(direction) = next {
+                sort.push((index, direction));
+            }
+        });
+    };
+
+    // only the most significant sort column has an `aria-sort`, as there is one per table
+    let aria_sort = move |index: usize| {
+        sort.with(|sort| match sort.first() {
+            Some((column, SortDirection::Ascending)) if *column == index => Some("ascending"),
+            Some((column, SortDirection::Descending)) if *column == index => Some("descending"),
+            _ => None,
+        })
+    };
+
+    let sort_indicator = move |index: usize| {
+        sort.with(|sort| {
+            let position = sort.iter().position(|(column, _)| *column == index)?;
+            let arrow = match sort[position].1 {
+                SortDirection::Ascending => "▲",
+                SortDirection::Descending => "▼",
+            };
+            Some(if sort.len() > 1 {
+                format!(" {arrow}{}", position + 1)
+            } else {
+                format!(" {arrow}")
+            })
+        })
+    };
+
+    let all_visible_selected = move || {
+        visible_rows.with(|rows| {
+            !rows.is_empty()
+                && selected.with(|selected| {
+                    rows.iter()
+                        .all(|row| row.with(|row| selected.contains(&row_key(row))))
+                })
+        })
+    };
+    let toggle_all_visible = move |ev| {
+        let checked = event_target_checked(&ev);
+        visible_rows.with_untracked(|rows| {
+            selected.update(|selected| {
+                for row in rows {
+                    let key = row.with_untracked(row_key);
+                    if checked {
+                        selected.insert(key);
+                    } else {
+                        selected.remove(&key);
+                    }
+                }
+            })
+        });
+    };
+
+    let headers = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            view! {
+                <th aria-sort=move || aria_sort(index)>
+                    <button
+                        type="button"
+                        class="button is-white has-text-weight-bold"
+                        on:click=move |ev| on_header_click(index, ev)
+                    >
+                        {column.header}
+                        {move || sort_indicator(index)}
+                    </button>
+                </th>
+            }
+        })
+        .collect_view();
+
+    let filter_inputs = columns
+        .iter()
+        .zip(filters)
+        .map(|(column, filter)| {
+            let header = column.header;
+            view! {
+                <th>
+                    <input
+                        class="input is-small"
+                        type="search"
+                        placeholder=t!("table-filter-placeholder")
+                        aria-label=t!("table-filter", column = header.get())
+                        prop:value=filter
+                        on:input=move |ev| filter.set(event_target_value(&ev))
+                    />
+                </th>
+            }
+        })
+        .collect_view();
+
+    let render_row = move |row: RwSignal<T>| {
+        let key = row.with_untracked(row_key);
+        let is_selected = {
+            let key = key.clone();
+            move || selected.with(|selected| selected.contains(&key))
+        };
+        let toggle = move |ev| {
+            let key = key.clone();
+            if event_target_checked(&ev) {
+                selected.update(|selected| {
+                    selected.insert(key);
+                });
+            } else {
+                selected.update(|selected| {
+                    selected.remove(&key);
+                });
+            }
+        };
+        // each cell only reads its own row, so a change elsewhere never reaches it
+        let cells = columns
+            .iter()
+            .map(|column| {
+                let text = Rc::clone(&column.text);
+                view! { <td>{move || row.with(|row| text(row))}</td> }
+            })
+            .collect_view();
+
+        view! {
+            <tr class:is-selected=is_selected.clone()>
+                <td>
+                    <input
+                        type="checkbox"
+                        aria-label=t!("table-select-row")
+                        prop:checked=is_selected
+                        on:change=toggle
+                    />
+                </td>
+                {cells}
+            </tr>
+        }
+    };
+
+    view! {
+        <table class="table is-fullwidth is-hoverable is-narrow">
+            <thead>
+                <tr>
+                    <th>
+                        <input
+                            type="checkbox"
+                            aria-label=t!("table-select-visible")
+                            prop:checked=all_visible_selected
+                            on:change=toggle_all_visible
+                        />
+                    </th>
+                    {headers}
+                </tr>
+                <tr>
+                    <th></th>
+                    {filter_inputs}
+                </tr>
+            </thead>
+            <tbody>
+                <For each=move || visible_rows.get() key=|row| *row children=render_row/>
+            </tbody>
+        </table>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_keys_share_a_rank() {
+        assert_eq!(ranks(&["b", "a", "c", "a"]), vec![2, 0, 3, 0]);
+        assert!(ranks::<u32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn sorts_by_the_first_column_that_differs() {
+        let parity = (vec![0, 1, 0, 1], SortDirection::Ascending);
+        let value = (vec![0, 1, 2, 3], SortDirection::Descending);
+        assert_eq!(sorted_order(4, &[parity, value]), vec![2, 0, 3, 1]);
+    }
+
+    #[test]
+    fn ties_keep_their_order() {
+        let all_equal = (vec![0, 0, 0], SortDirection::Descending);
+        assert_eq!(sorted_order(3, &[all_equal]), vec![0, 1, 2]);
+    }
+}
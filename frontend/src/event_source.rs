@@ -0,0 +1,210 @@
+use crate::fallible::RetryPolicy;
+use crate::websocket::ConnectionState;
+use gloo_timers::callback::Timeout;
+use leptos::*;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::prelude::*;
+use web_sys::{EventSource, MessageEvent};
+
+struct Source {
+    source: EventSource,
+    event: String,
+    // the handlers must live as long as the source may call them
+    _on_open: Closure<dyn FnMut()>,
+    on_event: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut()>,
+}
+
+impl Drop for Source {
+    fn drop(&mut self) {
+        self.source.set_onopen(None);
+        self.source.set_onerror(None);
+        _ = self.source.remove_event_listener_with_callback(
+            &self.event,
+            self.on_event.as_ref().unchecked_ref(),
+        );
+        self.source.close();
+    }
+}
+
+/// Server-Sent Events carrying JSON, the latest of which is kept in a signal.
+///
+/// After a dropped connection the browser reconnects on its own, sending the id of the last
+/// event it got as `Last-Event-ID` so the server can send the ones missed. Once the browser gives
+/// up, e.g. on an error response, the stream connects anew itself and passes that id as
+/// `?last_event_id=`, since a new `EventSource` can't set headers.
+pub struct EventStream<T: 'static> {
+    url: StoredValue<String>,
+    event: StoredValue<String>,
+    policy: RetryPolicy,
+    state: RwSignal<ConnectionState>,
+    latest: RwSignal<Option<T>>,
+    last_event_id: RwSignal<Option<String>>,
+    source: StoredValue<Option<Source>>,
+    retry: StoredValue<Option<Timeout>>,
+    failed_attempts: StoredValue<u32>,
+}
+
+impl<T> Clone for EventStream<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EventStream<T> {}
+
+/// Listens to the events named `event` that `url` sends. The stream closes with its owner.
+pub fn create_event_stream<T>(
+    url: impl Into<String>,
+    event: impl Into<String>,
+    policy: RetryPolicy,
+) -> EventStream<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    let stream = EventStream {
+        url: store_value(url.into()),
+        event: store_value(event.into()),
+        policy,
+        state: create_rw_signal(ConnectionState::Connecting),
+        latest: create_rw_signal(None),
+        last_event_id: create_rw_signal(None),
+        source: store_value(None),
+        retry: store_value(None),
+        failed_attempts: store_value(0),
+    };
+    on_cleanup(move || {
+        stream.retry.set_value(None);
+        stream.source.set_value(None);
+    });
+    stream.connect();
+    stream
+}
+
+impl<T> EventStream<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    /// The last event that parsed as `T`, `None` until the first arrives.
+    pub fn latest(&self) -> Signal<Option<T>>
+    where
+        T: Clone,
+    {
+        self.latest.into()
+    }
+
+    pub fn state(&self) -> Signal<ConnectionState> {
+        self.state.into()
+    }
+
+    /// The id of the last event, if the server gives them ids.
+    pub fn last_event_id(&self) -> Signal<Option<String>> {
+        self.last_event_id.into()
+    }
+
+    /// Connects again right away, e.g. after the stream gave up. Missed events follow, if the
+    /// server keeps them.
+    pub fn reconnect(&self) {
+        self.retry.set_value(None);
+        self.failed_attempts.set_value(0);
+        self.state.set(ConnectionState::Connecting);
+        self.connect();
+    }
+
+    fn connect(self) {
+        let url = self
+            .url
+            .with_value(|url| match self.last_event_id.get_untracked() {
+                Some(id) => {
+                    let separator = if url.contains('?') { '&' } else { '?' };
+                    format!(
+                        "{url}{separator}last_event_id={}",
+                        js_sys::encode_uri_component(&id)
+                    )
+                }
+                None => url.clone(),
+            });
+        let source = match EventSource::new(&url) {
+            Ok(source) => source,
+            Err(_) => {
+                self.retry_later();
+                return;
+            }
+        };
+
+        let on_open = Closure::<dyn FnMut()>::new(move || {
+            self.failed_attempts.set_value(0);
+            self.state.set(ConnectionState::Open);
+        });
+        let on_event = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let id = event.last_event_id();
+            if !id.is_empty() {
+                self.last_event_id.set(Some(id));
+            }
+            let Some(data) = event.data().as_string() else {
+                return;
+            };
+            match serde_json::from_str::<T>(&data) {
+                Ok(value) => self.latest.set(Some(value)),
+                Err(error) => logging::warn!("ignoring event {data:?}: {error}"),
+            }
+        });
+        let on_error = Closure::<dyn FnMut()>::new(move || {
+            let browser_retries = self.source.with_value(|source| {
+                source
+                    .as_ref()
+                    .is_some_and(|source| source.source.ready_state() == EventSource::CONNECTING)
+            });
+            if browser_retries {
+                self.browser_retries();
+            } else {
+                self.retry_later();
+            }
+        });
+
+        let event = self.event.get_value();
+        source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        _ = source.add_event_listener_with_callback(&event, on_event.as_ref().unchecked_ref());
+        self.source.set_value(Some(Source {
+            source,
+            event,
+            _on_open: on_open,
+            on_event,
+            _on_error: on_error,
+        }));
+    }
+
+    /// The browser reconnects by itself, after the delay the server asked for; count it against
+    /// the policy all the same.
+    fn browser_retries(self) {
+        let attempt = self.failed_attempts.get_value() + 1;
+        if attempt > self.policy.retries {
+            // only close it: this runs in a handler of the source, which dropping it would free
+            self.source.with_value(|source| {
+                if let Some(source) = source {
+                    source.source.close();
+                }
+            });
+            self.state.set(ConnectionState::Closed);
+            return;
+        }
+        self.failed_attempts.set_value(attempt);
+        self.state.set(ConnectionState::Reconnecting { attempt });
+    }
+
+    fn retry_later(self) {
+        let attempt = self.failed_attempts.get_value();
+        if attempt >= self.policy.retries {
+            self.state.set(ConnectionState::Closed);
+            return;
+        }
+        self.failed_attempts.set_value(attempt + 1);
+        self.state.set(ConnectionState::Reconnecting {
+            attempt: attempt + 1,
+        });
+        // connect from the timer, not from the handler of the old source, which this replaces
+        let timeout = Timeout::new(self.policy.delay_ms(attempt), move || self.connect());
+        self.retry.set_value(Some(timeout));
+    }
+}
@@ -0,0 +1,392 @@
+use crate::a11y::unique_id;
+use crate::bulma::{BButton, BColor, Color, Message};
+use crate::i18n::t;
+use gloo_timers::future::TimeoutFuture;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+
+/// Why a (fake) API call failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApiError {
+    /// The server answered with an error. Worth trying again.
+    Unavailable,
+    /// No answer in time. Worth trying again.
+    Timeout,
+    /// The thing asked for doesn't exist. Asking again won't change that.
+    NotFound,
+    /// There is no network. Worth trying again, it may be back by then.
+    Offline,
+    /// The caller stopped waiting, e.g. because a newer load superseded this one.
+    Aborted,
+}
+
+impl ApiError {
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ApiError::Unavailable | ApiError::Timeout | ApiError::Offline
+        )
+    }
+
+    /// What went wrong, in the current locale. `Display` is for logs.
+    pub fn message(&self) -> Signal<String> {
+        match self {
+            ApiError::Unavailable => t!("error-unavailable"),
+            ApiError::Timeout => t!("error-timeout"),
+            ApiError::NotFound => t!("error-not-found"),
+            ApiError::Offline => t!("error-offline"),
+            ApiError::Aborted => t!("error-aborted"),
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Unavailable => write!(f, "the server is unavailable"),
+            ApiError::Timeout => write!(f, "the request timed out"),
+            ApiError::NotFound => write!(f, "not found"),
+            ApiError::Offline => write!(f, "you are offline"),
+            ApiError::Aborted => write!(f, "the request was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// How often, and how patiently, a failed call is tried again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Tries after the first one. 0 means a failure is final.
+    pub retries: u32,
+    /// The wait before the first retry; it doubles for every retry after that.
+    pub base_delay_ms: u32,
+    /// The wait never grows past this.
+    pub max_delay_ms: u32,
+    /// Up to this fraction of every wait is taken off at random, so clients that failed
+    /// together don't all come back at the same moment. 0.0 to 1.0.
+    pub jitter: f64,
+}
+
+impl RetryPolicy {
+    pub const NEVER: RetryPolicy = RetryPolicy {
+        retries: 0,
+        base_delay_ms: 0,
+        max_delay_ms: 0,
+        jitter: 0.0,
+    };
+
+    /// The wait before retry number `retry`, counting from 0.
+    pub fn delay_ms(&self, retry: u32) -> u32 {
+        self.delay_ms_with(retry, js_sys::Math::random())
+    }
+
+    /// `delay_ms` with the random number from 0.0 to 1.0 the jitter is taken from.
+    fn delay_ms_with(&self, retry: u32, random: f64) -> u32 {
+        let backoff = self
+            .base_delay_ms
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay_ms);
+        let jitter = f64::from(backoff) * self.jitter.clamp(0.0, 1.0) * random;
+        backoff.saturating_sub(jitter as u32)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            retries: 3,
+            base_delay_ms: 250,
+            max_delay_ms: 4_000,
+            jitter: 0.5,
+        }
+    }
+}
+
+/// Calls `attempt` until it succeeds, fails with an error that isn't retryable, or `policy`
+/// runs out of retries. `attempt` gets the number of the try, starting at 1.
+pub async fn with_retry<T, Fut>(
+    policy: RetryPolicy,
+    attempt: impl FnMut(u32) -> Fut,
+) -> Result<T, ApiError>
+where
+    Fut: Future<Output = Result<T, ApiError>>,
+{
+    retry_with(policy, attempt, |retry| {
+        TimeoutFuture::new(policy.delay_ms(retry))
+    })
+    .await
+}
+
+/// `with_retry`, waiting out retry number `retry` with `wait(retry)`.
+async fn retry_with<T, Fut, Wait>(
+    policy: RetryPolicy,
+    mut attempt: impl FnMut(u32) -> Fut,
+    mut wait: impl FnMut(u32) -> Wait,
+) -> Result<T, ApiError>
+where
+    Fut: Future<Output = Result<T, ApiError>>,
+    Wait: Future,
+{
+    let mut retry = 0;
+    loop {
+        match attempt(retry + 1).await {
+            Err(error) if error.is_retryable() && retry < policy.retries => {
+                wait(retry).await;
+                retry += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Makes the fake loaders of the demos fail on purpose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FailureMode {
+    #[default]
+    Never,
+    /// Half of the calls fail, so retries usually get through in the end.
+    Sometimes,
+    Always,
+}
+
+impl FailureMode {
+    /// Each mode, with the value of its `<option>`.
+    const ALL: [(FailureMode, &'static str); 3] = [
+        (FailureMode::Never, "never"),
+        (FailureMode::Sometimes, "sometimes"),
+        (FailureMode::Always, "always"),
+    ];
+
+    fn label(self) -> Signal<String> {
+        match self {
+            FailureMode::Never => t!("failures-never"),
+            FailureMode::Sometimes => t!("failures-sometimes"),
+            FailureMode::Always => t!("failures-always"),
+        }
+    }
+
+    /// Whether the call at hand succeeds, and if not, how it failed.
+    pub fn roll(self) -> Result<(), ApiError> {
+        let failure_rate = match self {
+            FailureMode::Never => 0.0,
+            FailureMode::Sometimes => 0.5,
+            FailureMode::Always => 1.0,
+        };
+        let roll = js_sys::Math::random();
+        if roll >= failure_rate {
+            Ok(())
+        } else if roll < failure_rate / 2.0 {
+            Err(ApiError::Unavailable)
+        } else {
+            Err(ApiError::Timeout)
+        }
+    }
+}
+
+/// Picks the `FailureMode` of a demo.
+#[component]
+pub fn FailureSwitch(failures: RwSignal<FailureMode>) -> impl IntoView {
+    // a page can have several
+    let id = unique_id("inject-failures");
+
+    view! {
+        <div class="field is-horizontal">
+            <label class="label is-small mr-2" for=id.clone()>
+                {t!("failures-inject")}
+            </label>
+            <div class="select is-small">
+                <select
+                    id=id
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        if let Some((mode, _)) = FailureMode::ALL
+                            .into_iter()
+                            .find(|(_, option)| *option == value)
+                        {
+                            failures.set(mode);
+                        }
+                    }
+                >
+                    {FailureMode::ALL
+                        .into_iter()
+                        .map(|(mode, option)| {
+                            view! {
+                                <option value=option selected=move || failures() == mode>
+                                    {mode.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+        </div>
+    }
+}
+
+/// A failed load, with a "Retry" button calling `on_retry`.
+#[component]
+pub fn LoadError(error: ApiError, #[prop(into)] on_retry: Callback<()>) -> impl IntoView {
+    let message = error.message();
+
+    view! {
+        <Message color=Color::Danger>
+            <p>{t!("failures-load-failed", error = message.get())}</p>
+            <BButton color=BColor::Danger on:click=move |_| on_retry(())>
+                {t!("failures-retry")}
+            </BButton>
+        </Message>
+    }
+}
+
+/// `<Suspense/>` for views built from fallible resources.
+///
+/// While loading it shows `fallback`. If a resource read inside resolves to an `Err`, the
+/// errors are listed with a "Retry" button calling `on_retry`, which should refetch them;
+/// once they load fine the children come back on their own.
+#[component]
+pub fn FallibleSuspense<F, FV>(
+    fallback: F,
+    #[prop(into)] on_retry: Callback<()>,
+    children: ChildrenFn,
+) -> impl IntoView
+where
+    F: Fn() -> FV + 'static,
+    FV: IntoView + 'static,
+{
+    let children = store_value(children);
+
+    view! {
+        <ErrorBoundary fallback=move |errors| {
+            view! {
+                <Message color=Color::Danger title=t!("failures-title")>
+                    <ul>
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|(_, error)| {
+                                    match error.downcast_ref::<ApiError>() {
+                                        Some(error) => view! { <li>{error.message()}</li> },
+                                        None => view! { <li>{error.to_string()}</li> },
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </ul>
+                    <BButton color=BColor::Danger on:click=move |_| on_retry(())>
+                        {t!("failures-retry")}
+                    </BButton>
+                </Message>
+            }
+        }>
+            <Suspense fallback=fallback>{children.with_value(|children| children())}</Suspense>
+        </ErrorBoundary>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::future::ready;
+    use std::cell::RefCell;
+
+    const POLICY: RetryPolicy = RetryPolicy {
+        retries: 3,
+        base_delay_ms: 100,
+        max_delay_ms: 1_000,
+        jitter: 0.5,
+    };
+
+    #[test]
+    fn delays_double_up_to_the_maximum() {
+        let delays = (0..6)
+            .map(|retry| POLICY.delay_ms_with(retry, 0.0))
+            .collect::<Vec<_>>();
+        assert_eq!(delays, [100, 200, 400, 800, 1_000, 1_000]);
+        assert_eq!(POLICY.delay_ms_with(u32::MAX, 0.0), 1_000);
+    }
+
+    #[test]
+    fn jitter_takes_off_up_to_its_fraction() {
+        assert_eq!(POLICY.delay_ms_with(1, 1.0), 100);
+        assert_eq!(POLICY.delay_ms_with(1, 0.5), 150);
+    }
+
+    #[test]
+    fn jitter_out_of_range_is_clamped() {
+        let wild = RetryPolicy {
+            jitter: 3.0,
+            ..POLICY
+        };
+        assert_eq!(wild.delay_ms_with(0, 1.0), 0);
+        let negative = RetryPolicy {
+            jitter: -1.0,
+            ..POLICY
+        };
+        assert_eq!(negative.delay_ms_with(0, 1.0), 100);
+    }
+
+    /// Runs `with_retry` over `results`, one per try, and returns its result, the tries and
+    /// the retries it waited for.
+    fn run(
+        policy: RetryPolicy,
+        results: Vec<Result<u32, ApiError>>,
+    ) -> (Result<u32, ApiError>, Vec<u32>, Vec<u32>) {
+        let tries = RefCell::new(Vec::new());
+        let waits = RefCell::new(Vec::new());
+        let mut results = results.into_iter();
+        let result = block_on(retry_with(
+            policy,
+            |attempt| {
+                tries.borrow_mut().push(attempt);
+                ready(results.next().expect("no more tries than results"))
+            },
+            |retry| {
+                waits.borrow_mut().push(retry);
+                ready(())
+            },
+        ));
+        (result, tries.into_inner(), waits.into_inner())
+    }
+
+    #[test]
+    fn success_needs_no_retry() {
+        assert_eq!(run(POLICY, vec![Ok(1)]), (Ok(1), vec![1], vec![]));
+    }
+
+    #[test]
+    fn retries_until_it_succeeds() {
+        let results = vec![Err(ApiError::Unavailable), Err(ApiError::Timeout), Ok(3)];
+        assert_eq!(run(POLICY, results), (Ok(3), vec![1, 2, 3], vec![0, 1]));
+    }
+
+    #[test]
+    fn gives_up_after_the_last_retry() {
+        let results = vec![Err(ApiError::Offline); 4];
+        assert_eq!(
+            run(POLICY, results),
+            (Err(ApiError::Offline), vec![1, 2, 3, 4], vec![0, 1, 2])
+        );
+    }
+
+    #[test]
+    fn does_not_retry_final_errors() {
+        for error in [ApiError::NotFound, ApiError::Aborted] {
+            let results = vec![Err(error.clone())];
+            assert_eq!(run(POLICY, results), (Err(error), vec![1], vec![]));
+        }
+    }
+
+    #[test]
+    fn never_means_one_try() {
+        let results = vec![Err(ApiError::Unavailable)];
+        assert_eq!(
+            run(RetryPolicy::NEVER, results),
+            (Err(ApiError::Unavailable), vec![1], vec![])
+        );
+    }
+}
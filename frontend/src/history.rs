@@ -0,0 +1,275 @@
+use crate::bulma::{disabled_if, BButton, BButtons, BSize};
+use crate::i18n::t;
+use crate::persisted::is_syncing;
+use leptos::ev::KeyboardEvent;
+use leptos::*;
+
+/// How many steps back a history usually keeps.
+pub const DEFAULT_DEPTH: usize = 100;
+/// Changes closer together than this become a single step, so typing a word is one undo.
+pub const TYPING_COALESCE_MS: f64 = 500.0;
+
+/// Undo and redo for an `RwSignal`. It is `Copy`, like the signal it wraps.
+///
+/// Every change made to the signal in this tab is recorded, whoever makes it. Undoing and
+/// redoing set the signal back, so the views reading it follow along.
+pub struct History<T: 'static> {
+    value: RwSignal<T>,
+    steps: RwSignal<Steps<T>>,
+    // set while undo or redo write to `value`, so that write isn't recorded as a new change
+    restoring: StoredValue<bool>,
+    on_forget: StoredValue<Option<Callback<T>>>,
+}
+
+// derived impls would require `T: Copy`, but only the handles are copied
+impl<T> Clone for History<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for History<T> {}
+
+/// Starts recording the changes of `value`.
+///
+/// At most `depth` steps are kept. Changes less than `coalesce_ms` apart are merged into one
+/// step; pass `0.0` to record every change on its own.
+pub fn create_history<T>(value: RwSignal<T>, depth: usize, coalesce_ms: f64) -> History<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    let history = History {
+        value,
+        steps: create_rw_signal(Steps::new(depth, coalesce_ms)),
+        restoring: store_value(false),
+        on_forget: store_value(None),
+    };
+
+    create_effect(move |previous: Option<T>| {
+        let current = value.get();
+        if let Some(previous) = previous {
+            // another tab's change is not this tab's to undo
+            if previous != current && !history.restoring.get_value() && !is_syncing() {
+                let forgotten = history
+                    .steps
+                    .try_update(|steps| steps.record(previous, js_sys::Date::now()))
+                    .unwrap_or_default();
+                history.forget(forgotten);
+            }
+        }
+        current
+    });
+
+    history
+}
+
+impl<T: Clone + 'static> History<T> {
+    fn restore(self, value: T) {
+        self.restoring.set_value(true);
+        self.value.set(value);
+        self.restoring.set_value(false);
+    }
+
+    fn forget(self, steps: Vec<T>) {
+        if let Some(on_forget) = self.on_forget.get_value() {
+            steps.into_iter().for_each(|step| on_forget(step));
+        }
+    }
+
+    pub fn undo(self) {
+        let current = self.value.get_untracked();
+        if let Some(previous) = self.steps.try_update(|steps| steps.undo(current)).flatten() {
+            self.restore(previous);
+        }
+    }
+
+    pub fn redo(self) {
+        let current = self.value.get_untracked();
+        if let Some(next) = self.steps.try_update(|steps| steps.redo(current)).flatten() {
+            self.restore(next);
+        }
+    }
+
+    pub fn can_undo(self) -> Signal<bool> {
+        Signal::derive(move || self.steps.with(|steps| !steps.past.is_empty()))
+    }
+
+    pub fn can_redo(self) -> Signal<bool> {
+        Signal::derive(move || self.steps.with(|steps| !steps.future.is_empty()))
+    }
+
+    /// Whether any step kept for undo or redo matches `predicate`.
+    pub fn any_step(self, predicate: impl Fn(&T) -> bool) -> bool {
+        self.steps
+            .with_untracked(|steps| steps.past.iter().chain(&steps.future).any(predicate))
+    }
+
+    /// Calls `on_forget` with every step the history lets go of: the oldest once there are more
+    /// than `depth`, the undone ones once a new change is made, and those merged into another.
+    /// That is when whatever only undo kept alive can be released.
+    pub fn on_forget(self, on_forget: impl Fn(T) + 'static) {
+        self.on_forget.set_value(Some(Callback::new(on_forget)));
+    }
+
+    /// Handles Ctrl+Z (undo) and Ctrl+Shift+Z or Ctrl+Y (redo), with Cmd in place of Ctrl on macOS.
+    ///
+    /// Attach it to the element around the controls, `on:keydown=move |ev| history.on_keydown(ev)`,
+    /// so each history only answers for its own part of the page.
+    pub fn on_keydown(self, ev: KeyboardEvent) {
+        if !(ev.ctrl_key() || ev.meta_key()) || ev.alt_key() {
+            return;
+        }
+        match ev.key().to_lowercase().as_str() {
+            "z" if ev.shift_key() => self.redo(),
+            "z" => self.undo(),
+            "y" => self.redo(),
+            _ => return,
+        }
+        // the browser would otherwise undo the text of the focused input on its own
+        ev.prevent_default();
+    }
+}
+
+/// The values to go back and forth to, without anything reactive.
+#[derive(Debug, Clone)]
+struct Steps<T> {
+    past: Vec<T>,
+    future: Vec<T>,
+    last_change_ms: f64,
+    depth: usize,
+    coalesce_ms: f64,
+}
+
+impl<T> Steps<T> {
+    fn new(depth: usize, coalesce_ms: f64) -> Self {
+        Steps {
+            past: Vec::new(),
+            future: Vec::new(),
+            last_change_ms: f64::NEG_INFINITY,
+            depth,
+            coalesce_ms,
+        }
+    }
+
+    /// Records a change away from `previous` made at `now_ms`, and returns the steps no longer
+    /// kept.
+    fn record(&mut self, previous: T, now_ms: f64) -> Vec<T> {
+        let coalesce = now_ms - self.last_change_ms < self.coalesce_ms && !self.past.is_empty();
+        self.last_change_ms = now_ms;
+        let mut forgotten = std::mem::take(&mut self.future);
+        if coalesce {
+            // the step before already goes back to before `previous`
+            forgotten.push(previous);
+        } else {
+            self.past.push(previous);
+            if self.past.len() > self.depth {
+                forgotten.push(self.past.remove(0));
+            }
+        }
+        forgotten
+    }
+
+    /// The value to go back to from `current`, if there is one.
+    fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.past.pop()?;
+        self.future.push(current);
+        // the next change starts a new step, however soon it comes
+        self.last_change_ms = f64::NEG_INFINITY;
+        Some(previous)
+    }
+
+    /// The value undone from `current`, if there is one.
+    fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.future.pop()?;
+        self.past.push(current);
+        self.last_change_ms = f64::NEG_INFINITY;
+        Some(next)
+    }
+}
+
+/// Undo and redo buttons for a `History`.
+#[component]
+pub fn HistoryControls<T: Clone + 'static>(history: History<T>) -> impl IntoView {
+    view! {
+        <BButtons has_addons=true>
+            <BButton
+                size=BSize::Small
+                state=disabled_if(move || !history.can_undo().get())
+                on:click=move |_| history.undo()
+            >
+                {t!("history-undo")}
+            </BButton>
+            <BButton
+                size=BSize::Small
+                state=disabled_if(move || !history.can_redo().get())
+                on:click=move |_| history.redo()
+            >
+                {t!("history-redo")}
+            </BButton>
+        </BButtons>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_and_redo() {
+        let mut steps = Steps::new(10, 0.0);
+        steps.record(1, 0.0);
+        steps.record(2, 10.0);
+        // the value is now 3
+        assert_eq!(steps.undo(3), Some(2));
+        assert_eq!(steps.undo(2), Some(1));
+        assert_eq!(steps.undo(1), None);
+        assert_eq!(steps.redo(1), Some(2));
+        assert_eq!(steps.redo(2), Some(3));
+        assert_eq!(steps.redo(3), None);
+    }
+
+    #[test]
+    fn a_change_drops_what_was_undone() {
+        let mut steps = Steps::new(10, 0.0);
+        steps.record(1, 0.0);
+        steps.record(2, 10.0);
+        steps.undo(3);
+        steps.undo(2);
+        // from 1, the value changes to 4
+        assert_eq!(steps.record(1, 20.0), vec![3, 2]);
+        assert_eq!(steps.redo(4), None);
+        assert_eq!(steps.undo(4), Some(1));
+    }
+
+    #[test]
+    fn quick_changes_are_one_step() {
+        let mut steps = Steps::new(10, 500.0);
+        assert!(steps.record('a', 0.0).is_empty());
+        assert_eq!(steps.record('b', 100.0), vec!['b']);
+        assert_eq!(steps.record('c', 550.0), vec!['c']);
+        // a pause, then a new step
+        assert!(steps.record('d', 1_100.0).is_empty());
+        assert_eq!(steps.undo('e'), Some('d'));
+        assert_eq!(steps.undo('d'), Some('a'));
+    }
+
+    #[test]
+    fn undo_starts_a_new_step() {
+        let mut steps = Steps::new(10, 500.0);
+        steps.record(1, 0.0);
+        steps.record(2, 1_000.0);
+        assert_eq!(steps.undo(3), Some(2));
+        // soon after the undo, but not merged into the step before it
+        assert_eq!(steps.record(2, 1_100.0), vec![3]);
+        assert_eq!(steps.past, vec![1, 2]);
+    }
+
+    #[test]
+    fn only_depth_steps_are_kept() {
+        let mut steps = Steps::new(2, 0.0);
+        assert!(steps.record(1, 0.0).is_empty());
+        assert!(steps.record(2, 10.0).is_empty());
+        assert_eq!(steps.record(3, 20.0), vec![1]);
+        assert_eq!(steps.past, vec![2, 3]);
+    }
+}
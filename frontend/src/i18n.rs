@@ -0,0 +1,365 @@
+//! Translations, from the Fluent files in `locales/`, embedded at build time.
+//!
+//! `build.rs` checks them: every file parses, every locale has every message, and every key
+//! passed to `t!`, `t_split!` or `t_string!` exists.
+//!
+//! All the app's texts are translated, the demos' included. Code the demos show, sample data and
+//! the `Display` of errors, which is for logs, stay as they are.
+
+use crate::persisted::{create_persisted_signal, StorageKey};
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use intl_memoizer::{IntlLangMemoizer, Memoizable};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use unic_langid::LanguageIdentifier;
+use wasm_bindgen::prelude::*;
+
+/// The message `key` in the current locale, as a `Signal<String>` for views.
+///
+/// Arguments become Fluent variables, `t!("cart-items", count = items.with(Vec::len))` sets
+/// `$count`. They are evaluated in the signal, so the text follows the signals they read too.
+macro_rules! t {
+    ($key:literal) => {{
+        let i18n = $crate::i18n::use_i18n();
+        ::leptos::Signal::derive(move || i18n.translate($key, None))
+    }};
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let i18n = $crate::i18n::use_i18n();
+        ::leptos::Signal::derive(move || {
+            let mut args = ::fluent_bundle::FluentArgs::new();
+            $(args.set(stringify!($name), $value);)+
+            i18n.translate($key, Some(&args))
+        })
+    }};
+}
+
+pub(crate) use t;
+
+/// The message `key` split where its argument `$name` goes, as a `Signal<(String, String)>`, for a
+/// message around a link or other markup: the view puts that between the two halves.
+///
+/// `t_split!("demos-last-visit", demo)` splits `Last time you were at { $demo }.`
+macro_rules! t_split {
+    ($key:literal, $name:ident) => {{
+        let i18n = $crate::i18n::use_i18n();
+        ::leptos::Signal::derive(move || i18n.translate_split($key, stringify!($name)))
+    }};
+}
+
+pub(crate) use t_split;
+
+/// The message `key` in the current locale of `i18n`, as a `String`, for text that a view doesn't
+/// show as it is, like a notification: it keeps the locale it was made in.
+///
+/// `t_string!(i18n, "async-todo-added", id = id.to_string())`
+macro_rules! t_string {
+    ($i18n:expr, $key:literal) => {
+        ::leptos::untrack(|| $i18n.translate($key, None))
+    };
+    ($i18n:expr, $key:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = ::fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        ::leptos::untrack(|| $i18n.translate($key, Some(&args)))
+    }};
+}
+
+pub(crate) use t_string;
+
+/// Stands in for the argument `translate_split` splits at: a private use character, which no
+/// message has.
+const SPLIT_AT: &str = "\u{E000}";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Locale {
+    En,
+    De,
+    Fr,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::En, Locale::De, Locale::Fr];
+
+    /// The language tag, which its file in `locales/` is named after.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+            Locale::Fr => "fr",
+        }
+    }
+
+    /// The name of the language, in that language.
+    pub fn name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::De => "Deutsch",
+            Locale::Fr => "Français",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Locale::En => include_str!("../locales/en.ftl"),
+            Locale::De => include_str!("../locales/de.ftl"),
+            Locale::Fr => include_str!("../locales/fr.ftl"),
+        }
+    }
+
+    /// The first of the browser's languages there is a translation for.
+    fn preferred() -> Locale {
+        window()
+            .navigator()
+            .languages()
+            .iter()
+            .filter_map(|language| language.as_string())
+            .find_map(|language| {
+                let primary = language.split('-').next().unwrap_or_default();
+                Locale::ALL
+                    .into_iter()
+                    .find(|locale| locale.tag().eq_ignore_ascii_case(primary))
+            })
+            .unwrap_or(Locale::En)
+    }
+
+    fn bundle(self) -> FluentBundle<FluentResource> {
+        let resource = FluentResource::try_new(self.source().to_string())
+            .unwrap_or_else(|_| panic!("locales/{}.ftl parses, build.rs checked", self.tag()));
+        let language = self
+            .tag()
+            .parse::<LanguageIdentifier>()
+            .expect("locale tags are valid");
+        let mut bundle = FluentBundle::new(vec![language]);
+        // no Unicode isolation marks around arguments: all of these are written left to right
+        bundle.set_use_isolating(false);
+        bundle.set_formatter(Some(format_number));
+        bundle
+            .add_builtins()
+            .expect("the builtins are only added once");
+        bundle
+            .add_resource(resource)
+            .unwrap_or_else(|_| panic!("locales/{}.ftl has no duplicate messages", self.tag()));
+        bundle
+    }
+}
+
+thread_local! {
+    static BUNDLES: [FluentBundle<FluentResource>; 3] = Locale::ALL.map(Locale::bundle);
+}
+
+/// The locale the app is shown in. It starts out as the browser's, and is remembered once
+/// picked.
+#[derive(Clone, Copy)]
+pub struct I18n {
+    locale: RwSignal<Locale>,
+}
+
+impl I18n {
+    pub fn new() -> Self {
+        let locale = create_persisted_signal(StorageKey::new("locale", 1), Locale::preferred());
+        // so screen readers pronounce the page right, and the browser hyphenates it right
+        create_effect(move |_| {
+            if let Some(html) = document().document_element() {
+                _ = html.set_attribute("lang", locale.get().tag());
+            }
+        });
+        I18n { locale }
+    }
+
+    /// Always `locale`, and without the browser, for tests.
+    #[cfg(test)]
+    pub fn fixed(locale: Locale) -> Self {
+        I18n {
+            locale: create_rw_signal(locale),
+        }
+    }
+
+    pub fn locale(&self) -> Signal<Locale> {
+        self.locale.into()
+    }
+
+    pub fn set_locale(&self, locale: Locale) {
+        self.locale.set(locale);
+    }
+
+    /// The message `key` in the current locale, `key` itself if there is none. Prefer `t!`,
+    /// whose keys `build.rs` checks.
+    pub fn translate(&self, key: &str, args: Option<&FluentArgs>) -> String {
+        let locale = self.locale.get();
+        BUNDLES.with(|bundles| {
+            let bundle = &bundles[locale as usize];
+            let Some(pattern) = bundle.get_message(key).and_then(|message| message.value()) else {
+                logging::warn!("no message {key:?} in locales/{}.ftl", locale.tag());
+                return key.to_string();
+            };
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+            for error in errors {
+                logging::warn!("message {key:?} in locales/{}.ftl: {error}", locale.tag());
+            }
+            text.into_owned()
+        })
+    }
+
+    /// The message `key` in the current locale, split where the argument `name` goes. All of it
+    /// comes first if it has no such argument. Prefer `t_split!`.
+    pub fn translate_split(&self, key: &str, name: &str) -> (String, String) {
+        let mut args = FluentArgs::new();
+        args.set(name, SPLIT_AT);
+        let text = self.translate(key, Some(&args));
+        match text.split_once(SPLIT_AT) {
+            Some((before, after)) => (before.to_string(), after.to_string()),
+            None => (text, String::new()),
+        }
+    }
+}
+
+impl Default for I18n {
+    fn default() -> Self {
+        I18n::new()
+    }
+}
+
+pub fn use_i18n() -> I18n {
+    use_context::<I18n>().expect("I18n provided")
+}
+
+/// Picks the locale. Goes in the navbar.
+#[component]
+pub fn LanguageSwitcher() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="select is-small">
+            <select
+                aria-label=t!("nav-language")
+                on:change=move |ev| {
+                    let tag = event_target_value(&ev);
+                    if let Some(locale) = Locale::ALL.into_iter().find(|locale| locale.tag() == tag) {
+                        i18n.set_locale(locale);
+                    }
+                }
+            >
+                {Locale::ALL
+                    .into_iter()
+                    .map(|locale| {
+                        view! {
+                            <option
+                                value=locale.tag()
+                                lang=locale.tag()
+                                selected=move || i18n.locale().get() == locale
+                            >
+                                {locale.name()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
+
+/// The options of a Fluent number that `Intl.NumberFormat` cares about.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct NumberOptions {
+    style: &'static str,
+    currency: Option<String>,
+    use_grouping: bool,
+    minimum_fraction_digits: Option<usize>,
+    maximum_fraction_digits: Option<usize>,
+}
+
+struct NumberFormat(js_sys::Intl::NumberFormat);
+
+impl Memoizable for NumberFormat {
+    type Args = NumberOptions;
+    type Error = ();
+
+    fn construct(language: LanguageIdentifier, args: NumberOptions) -> Result<Self, ()> {
+        // `Intl.NumberFormat` throws on a currency style without a currency
+        if args.style == "currency" && args.currency.is_none() {
+            return Err(());
+        }
+        let options = js_sys::Object::new();
+        let set = |name: &str, value: JsValue| {
+            _ = js_sys::Reflect::set(&options, &JsValue::from_str(name), &value);
+        };
+        set("style", JsValue::from_str(args.style));
+        set("useGrouping", JsValue::from_bool(args.use_grouping));
+        if let Some(currency) = &args.currency {
+            set("currency", JsValue::from_str(currency));
+        }
+        if let Some(digits) = args.minimum_fraction_digits {
+            set("minimumFractionDigits", JsValue::from_f64(digits as f64));
+        }
+        if let Some(digits) = args.maximum_fraction_digits {
+            set("maximumFractionDigits", JsValue::from_f64(digits as f64));
+        }
+        let locales = js_sys::Array::of1(&JsValue::from_str(&language.to_string()));
+        Ok(NumberFormat(js_sys::Intl::NumberFormat::new(
+            &locales, &options,
+        )))
+    }
+}
+
+/// Writes numbers the way the locale does, with the browser's `Intl.NumberFormat`: Fluent on its
+/// own has no thousands separators, and a decimal point everywhere.
+fn format_number(value: &FluentValue, memoizer: &IntlLangMemoizer) -> Option<String> {
+    use fluent_bundle::types::FluentNumberStyle;
+
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+    let options = &number.options;
+    let args = NumberOptions {
+        style: match options.style {
+            FluentNumberStyle::Decimal => "decimal",
+            FluentNumberStyle::Currency => "currency",
+            FluentNumberStyle::Percent => "percent",
+        },
+        currency: options.currency.clone(),
+        use_grouping: options.use_grouping,
+        minimum_fraction_digits: options.minimum_fraction_digits,
+        maximum_fraction_digits: options.maximum_fraction_digits,
+    };
+    memoizer
+        .with_try_get::<NumberFormat, _, _>(args, |format| {
+            format
+                .0
+                .format()
+                .call1(&JsValue::UNDEFINED, &JsValue::from_f64(number.value))
+                .ok()?
+                .as_string()
+        })
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(before: &str, after: &str) -> (String, String) {
+        (before.to_string(), after.to_string())
+    }
+
+    #[test]
+    fn splits_messages_where_the_argument_goes() {
+        let runtime = create_runtime();
+        let i18n = I18n::fixed(Locale::En);
+        assert_eq!(
+            i18n.translate_split("demos-last-visit", "demo"),
+            split("Last time you were at ", ".")
+        );
+        i18n.set_locale(Locale::De);
+        assert_eq!(
+            i18n.translate_split("demos-last-visit", "demo"),
+            split("Zuletzt warst du bei ", ".")
+        );
+        // no such argument
+        assert_eq!(
+            i18n.translate_split("demos-default", "demo"),
+            split("Das ist die Standardansicht von /demos", "")
+        );
+        runtime.dispose();
+    }
+}
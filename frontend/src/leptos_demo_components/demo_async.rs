@@ -1,13 +1,26 @@
-use gloo_timers::future::TimeoutFuture;
+use crate::abortable::{create_abortable_query, sleep};
+use crate::bulma::{BButton, BColor, BSize, Tab, Tabs, TabsAlignment, TabsStyle};
+use crate::fallible::{
+    with_retry, ApiError, FailureMode, FailureSwitch, FallibleSuspense, LoadError, RetryPolicy,
+};
+use crate::i18n::{t, t_string, use_i18n};
+use crate::network::{use_network, Network, NetworkPanel};
+use crate::query_cache::{create_query, use_query_client, QueryDevtools};
+use crate::timeline::{create_timeline, Phase, SuspenseProbe, TimelineChart};
+use crate::timing::debounced;
+use crate::toast::{use_notifier, NotificationLevel};
 use leptos::html::Input;
 use leptos::*;
+use std::cell::RefCell;
 use uuid::Uuid;
+use web_sys::AbortSignal;
 
 #[component]
 pub fn DemoAsync() -> impl IntoView {
     view! {
         <div class="section">
-            <h1 class="title">Demo Async</h1>
+            <h1 class="title">{t!("async-title")}</h1>
+            <NetworkPanel/>
             <ul>
                 <li>
                     <Demo01/>
@@ -27,7 +40,14 @@ pub fn DemoAsync() -> impl IntoView {
                 <li>
                     <Demo05/>
                 </li>
+                <li>
+                    <Demo06/>
+                </li>
+                <li>
+                    <Demo07/>
+                </li>
             </ul>
+            <QueryDevtools/>
         </div>
     }
 }
@@ -35,16 +55,19 @@ pub fn DemoAsync() -> impl IntoView {
 // Here we define an async function
 // This could be anything: a network request, database read, etc.
 // Here, we just multiply a number by 10
-async fn load_data(value: i32) -> i32 {
-    // fake a one-second delay
-    TimeoutFuture::new(1_000).await;
-    value * 10
+async fn load_data(value: i32, network: Network, failures: FailureMode) -> Result<i32, ApiError> {
+    // fake a round trip to the server, which may end in an error like a real request
+    network.request(1, failures).await?;
+    Ok(value * 10)
 }
 
 #[component]
 pub fn Demo01() -> impl IntoView {
+    let network = use_network();
     // this count is our synchronous, local state
     let (count, set_count) = create_signal(0);
+    let failures = create_rw_signal(FailureMode::Never);
+    let (attempts, set_attempts) = create_signal(0);
 
     // create_resource takes two arguments after its scope
     let async_data = create_resource(
@@ -53,45 +76,70 @@ pub fn Demo01() -> impl IntoView {
         // the second is the loader
         // it takes the source signal's value as its argument
         // and does some async work
-        |value| async move { load_data(value).await },
+        // failed attempts are tried again, waiting longer each time
+        move |value| {
+            with_retry(RetryPolicy::default(), move |attempt| {
+                set_attempts(attempt);
+                load_data(value, network, failures.get_untracked())
+            })
+        },
     );
     // whenever the source signal changes, the loader reloads
 
     // you can also create resources that only load once
     // just return the unit type () from the source signal
     // that doesn't depend on anything: we just load it once
-    let stable = create_resource(|| (), |_| async move { load_data(1).await });
+    let stable = create_resource(
+        || (),
+        move |_| async move { load_data(1, network, FailureMode::Never).await },
+    );
 
     // we can access the resource values with .read()
     // this will reactively return None before the Future has resolved
     // and update to Some(T) when it has resolved
+    let loading_text = t!("async-loading");
     let async_result = move || {
         async_data
             .get()
-            .map(|value| format!("Server returned {value:?}"))
+            .map(|value| {
+                t!(
+                    "async-returned",
+                    value = format!("{value:?}"),
+                    attempts = attempts()
+                )
+                .get()
+            })
             // This loading state will only show before the first load
-            .unwrap_or_else(|| "Loading...".to_string())
+            .unwrap_or_else(|| loading_text.get())
     };
 
     // the resource's loading() method gives us a
     // signal to indicate whether it's currently loading
     let loading = async_data.loading();
-    let is_loading = move || if loading() { "Loading..." } else { "Idle." };
+    let idle_text = t!("async-idle");
+    let is_loading = move || {
+        if loading() {
+            loading_text.get()
+        } else {
+            idle_text.get()
+        }
+    };
 
     view! {
         <div class="container">
-            <h1 class="subtitle">Load data with resource</h1>
+            <h2 class="subtitle">{t!("async-resource")}</h2>
+            <FailureSwitch failures/>
 
             <button on:click=move |_| {
                 set_count.update(|n| *n += 1);
             }>
 
-                "Click me"
+                {t!("async-click-me")}
             </button>
             <p>
                 <code>"stable"</code>
                 ": "
-                {move || stable.get()}
+                {move || stable.get().map(|value| format!("{value:?}"))}
             </p>
             <p>
                 <code>"count"</code>
@@ -111,51 +159,76 @@ pub fn Demo01() -> impl IntoView {
 
 #[component]
 fn ShowA(a: i32) -> impl IntoView {
-    view! { <p>some A: {a}</p> }
+    view! { <p>{t!("async-some-a", value = a)}</p> }
 }
 
 #[component]
 fn ShowB(b: i32) -> impl IntoView {
-    view! { <p>some B: {b}</p> }
+    view! { <p>{t!("async-some-b", value = b)}</p> }
 }
 
 // Here we define an async function
 // This could be anything: a network request, database read, etc.
 // Here, we just multiply a number by 10
-async fn load_a(value: i32) -> i32 {
-    // fake a one-second delay
-    TimeoutFuture::new(5_000).await;
-    value * 10
+async fn load_a(value: i32, network: Network, failures: FailureMode) -> Result<i32, ApiError> {
+    // fake a slow request: five round trips
+    network.request(5, failures).await?;
+    Ok(value * 10)
 }
 
-async fn load_b(value: i32) -> i32 {
-    // fake a one-second delay
-    TimeoutFuture::new(1_000).await;
-    value * 10
+async fn load_b(value: i32, network: Network, failures: FailureMode) -> Result<i32, ApiError> {
+    // fake a single round trip
+    network.request(1, failures).await?;
+    Ok(value * 10)
 }
 
 /// Demo await on multiple resources V1 using match
 #[component]
 pub fn Demo02V1() -> impl IntoView {
-    let (count, _set_count) = create_signal(0);
-    let (count2, _set_count2) = create_signal(0);
-    let a = create_resource(count, |count| async move { load_a(count).await });
-    let b = create_resource(count2, |count| async move { load_b(count).await });
+    let network = use_network();
+    let (count, set_count) = create_signal(0);
+    let (count2, set_count2) = create_signal(0);
+    let failures = create_rw_signal(FailureMode::Never);
+    let a = create_resource(count, move |count| {
+        load_a(count, network, failures.get_untracked())
+    });
+    let b = create_resource(count2, move |count| {
+        load_b(count, network, failures.get_untracked())
+    });
+    let retry = move |_| {
+        a.refetch();
+        b.refetch();
+    };
+
+    // a resource keeps its value while it reloads, so "Loading..." only shows the first time
+    let timeline = create_timeline();
+    timeline.track_resource("A", a);
+    timeline.track_resource("B", b);
+    timeline.track("match", Phase::Fallback, move || match (a.get(), b.get()) {
+        (Some(Err(_)), _) | (_, Some(Err(_))) => false,
+        (a, b) => a.is_none() || b.is_none(),
+    });
 
     view! {
         <div class="container">
-            <h1 class="subtitle">"Demo: Wait two resources v1"</h1>
+            <h2 class="subtitle">{t!("async-two-v1")}</h2>
+            <FailureSwitch failures/>
+            <ReloadButtons set_count set_count2/>
             {move || match (a.get(), b.get()) {
-                (Some(a), Some(b)) => {
+                (Some(Ok(a)), Some(Ok(b))) => {
                     view! {
                         <ShowA a/>
                         <ShowB b/>
                     }
                         .into_view()
                 }
-                _ => view! { <p>"Loading..."</p> }.into_view(),
+                // one failure is enough to fail the whole view
+                (Some(Err(error)), _) | (_, Some(Err(error))) => {
+                    view! { <LoadError error on_retry=retry/> }.into_view()
+                }
+                _ => view! { <p>{t!("async-loading")}</p> }.into_view(),
             }}
-
+            <TimelineChart timeline/>
         </div>
     }
 }
@@ -163,64 +236,127 @@ pub fn Demo02V1() -> impl IntoView {
 /// Demo await on multiple resources V2 using Suspense
 #[component]
 pub fn Demo02V2() -> impl IntoView {
-    let (count, _set_count) = create_signal(0);
-    let (count2, _set_count2) = create_signal(0);
-    let a = create_resource(count, |count| async move { load_a(count).await });
-    let b = create_resource(count2, |count| async move { load_b(count).await });
+    let network = use_network();
+    let (count, set_count) = create_signal(0);
+    let (count2, set_count2) = create_signal(0);
+    let failures = create_rw_signal(FailureMode::Never);
+    let a = create_resource(count, move |count| {
+        load_a(count, network, failures.get_untracked())
+    });
+    let b = create_resource(count2, move |count| {
+        load_b(count, network, failures.get_untracked())
+    });
+    let retry = move |_| {
+        a.refetch();
+        b.refetch();
+    };
+
+    // the suspense falls back every time either resource reloads
+    let timeline = create_timeline();
+    timeline.track_resource("A", a);
+    timeline.track_resource("B", b);
 
     view! {
         <div class="container">
-            <h1 class="subtitle">"Demo: Wait two resources v2"</h1>
-            <Suspense fallback=move || view! { <p>"Loading..."</p> }>
-                <h4>"My Data"</h4>
+            <h2 class="subtitle">{t!("async-two-v2")}</h2>
+            <FailureSwitch failures/>
+            <ReloadButtons set_count set_count2/>
+            // an `Err` read inside is caught by the boundary around the suspense
+            <FallibleSuspense fallback=move || view! { <p>{t!("async-loading")}</p> } on_retry=retry>
+                <SuspenseProbe timeline name="Suspense"/>
+                <h4>{t!("async-my-data")}</h4>
                 <h5>"A"</h5>
-                {move || { a.get().map(|a| view! { <ShowA a/> }) }}
+                {move || { a.get().map(|a| a.map(|a| view! { <ShowA a/> })) }}
 
                 <h5>"B"</h5>
-                {move || { b.get().map(|b| view! { <ShowB b/> }) }}
+                {move || { b.get().map(|b| b.map(|b| view! { <ShowB b/> })) }}
 
-            </Suspense>
+            </FallibleSuspense>
+            <TimelineChart timeline/>
         </div>
     }
 }
 
-async fn fetch_monkeys(monkey: i32) -> i32 {
+/// Reloads the resources of `Demo02V1` and `Demo02V2`, by changing their sources.
+#[component]
+fn ReloadButtons(set_count: WriteSignal<i32>, set_count2: WriteSignal<i32>) -> impl IntoView {
+    view! {
+        <div class="buttons">
+            <BButton size=BSize::Small on:click=move |_| set_count.update(|n| *n += 1)>
+                {t!("async-reload-a")}
+            </BButton>
+            <BButton size=BSize::Small on:click=move |_| set_count2.update(|n| *n += 1)>
+                {t!("async-reload-b")}
+            </BButton>
+        </div>
+    }
+}
+
+async fn fetch_monkeys(
+    monkey: i32,
+    network: Network,
+    failures: FailureMode,
+) -> Result<i32, ApiError> {
     // maybe this didn't need to be async
-    TimeoutFuture::new(5_000).await;
-    monkey * 2
+    network.request(5, failures).await?;
+    Ok(monkey * 2)
 }
 
 /// Demo 6.2 <Await/> to only render loaded resource
 /// Not loaded resource are simply not rendered at all.
 #[component]
 pub fn Demo03() -> impl IntoView {
+    let network = use_network();
+    let failures = create_rw_signal(FailureMode::Never);
+    // <Await/> only runs its future once, so retrying means rendering it again
+    let (tries, set_tries) = create_signal(0);
+    let retry = move |_| set_tries.update(|n| *n += 1);
+
     view! {
         <div class="container">
-            <h3 class="subtitle">
-                "Demo: use <Await/> for some future to resolve before rendering"
-            </h3>
-            <Await
-                // `future` provides the `Future` to be resolved
-                future=|| fetch_monkeys(3)
-                // the data is bound to whatever variable name you provide
-                let:data
-            >
-                // you receive the data by reference and can use it in your view here
-                <p>{*data} " little monkeys, jumping on the bed."</p>
-            </Await>
+            <h3 class="subtitle">{t!("async-await")}</h3>
+            <FailureSwitch failures/>
+            {move || {
+                tries.track();
+                view! {
+                    <Await
+                        // `future` provides the `Future` to be resolved
+                        future=move || fetch_monkeys(3, network, failures.get_untracked())
+                        // the data is bound to whatever variable name you provide
+                        let:data
+                    >
+                        // you receive the data by reference and can use it in your view here
+                        {match data {
+                            Ok(monkeys) => {
+                                let monkeys = *monkeys;
+                                view! { <p>{t!("async-monkeys", monkeys = monkeys)}</p> }.into_view()
+                            }
+                            Err(error) => {
+                                view! { <LoadError error=error.clone() on_retry=retry/> }.into_view()
+                            }
+                        }}
+                    </Await>
+                }
+            }}
         </div>
     }
 }
 
-async fn important_api_call(id: usize) -> String {
-    TimeoutFuture::new(1_000).await;
-    match id {
-        0 => "Alice",
-        1 => "Bob",
-        2 => "Carol",
-        _ => "User not found",
-    }
-    .to_string()
+/// With some jitter on the network, a later call often finishes first.
+/// Once `signal` is aborted, the call stops waiting and its timer is cleared.
+async fn important_api_call(
+    id: usize,
+    network: Network,
+    failures: FailureMode,
+    signal: AbortSignal,
+) -> Result<String, ApiError> {
+    let latency = network.latency_ms(1);
+    sleep(latency, &signal).await?;
+    network.outcome(failures)?;
+    Ok(format!(
+        "{} (after {latency} ms)",
+        important_api_call_result(id)?
+    ))
 }
 
 /// Use Transition instead of Suspense to prevent falling back every time when user
@@ -228,32 +364,91 @@ async fn important_api_call(id: usize) -> String {
 /// This seperate initial loading and other loadings.
 #[component]
 pub fn Demo04() -> impl IntoView {
+    let network = use_network();
     let (tab, set_tab) = create_signal(0);
+    let (started, set_started) = create_signal(0);
+    let (cancelled, set_cancelled) = create_signal(0);
+    let failures = create_rw_signal(FailureMode::Never);
+
+    // this will reload every time `tab` changes, but through the query cache:
+    // each tab is kept under its own key, "user/0" to "user/2",
+    // so going back to a tab that was already loaded doesn't wait again.
+    // Clicking through the tabs quickly aborts the loads of the tabs left behind,
+    // so a slow answer for one of them can't come in after the selected tab's
+    let user_data = create_abortable_query("user", tab, move |id, signal| {
+        set_started.update(|n| *n += 1);
+        let failures = failures.get_untracked();
+        async move {
+            let result = with_retry(RetryPolicy::default(), |_| {
+                important_api_call(id, network, failures, signal.clone())
+            })
+            .await;
+            if result == Err(ApiError::Aborted) {
+                set_cancelled.update(|n| *n += 1);
+            }
+            result
+        }
+    });
+    // failures aren't cached, so retrying is loading again
+    let retry = Callback::new(move |_| user_data.refetch());
 
-    // this will reload every time `tab` changes
-    let user_data = create_resource(tab, |tab| async move { important_api_call(tab).await });
+    // tabs loaded before come from the cache, so they don't show up as loads at all
+    let timeline = create_timeline();
+    timeline.track_resource("user", user_data);
 
     view! {
         <div class="container">
-            <h3 class="subtitle">Demo Transition</h3>
-            <div class="buttons">
-                <button on:click=move |_| set_tab(0) class:selected=move || tab() == 0>
-                    "Tab A"
-                </button>
-                <button on:click=move |_| set_tab(1) class:selected=move || tab() == 1>
-                    "Tab B"
-                </button>
-                <button on:click=move |_| set_tab(2) class:selected=move || tab() == 2>
-                    "Tab C"
-                </button>
-            </div>
+            <h3 class="subtitle">{t!("async-transition")}</h3>
+            <FailureSwitch failures/>
+            <Tabs style=TabsStyle::Toggle alignment=TabsAlignment::Centered>
+                <Tab active=Signal::derive(move || tab() == 0)>
+                    <button
+                        type="button"
+                        class="button is-white"
+                        aria-pressed=move || (tab() == 0).to_string()
+                        on:click=move |_| set_tab(0)
+                    >
+                        {t!("async-tab", tab = "A")}
+                    </button>
+                </Tab>
+                <Tab active=Signal::derive(move || tab() == 1)>
+                    <button
+                        type="button"
+                        class="button is-white"
+                        aria-pressed=move || (tab() == 1).to_string()
+                        on:click=move |_| set_tab(1)
+                    >
+                        {t!("async-tab", tab = "B")}
+                    </button>
+                </Tab>
+                <Tab active=Signal::derive(move || tab() == 2)>
+                    <button
+                        type="button"
+                        class="button is-white"
+                        aria-pressed=move || (tab() == 2).to_string()
+                        on:click=move |_| set_tab(2)
+                    >
+                        {t!("async-tab", tab = "C")}
+                    </button>
+                </Tab>
+            </Tabs>
             // the fallback will show initially
             // on subsequent reloads, the current child will
             // continue showing
-            <Transition fallback=move || view! { <p>"Loading initial data..."</p> }>
-                <p>{move || user_data.get()}</p>
+            <Transition fallback=move || view! { <p>{t!("async-loading-initial")}</p> }>
+                <SuspenseProbe timeline name="Transition" transition=true/>
+                {move || {
+                    user_data
+                        .get()
+                        .map(|user| match user {
+                            Ok(name) => view! { <p>{name}</p> }.into_view(),
+                            Err(error) => view! { <LoadError error on_retry=retry/> }.into_view(),
+                        })
+                }}
             </Transition>
-            {move || if user_data.loading().get() { "Hang on..." } else { "" }}
+            {move || user_data.loading().get().then(|| t!("async-hang-on"))}
+            <p>{t!("async-loads", started = started(), cancelled = cancelled())}</p>
+            <TimelineChart timeline/>
         </div>
     }
 }
@@ -262,14 +457,21 @@ pub fn Demo04() -> impl IntoView {
 /// Usually it is with button to submit something
 #[component]
 pub fn Demo05() -> impl IntoView {
+    let network = use_network();
     // an action takes an async function with single argument
     // it can be a simple type, a struct, or ()
-    let add_todo = create_action(|input: &String| {
+    let failures = create_rw_signal(FailureMode::Never);
+    let add_todo = create_action(move |input: &String| {
         // the input is a reference, but we need the Future to own it
         // this is important: we need to clone and move into the Future
         // so it has a 'static lifetime
         let input = input.to_owned();
-        async move { add_todo(&input).await }
+        let failures = failures.get_untracked();
+        // adding isn't idempotent: a retry after a timeout could add the todo twice
+        with_retry(RetryPolicy::NEVER, move |_| {
+            let input = input.clone();
+            async move { add_todo(&input, network, failures).await }
+        })
     });
 
     // actions provide a bunch of synchronous, reactive variables
@@ -280,22 +482,78 @@ pub fn Demo05() -> impl IntoView {
 
     let input_ref = create_node_ref::<Input>();
 
+    // the list is cached; adding a todo makes it stale, so it is fetched again
+    let todos = create_query("todos", || "all", move |_| fetch_todos(network));
+    let query_client = use_query_client();
+
+    // surface the result as a toast instead of only dumping the debug values below
+    let notifier = use_notifier();
+    let i18n = use_i18n();
+    create_effect(move |_| match todo_id() {
+        Some(Ok(id)) => {
+            query_client.invalidate("todos");
+            notifier.notify(
+                NotificationLevel::Success,
+                t_string!(i18n, "async-todo-added", id = id.to_string()),
+            );
+        }
+        Some(Err(error)) => notifier.notify(
+            NotificationLevel::Danger,
+            t_string!(
+                i18n,
+                "async-todo-failed",
+                error = error.message().get_untracked()
+            ),
+        ),
+        None => {}
+    });
+
     view! {
         <div class="container">
-            <h3 class="subtitle">Demo Action</h3>
+            <h3 class="subtitle">{t!("async-action")}</h3>
+            <FailureSwitch failures/>
             <form on:submit=move |ev| {
                 ev.prevent_default();
                 let input = input_ref.get().expect("input to exist");
+                notifier
+                    .notify(
+                        NotificationLevel::Info,
+                        t_string!(i18n, "async-adding", todo = input.value()),
+                    );
                 add_todo.dispatch(input.value());
             }>
 
-                <label>"What do you need to do?" <input type="text" node_ref=input_ref/></label>
-                <button type="submit">"Add Todo"</button>
+                <label>
+                    {t!("async-what-to-do")} " " <input type="text" node_ref=input_ref/>
+                </label>
+                <button type="submit">{t!("async-add-todo")}</button>
             </form>
-            <p>{move || pending().then(|| "Loading...")}</p>
-            <p>"Submitted: " <code>{move || format!("{:#?}", submitted())}</code></p>
-            <p>"Pending: " <code>{move || format!("{:#?}", pending())}</code></p>
-            <p>"Todo ID: " <code>{move || format!("{:#?}", todo_id())}</code></p>
+            <p>{move || pending().then(|| t!("async-loading"))}</p>
+            <p>
+                {t!("async-submitted")} " " <code>{move || format!("{:#?}", submitted())}</code>
+            </p>
+            <p>{t!("async-pending")} " " <code>{move || format!("{:#?}", pending())}</code></p>
+            <p>{t!("async-todo-id")} " " <code>{move || format!("{:#?}", todo_id())}</code></p>
+            <h4>
+                {move || {
+                    if query_client.is_fetching("todos/all") {
+                        t!("async-todos-refreshing")
+                    } else {
+                        t!("async-todos")
+                    }
+                }}
+            </h4>
+            <ul>
+                {move || {
+                    todos
+                        .get()
+                        .and_then(Result::ok)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|todo| view! { <li>{todo}</li> })
+                        .collect_view()
+                }}
+            </ul>
         </div>
     }
 }
@@ -304,10 +562,154 @@ pub fn Demo05() -> impl IntoView {
 // This could be anything: a network request, database read, etc.
 // Think of it as a mutation: some imperative async action you run,
 // whereas a resource would be some async data you load
-async fn add_todo(text: &str) -> Uuid {
-    _ = text;
-    // fake a one-second delay
-    TimeoutFuture::new(1_000).await;
+async fn add_todo(text: &str, network: Network, failures: FailureMode) -> Result<Uuid, ApiError> {
+    // fake a round trip to the server
+    network.request(1, failures).await?;
+    TODOS.with(|todos| todos.borrow_mut().push(text.to_string()));
     // pretend this is a post ID or something
-    Uuid::new_v4()
+    Ok(Uuid::new_v4())
+}
+
+thread_local! {
+    // stands in for the server's database
+    static TODOS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+async fn fetch_todos(network: Network) -> Result<Vec<String>, ApiError> {
+    network.request(1, FailureMode::Never).await?;
+    Ok(TODOS.with(|todos| todos.borrow().clone()))
+}
+
+/// Demo the notification service provided at the App root.
+/// Click quickly to see older toasts make room for newer ones.
+#[component]
+pub fn Demo06() -> impl IntoView {
+    let notifier = use_notifier();
+    let i18n = use_i18n();
+    let levels = [
+        (t!("async-info"), NotificationLevel::Info),
+        (t!("async-success"), NotificationLevel::Success),
+        (t!("async-warning"), NotificationLevel::Warning),
+        (t!("async-danger"), NotificationLevel::Danger),
+    ];
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("async-notifications")}</h3>
+            <div class="buttons">
+                {levels
+                    .into_iter()
+                    .map(|(label, level)| {
+                        view! {
+                            <BButton
+                                color=BColor::from(level.color())
+                                on:click=move |_| {
+                                    notifier
+                                        .notify(
+                                            level,
+                                            t_string!(
+                                                i18n, "async-notification", level = label.get_untracked()
+                                            ),
+                                        )
+                                }
+                            >
+
+                                {label}
+                            </BButton>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+}
+
+const FRUITS: [&str; 12] = [
+    "apple",
+    "apricot",
+    "banana",
+    "blackberry",
+    "blueberry",
+    "cherry",
+    "grape",
+    "lemon",
+    "mango",
+    "orange",
+    "peach",
+    "pear",
+];
+
+async fn search_fruits(
+    query: String,
+    network: Network,
+    failures: FailureMode,
+) -> Result<Vec<String>, ApiError> {
+    network.request(1, failures).await?;
+    Ok(FRUITS
+        .into_iter()
+        .filter(|fruit| fruit.contains(&query.to_lowercase()))
+        .map(String::from)
+        .collect())
+}
+
+/// Search as you type without a request per keystroke:
+/// the resource only follows the query once it has been still for a moment.
+#[component]
+pub fn Demo07() -> impl IntoView {
+    let network = use_network();
+    let (query, set_query) = create_signal(String::new());
+    let settled_query = debounced(query, 400);
+    let (searches, set_searches) = create_signal(0);
+    let failures = create_rw_signal(FailureMode::Never);
+
+    let results = create_resource(settled_query, move |query| {
+        with_retry(RetryPolicy::default(), move |_| {
+            set_searches.update(|n| *n += 1);
+            search_fruits(query.clone(), network, failures.get_untracked())
+        })
+    });
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("async-search")}</h3>
+            <FailureSwitch failures/>
+            <input
+                class="input"
+                type="search"
+                placeholder=t!("async-search-fruits")
+                aria-label=t!("async-search-fruits")
+                prop:value=query
+                on:input=move |ev| set_query(event_target_value(&ev))
+            />
+            <p>{t!("async-searches", count = searches())}</p>
+            <Transition fallback=move || view! { <p>{t!("async-searching")}</p> }>
+                <ul>
+                    {move || {
+                        results
+                            .get()
+                            .map(|fruits| match fruits {
+                                Ok(fruits) => {
+                                    fruits
+                                        .into_iter()
+                                        .map(|fruit| view! { <li>{fruit}</li> })
+                                        .collect_view()
+                                }
+                                Err(error) => {
+                                    view! { <LoadError error on_retry=move |_| results.refetch()/> }
+                                }
+                            })
+                    }}
+                </ul>
+            </Transition>
+        </div>
+    }
+}
+
+fn important_api_call_result(id: usize) -> Result<&'static str, ApiError> {
+    match id {
+        0 => Ok("Alice"),
+        1 => Ok("Bob"),
+        2 => Ok("Carol"),
+        _ => Err(ApiError::NotFound),
+    }
 }
@@ -1,3 +1,6 @@
+use crate::bulma::BButton;
+use crate::i18n::t;
+use crate::persisted::{create_persisted_signal, StorageKey};
 use leptos::*;
 
 #[component]
@@ -5,7 +8,7 @@ pub fn BasicComponent() -> impl IntoView {
     view! {
         <div class="section"></div>
 
-        <h1 class="title">"Basic Component (3.1 and 3.2)"</h1>
+        <h1 class="title">{t!("basics-title")}</h1>
         <Counter/>
         <DynamicAttributes/>
     }
@@ -14,21 +17,21 @@ pub fn BasicComponent() -> impl IntoView {
 /// For shows examples from 3.1
 #[component]
 pub fn Counter() -> impl IntoView {
-    let (count, set_count) = create_signal(0);
+    // kept across reloads, and shared with any other tab showing this demo
+    let (count, set_count) =
+        create_persisted_signal(StorageKey::new("basics.counter", 1), 0).split();
 
     view! {
         <div class="container">
-            <h1 class="subtitle">Simple click button</h1>
-            <button
-                class="button"
+            <h2 class="subtitle">{t!("basics-click-button")}</h2>
+            <BButton
                 on:click=move |_| {
                     set_count.update(|n| *n += 1);
                 }
             >
 
-                "Click me: "
-                {move || count()}
-            </button>
+                {t!("basics-click-me-count", count = count())}
+            </BButton>
         </div>
     }
 }
@@ -41,9 +44,9 @@ pub fn DynamicAttributes() -> impl IntoView {
 
     let double_count = move || count() * 2;
     view! {
-        <h2 class="subtitle">Dynamic Classes</h2>
+        <h2 class="subtitle">{t!("basics-dynamic-classes")}</h2>
 
-        <p>click the below button to change the progress bar</p>
+        <p>{t!("basics-progress-hint")}</p>
         <ul>
             <li>
                 <button
@@ -56,13 +59,12 @@ pub fn DynamicAttributes() -> impl IntoView {
                     class=("button-20", move || count() % 2 == 1)
                 >
 
-                    "Click me to change progress bar: "
-                    {move || count}
+                    {t!("basics-change-progress", count = count())}
                 </button>
             </li>
         </ul>
 
-        <h2 class="subtitle">Dynamic Sytle</h2>
+        <h2 class="subtitle">{t!("basics-dynamic-style")}</h2>
         <ul>
 
             <li>
@@ -81,13 +83,13 @@ pub fn DynamicAttributes() -> impl IntoView {
                     // Set a CSS variable for stylesheet use
                     style=("--columns", x)
                 >
-                    "Click to Move"
+                    {t!("basics-move")}
                 </button>
             </li>
 
         </ul>
 
-        <h2 class="subtitle">Dynamic Attributes</h2>
+        <h2 class="subtitle">{t!("basics-dynamic-attributes")}</h2>
         <ul>
             <li>
                 <progress
@@ -100,8 +102,8 @@ pub fn DynamicAttributes() -> impl IntoView {
 
         </ul>
 
-        <h3 class="subtitle">Derived Signals</h3>
-        <p>"It shows a signal could be derived from another signal "</p>
+        <h3 class="subtitle">{t!("basics-derived")}</h3>
+        <p>{t!("basics-derived-intro")}</p>
         <ul>
             <li>
                 <progress
@@ -114,7 +116,7 @@ pub fn DynamicAttributes() -> impl IntoView {
             <li>
                 <p>
                     // and again here
-                    "Double Count: " {double_count}
+                    {t!("basics-double-count", count = double_count())}
                 </p>
             </li>
         </ul>
@@ -127,19 +129,18 @@ pub fn ComponentsAndProps() -> impl IntoView {
     let double_count = move || count() * 2;
     view! {
         <div class="section">
-            <h1 class="title">Components And Props</h1>
+            <h1 class="title">{t!("props-title")}</h1>
 
             // now we use our component!
             <ul>
-                <p>The following example shows how we pass the progress prop</p>
-                <button
-                    class="button"
+                <p>{t!("props-intro")}</p>
+                <BButton
                     on:click=move |_| {
                         set_count.update(|n| *n += 1);
                     }
                 >
-                    "Click me"
-                </button>
+                    {t!("props-click-me")}
+                </BButton>
 
                 <li>
                     <ProgressBarV1 progress=count/>
@@ -147,7 +148,7 @@ pub fn ComponentsAndProps() -> impl IntoView {
                 <li>
                     <ProgressBarV1 progress=double_count/>
                 </li>
-                <p>"Show the use of #[prop(into)]"</p>
+                <p>{t!("props-into")}</p>
                 <li>
                     <ProgressBarV2 progress=count/>
                 </li>
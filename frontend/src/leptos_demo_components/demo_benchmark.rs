@@ -0,0 +1,514 @@
+use crate::bulma::{BBox, BButton, BSize, Tab, Tabs, TabsStyle};
+use crate::i18n::t;
+use leptos::*;
+
+// the same word lists as js-framework-benchmark, so labels look familiar
+const ADJECTIVES: [&str; 25] = [
+    "pretty",
+    "large",
+    "big",
+    "small",
+    "tall",
+    "short",
+    "long",
+    "handsome",
+    "plain",
+    "quaint",
+    "clean",
+    "elegant",
+    "easy",
+    "angry",
+    "crazy",
+    "helpful",
+    "mushy",
+    "odd",
+    "unsightly",
+    "adorable",
+    "important",
+    "inexpensive",
+    "cheap",
+    "expensive",
+    "fancy",
+];
+const COLOURS: [&str; 11] = [
+    "red", "yellow", "blue", "green", "pink", "brown", "purple", "brown", "white", "black",
+    "orange",
+];
+const NOUNS: [&str; 13] = [
+    "table", "chair", "house", "bbq", "desk", "car", "pony", "cookie", "sandwich", "burger",
+    "pizza", "mouse", "keyboard",
+];
+
+fn random_label() -> String {
+    let pick =
+        |words: &[&'static str]| words[(js_sys::Math::random() * words.len() as f64) as usize];
+    format!("{} {} {}", pick(&ADJECTIVES), pick(&COLOURS), pick(&NOUNS))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    /// Keyed by id, the label is its own signal.
+    FineGrained,
+    /// Keyed by id and label, so a changed row is thrown away and rendered again.
+    Coarse,
+    /// Keyed by index, each row reads its data back out of the list through a memo.
+    Index,
+}
+
+impl Strategy {
+    fn label(self) -> Signal<String> {
+        match self {
+            Strategy::FineGrained => t!("benchmark-fine"),
+            Strategy::Coarse => t!("benchmark-coarse"),
+            Strategy::Index => t!("benchmark-index"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Create1k,
+    Create10k,
+    Append1k,
+    UpdateEvery10th,
+    Swap,
+    Select,
+    Remove,
+    Clear,
+}
+
+impl Operation {
+    const ALL: [Operation; 8] = [
+        Operation::Create1k,
+        Operation::Create10k,
+        Operation::Append1k,
+        Operation::UpdateEvery10th,
+        Operation::Swap,
+        Operation::Select,
+        Operation::Remove,
+        Operation::Clear,
+    ];
+
+    fn label(self) -> Signal<String> {
+        match self {
+            Operation::Create1k => t!("benchmark-create-1k"),
+            Operation::Create10k => t!("benchmark-create-10k"),
+            Operation::Append1k => t!("benchmark-append-1k"),
+            Operation::UpdateEvery10th => t!("benchmark-update"),
+            Operation::Swap => t!("benchmark-swap"),
+            Operation::Select => t!("benchmark-select"),
+            Operation::Remove => t!("benchmark-remove"),
+            Operation::Clear => t!("benchmark-clear"),
+        }
+    }
+}
+
+/// What the two row representations have to provide to run the operations.
+trait BenchRow: Clone + 'static {
+    fn new(id: usize, label: String) -> Self;
+    fn id(&self) -> usize;
+    /// Appends " !!!" to the label of every 10th row.
+    fn update_every_10th(rows: RwSignal<Vec<Self>>);
+    /// Releases whatever reactive state the row owns once it has left the list.
+    fn dispose(self) {}
+}
+
+#[derive(Debug, Clone)]
+struct FineRow {
+    id: usize,
+    label: RwSignal<String>,
+}
+
+impl BenchRow for FineRow {
+    fn new(id: usize, label: String) -> Self {
+        FineRow {
+            id,
+            label: create_rw_signal(label),
+        }
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn update_every_10th(rows: RwSignal<Vec<Self>>) {
+        // the list itself doesn't change: only the label signals are touched
+        rows.with(|rows| {
+            for row in rows.iter().step_by(10) {
+                row.label.update(|label| label.push_str(" !!!"));
+            }
+        });
+    }
+
+    fn dispose(self) {
+        self.label.dispose();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PlainRow {
+    id: usize,
+    label: String,
+}
+
+impl BenchRow for PlainRow {
+    fn new(id: usize, label: String) -> Self {
+        PlainRow { id, label }
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn update_every_10th(rows: RwSignal<Vec<Self>>) {
+        rows.update(|rows| {
+            for row in rows.iter_mut().step_by(10) {
+                row.label.push_str(" !!!");
+            }
+        });
+    }
+}
+
+struct RowStore<R: 'static> {
+    rows: RwSignal<Vec<R>>,
+    selected: RwSignal<Option<usize>>,
+    next_id: StoredValue<usize>,
+    // rows are built in click handlers, which have no owner of their own: their signals belong to
+    // the page instead, and go with it
+    owner: Owner,
+}
+
+// derived Clone/Copy would require `R: Copy`, but the handles are Copy for any row type
+impl<R> Clone for RowStore<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for RowStore<R> {}
+
+impl<R: BenchRow> RowStore<R> {
+    fn new() -> Self {
+        RowStore {
+            rows: create_rw_signal(Vec::new()),
+            selected: create_rw_signal(None),
+            next_id: store_value(1),
+            owner: Owner::current().expect("RowStore created in a component"),
+        }
+    }
+
+    fn build_rows(&self, count: usize) -> Vec<R> {
+        let first_id = self.next_id.get_value();
+        self.next_id.set_value(first_id + count);
+        with_owner(self.owner, || {
+            (first_id..first_id + count)
+                .map(|id| R::new(id, random_label()))
+                .collect()
+        })
+    }
+
+    fn run(&self, operation: Operation) {
+        match operation {
+            Operation::Create1k => self.replace_rows(self.build_rows(1_000)),
+            Operation::Create10k => self.replace_rows(self.build_rows(10_000)),
+            Operation::Append1k => {
+                let new_rows = self.build_rows(1_000);
+                self.rows.update(|rows| rows.extend(new_rows));
+            }
+            Operation::UpdateEvery10th => R::update_every_10th(self.rows),
+            Operation::Swap => self.rows.update(|rows| {
+                if rows.len() > 998 {
+                    rows.swap(1, 998);
+                }
+            }),
+            Operation::Select => {
+                let id = self.rows.with_untracked(|rows| rows.get(1).map(R::id));
+                self.selected.set(id);
+            }
+            Operation::Remove => {
+                if let Some(id) = self.rows.with_untracked(|rows| rows.get(1).map(R::id)) {
+                    self.remove(id);
+                }
+            }
+            Operation::Clear => self.replace_rows(Vec::new()),
+        }
+    }
+
+    fn replace_rows(&self, new_rows: Vec<R>) {
+        let old_rows = self
+            .rows
+            .try_update(|rows| std::mem::replace(rows, new_rows))
+            .unwrap_or_default();
+        old_rows.into_iter().for_each(R::dispose);
+    }
+
+    fn remove(&self, id: usize) {
+        let removed = self.rows.try_update(|rows| {
+            let index = rows.iter().position(|row| row.id() == id)?;
+            Some(rows.remove(index))
+        });
+        if let Some(row) = removed.flatten() {
+            row.dispose();
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Measurement {
+    strategy: Strategy,
+    operation: Operation,
+    rows: usize,
+    /// Until the signal update returned: reactive graph plus DOM changes.
+    sync_ms: f64,
+    /// Until the next animation frame starts, which also counts any work the browser queued meanwhile.
+    frame_ms: f64,
+}
+
+#[component]
+pub fn DemoBenchmark() -> impl IntoView {
+    let (strategy, set_strategy) = create_signal(Strategy::FineGrained);
+    let (results, set_results) = create_signal(Vec::<Measurement>::new());
+    let fine = RowStore::<FineRow>::new();
+    let plain = RowStore::<PlainRow>::new();
+
+    let run = move |operation: Operation| {
+        let strategy = strategy.get_untracked();
+        let performance = window().performance().expect("Performance API available");
+        let start = performance.now();
+        let rows = match strategy {
+            Strategy::FineGrained => {
+                fine.run(operation);
+                fine.rows.with_untracked(Vec::len)
+            }
+            Strategy::Coarse | Strategy::Index => {
+                plain.run(operation);
+                plain.rows.with_untracked(Vec::len)
+            }
+        };
+        let sync_ms = performance.now() - start;
+        request_animation_frame(move || {
+            let frame_ms = performance.now() - start;
+            set_results.update(|results| {
+                results.push(Measurement {
+                    strategy,
+                    operation,
+                    rows,
+                    sync_ms,
+                    frame_ms,
+                })
+            });
+        });
+    };
+
+    let switch_to = move |next: Strategy| {
+        // start every strategy from an empty table so the numbers are comparable
+        fine.run(Operation::Clear);
+        plain.run(Operation::Clear);
+        set_strategy(next);
+    };
+
+    view! {
+        <div class="section">
+            <h1 class="title">{t!("benchmark-title")}</h1>
+            <p>{t!("benchmark-intro")}</p>
+
+            <Tabs style=TabsStyle::Boxed>
+                {[Strategy::FineGrained, Strategy::Coarse, Strategy::Index]
+                    .into_iter()
+                    .map(|option| {
+                        view! {
+                            <Tab active=Signal::derive(move || strategy() == option)>
+                                <button
+                                    type="button"
+                                    class="button is-white"
+                                    aria-pressed=move || (strategy() == option).to_string()
+                                    on:click=move |_| switch_to(option)
+                                >
+                                    {option.label()}
+                                </button>
+                            </Tab>
+                        }
+                    })
+                    .collect_view()}
+            </Tabs>
+
+            <div class="buttons">
+                {Operation::ALL
+                    .into_iter()
+                    .map(|operation| {
+                        view! {
+                            <BButton size=BSize::Small on:click=move |_| run(operation)>
+                                {operation.label()}
+                            </BButton>
+                        }
+                    })
+                    .collect_view()}
+                <BButton
+                    size=BSize::Small
+                    is_light=true
+                    on:click=move |_| set_results.update(Vec::clear)
+                >
+                    {t!("benchmark-reset")}
+                </BButton>
+            </div>
+
+            <BBox>
+                <ResultsTable results/>
+            </BBox>
+
+            <div style="max-height: 400px; overflow-y: auto;">
+                {move || match strategy() {
+                    Strategy::FineGrained => view! { <FineGrainedTable store=fine/> },
+                    Strategy::Coarse => view! { <CoarseTable store=plain/> },
+                    Strategy::Index => view! { <IndexKeyedTable store=plain/> },
+                }}
+
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn ResultsTable(results: ReadSignal<Vec<Measurement>>) -> impl IntoView {
+    view! {
+        <table class="table is-narrow is-fullwidth">
+            <thead>
+                <tr>
+                    <th>{t!("benchmark-strategy")}</th>
+                    <th>{t!("benchmark-operation")}</th>
+                    <th>{t!("benchmark-rows-after")}</th>
+                    <th>{t!("benchmark-sync")}</th>
+                    <th>{t!("benchmark-frame")}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {move || {
+                    results
+                        .get()
+                        .into_iter()
+                        .map(|measurement| {
+                            view! {
+                                <tr>
+                                    <td>{measurement.strategy.label()}</td>
+                                    <td>{measurement.operation.label()}</td>
+                                    <td>{measurement.rows}</td>
+                                    <td>{t!("benchmark-ms", ms = measurement.sync_ms)}</td>
+                                    <td>{t!("benchmark-ms", ms = measurement.frame_ms)}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()
+                }}
+
+            </tbody>
+        </table>
+    }
+}
+
+/// Keyed by id. Updating a label only touches that label's text node, and
+/// `create_selector` makes selecting a row notify just the old and the new row.
+#[component]
+fn FineGrainedTable(store: RowStore<FineRow>) -> impl IntoView {
+    let is_selected = create_selector(move || store.selected.get());
+
+    view! {
+        <table class="table is-fullwidth is-narrow is-hoverable">
+            <tbody>
+                <For
+                    each=move || store.rows.get()
+                    key=|row| row.id
+                    children=move |row| {
+                        let is_selected = is_selected.clone();
+                        view! {
+                            <tr class:is-selected=move || is_selected.selected(Some(row.id))>
+                                <td>{row.id}</td>
+                                <td>
+                                    <a on:click=move |_| store.selected.set(Some(row.id))>{row.label}</a>
+                                </td>
+                                <td>
+                                    <button class="delete" on:click=move |_| store.remove(row.id)></button>
+                                </td>
+                            </tr>
+                        }
+                    }
+                />
+
+            </tbody>
+        </table>
+    }
+}
+
+/// Keyed by the whole row. A changed label is a new key, so that row is rebuilt,
+/// and every row watches `selected` itself.
+#[component]
+fn CoarseTable(store: RowStore<PlainRow>) -> impl IntoView {
+    view! {
+        <table class="table is-fullwidth is-narrow is-hoverable">
+            <tbody>
+                <For
+                    each=move || store.rows.get()
+                    key=|row| (row.id, row.label.clone())
+                    children=move |row| {
+                        let id = row.id;
+                        view! {
+                            <tr class:is-selected=move || store.selected.get() == Some(id)>
+                                <td>{id}</td>
+                                <td>
+                                    <a on:click=move |_| store.selected.set(Some(id))>{row.label}</a>
+                                </td>
+                                <td>
+                                    <button class="delete" on:click=move |_| store.remove(id)></button>
+                                </td>
+                            </tr>
+                        }
+                    }
+                />
+
+            </tbody>
+        </table>
+    }
+}
+
+/// Keyed by position, like `DemoComplexDataIteration`. Rows are never moved,
+/// but every memo re-reads its slot whenever the list changes.
+#[component]
+fn IndexKeyedTable(store: RowStore<PlainRow>) -> impl IntoView {
+    view! {
+        <table class="table is-fullwidth is-narrow is-hoverable">
+            <tbody>
+                <For
+                    each=move || 0..store.rows.with(Vec::len)
+                    key=|index| *index
+                    children=move |index| {
+                        let row = create_memo(move |_| {
+                            store.rows.with(|rows| rows.get(index).cloned())
+                        });
+                        let id = move || row.with(|row| row.as_ref().map(|row| row.id));
+                        let label = move || row.with(|row| row.as_ref().map(|row| row.label.clone()));
+                        view! {
+                            <tr class:is-selected=move || {
+                                id().is_some() && store.selected.get() == id()
+                            }>
+                                <td>{id}</td>
+                                <td>
+                                    <a on:click=move |_| store.selected.set(id())>{label}</a>
+                                </td>
+                                <td>
+                                    <button
+                                        class="delete"
+                                        on:click=move |_| {
+                                            if let Some(id) = id() {
+                                                store.remove(id);
+                                            }
+                                        }
+                                    ></button>
+                                </td>
+                            </tr>
+                        }
+                    }
+                />
+
+            </tbody>
+        </table>
+    }
+}
@@ -1,11 +1,13 @@
+use crate::bulma::{BColor, BSize, BTag};
+use crate::i18n::t;
 use leptos::*;
 
 #[component]
 pub fn DemoControlFlow() -> impl IntoView {
     view! {
         <div class="section">
-            <h1 class="title">Demo control flow</h1>
-            <p>"It is used for should I render this part of the view or not"</p>
+            <h1 class="title">{t!("control-title")}</h1>
+            <p>{t!("control-intro")}</p>
 
             <ul>
                 <li>
@@ -26,16 +28,17 @@ pub fn DemoControlFlow() -> impl IntoView {
 
 #[component]
 pub fn Demo01() -> impl IntoView {
+    let ding = t!("control-ding");
     let message = move || {
         if is_odd() {
-            Some("Ding ding ding!")
+            Some(ding.get())
         } else {
             None
         }
     };
     view! {
         <div class="container">
-            <p class="subtitle">demo 01</p>
+            <p class="subtitle">{t!("control-demo-01")}</p>
             <p>{message}</p>
         </div>
     }
@@ -47,41 +50,41 @@ fn is_odd() -> bool {
 
 #[component]
 fn Big() -> impl IntoView {
-    view! { <p>big component</p> }
+    view! { <p>{t!("control-big-component")}</p> }
 }
 
 #[component]
 fn Small() -> impl IntoView {
-    view! { <p>small component</p> }
+    view! { <p>{t!("control-small-component")}</p> }
 }
 
 #[component]
 pub fn Demo02() -> impl IntoView {
     let (value, set_value) = create_signal(0);
+    let (big, small) = (t!("control-big"), t!("control-small"));
 
     let message = move || {
         if value() > 5 {
             logging::log!("demo_control_flow Demo02 => {}: rendering Big", value());
-            "Big"
+            big.get()
         } else {
             logging::log!("demo_control_flow Demo02 =>{}: rendering Small", value());
-            "Small"
+            small.get()
         }
     };
 
     view! {
         <div class="container">
-            <p class="subtitle">demo 02: conditional render</p>
-            <p>
-                Be careful when use "ReadSignal<i32>"
-                in the condition because it will trigger rerender due to reactive
-            </p>
+            <p class="subtitle">{t!("control-demo-02")}</p>
+            <p>{t!("control-careful")}</p>
             <button on:click=move |_| {
                 set_value.update(|n| *n += 1)
-            }>"click me:" {move || value}</button>
-            <p>{message}</p>
+            }>{t!("control-click-me", count = value())}</button>
+            <BTag color=BColor::Info size=BSize::Medium>
+                {message}
+            </BTag>
 
-            <p>solution is to use "<show/>"</p>
+            <p>{t!("control-show")}</p>
             <Show when=move || { value() > 5 } fallback=|| view! { <Big/> }>
                 <Small/>
             </Show>
@@ -94,15 +97,18 @@ pub fn Demo03() -> impl IntoView {
     let (value, set_value) = create_signal(0);
     view! {
         <div class="container">
-            <p class="subtitle">"Demo03: Type conversion for match"</p>
+            <p class="subtitle">{t!("control-demo-03")}</p>
             <button on:click=move |_| {
                 set_value.update(|n| *n += 1)
-            }>"click me:" {move || value}</button>
+            }>{t!("control-click-me", count = value())}</button>
             <div>
                 {move || match is_odd() {
-                    true if value() == 1 => view! { <pre>"One"</pre> }.into_any(),
-                    false if value() == 2 => view! { <p>"Two"</p> }.into_any(),
-                    _ => view! { <textarea>{value()}</textarea> }.into_any(),
+                    true if value() == 1 => view! { <pre>{t!("control-one")}</pre> }.into_any(),
+                    false if value() == 2 => view! { <p>{t!("control-two")}</p> }.into_any(),
+                    _ => {
+                        view! { <textarea aria-label=t!("control-value")>{value()}</textarea> }
+                            .into_any()
+                    }
                 }}
 
             </div>
@@ -1,9 +1,11 @@
+use crate::bulma::{Color, Message};
+use crate::i18n::t;
 use leptos::*;
 
 #[component]
 pub fn DemoErrorHandling() -> impl IntoView {
     view! {
-        <h1>Demo error handling</h1>
+        <h1>{t!("errors-title")}</h1>
         <ul>
             <li>
                 <Demo01/>
@@ -35,9 +37,9 @@ fn NumericInputV1() -> impl IntoView {
 
     view! {
         <label>
-            "Type a number (or not!)" <input type="number" on:input=on_input/>
-            <p>"You entered " <strong>{value}</strong></p>
+            {t!("errors-type-number")} " " <input type="number" on:input=on_input/>
         </label>
+        <p>{t!("errors-you-entered")} " " <strong>{value}</strong></p>
     }
 }
 
@@ -49,30 +51,31 @@ fn NumericInputV2() -> impl IntoView {
 
     view! {
         <label>
-            "Type a number (or something that's not a number!)"
+            {t!("errors-type-not-number")}
+            " "
             <input type="number" on:input=on_input/>
-            // the fallback receives a signal containing current errors
-            <ErrorBoundary fallback=|errors| {
-                view! {
-                    <div class="error">
-                        <p>"Not a number! Errors: "</p>
-                        // we can render a list of errors as strings, if we'd like
-                        <ul>
-                            {move || {
-                                errors
-                                    .get()
-                                    .into_iter()
-                                    .map(|(_, e)| view! { <li>{e.to_string()}</li> })
-                                    .collect_view()
-                            }}
+        </label>
+        // the fallback receives a signal containing current errors
+        <ErrorBoundary fallback=|errors| {
+            view! {
+                <Message color=Color::Danger title=t!("errors-not-a-number")>
+                    <p>{t!("errors-list")}</p>
+                    // we can render a list of errors as strings, if we'd like
+                    <ul>
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|(_, e)| view! { <li>{e.to_string()}</li> })
+                                .collect_view()
+                        }}
 
-                        </ul>
-                    </div>
-                }
-            }>
+                    </ul>
+                </Message>
+            }
+        }>
 
-                <p>"You entered " <strong>{value}</strong></p>
-            </ErrorBoundary>
-        </label>
+            <p>{t!("errors-you-entered")} " " <strong>{value}</strong></p>
+        </ErrorBoundary>
     }
 }
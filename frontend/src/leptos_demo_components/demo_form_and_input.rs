@@ -1,9 +1,13 @@
+use crate::history::{create_history, HistoryControls, DEFAULT_DEPTH, TYPING_COALESCE_MS};
+use crate::i18n::t;
+use crate::persisted::{create_persisted_signal, create_session_signal, StorageKey};
+use crate::timing::{debounced, throttled};
 use leptos::*;
 
 #[component]
 pub fn DemoFormAndInput() -> impl IntoView {
     view! {
-        <h1>"Demo form and input"</h1>
+        <h1>{t!("form-title")}</h1>
         <ul>
             <li>
                 <DemoControlledInputs/>
@@ -23,18 +27,29 @@ pub fn DemoFormAndInput() -> impl IntoView {
 
 #[component]
 pub fn DemoControlledInputs() -> impl IntoView {
-    let (name, set_name) = create_signal("Controlled".to_string());
+    let name = create_persisted_signal(
+        StorageKey::new("form.controlled_name", 1),
+        "Controlled".to_string(),
+    );
+    let history = create_history(name, DEFAULT_DEPTH, TYPING_COALESCE_MS);
+    let (name, set_name) = name.split();
+    let settled_name = debounced(name, 500);
     view! {
-        <h2>Controlled input</h2>
+        <h2>{t!("form-controlled")}</h2>
+        <HistoryControls history=history/>
+        <label for="controlled-name">{t!("form-name")} " "</label>
         <input
+            id="controlled-name"
             type="text"
             on:input=move |ev| {
                 set_name(event_target_value(&ev));
             }
 
             prop:value=name
+            on:keydown=move |ev| history.on_keydown(ev)
         />
-        <p>"Name is: " {name}</p>
+        <p>{t!("form-name-is", name = name())}</p>
+        <p>{t!("form-settled-name", name = settled_name())}</p>
     }
 }
 
@@ -62,21 +77,34 @@ pub fn DemoUncontrolledInputs() -> impl IntoView {
     };
 
     view! {
-        <h2>Uncontrolled input</h2>
+        <h2>{t!("form-uncontrolled")}</h2>
         <form on:submit=on_submit>
-            <input type="text" value=name node_ref=input_element/>
-            <input type="submit" value="Submit"/>
+            <label for="uncontrolled-name">{t!("form-name")} " "</label>
+            <input id="uncontrolled-name" type="text" value=name node_ref=input_element/>
+            <input type="submit" value=t!("form-submit")/>
         </form>
-        <p>"Name is: " {name}</p>
+        <p>{t!("form-name-is", name = name())}</p>
     }
 }
 
 #[component]
 pub fn DemoTextArea() -> impl IntoView {
-    let (some_value, set_some_value) = create_signal("text area".to_string());
+    let some_value = create_persisted_signal(
+        StorageKey::new("form.text_area", 1),
+        "text area".to_string(),
+    );
+    let history = create_history(some_value, DEFAULT_DEPTH, TYPING_COALESCE_MS);
+    let (some_value, set_some_value) = some_value.split();
+    // counting words on every keystroke is wasted work for a long text
+    let sampled_text = throttled(some_value, 250);
+    let word_count = move || sampled_text.with(|text| text.split_whitespace().count());
     view! {
-        <h2>Text area</h2>
+        <h2>{t!("form-text-area")}</h2>
+        <HistoryControls history=history/>
+        <label for="text-area">{t!("form-text")} " "</label>
         <textarea
+            id="text-area"
+            on:keydown=move |ev| history.on_keydown(ev)
             prop:value=move || some_value.get()
             on:input=move |ev| {
                 set_some_value(event_target_value(&ev));
@@ -86,16 +114,25 @@ pub fn DemoTextArea() -> impl IntoView {
             {move || some_value.get_untracked()}
         </textarea>
 
-        <p>"text is: " {some_value}</p>
+        <p>{t!("form-text-is", text = some_value())}</p>
+        <p>{t!("form-words", count = word_count())}</p>
     }
 }
 
 #[component]
 pub fn DemoSelect() -> impl IntoView {
-    let (value, set_value) = create_signal("A".to_string());
+    // only kept for this tab
+    let value = create_session_signal(StorageKey::new("form.select", 1), "A".to_string());
+    let history = create_history(value, DEFAULT_DEPTH, 0.0);
+    let (value, set_value) = value.split();
     view! {
-        <h2>Demo select</h2>
-        <select on:change=move |ev| {
+        <h2>{t!("form-select")}</h2>
+        <HistoryControls history=history/>
+        <label for="select-letter">{t!("form-letter")} " "</label>
+        <select
+            id="select-letter"
+            on:keydown=move |ev| history.on_keydown(ev)
+            on:change=move |ev| {
             let new_value = event_target_value(&ev);
             set_value(new_value);
         }>
@@ -103,7 +140,7 @@ pub fn DemoSelect() -> impl IntoView {
             <SelectOption value is="B"/>
             <SelectOption value is="C"/>
         </select>
-        <p>"Your selection is: " {value}</p>
+        <p>{t!("form-selection-is", value = value())}</p>
     }
 }
 
@@ -0,0 +1,82 @@
+use crate::i18n::{t, use_i18n};
+use leptos::*;
+
+#[component]
+pub fn DemoI18n() -> impl IntoView {
+    let i18n = use_i18n();
+    let file = move || format!("locales/{}.ftl", i18n.locale().get().tag());
+
+    view! {
+        <div class="section">
+            <h1 class="title">{t!("i18n-title")}</h1>
+            <p>{t!("i18n-intro", file = file())}</p>
+            <ul>
+                <li>
+                    <Demo01/>
+                </li>
+                <li>
+                    <Demo02/>
+                </li>
+            </ul>
+        </div>
+    }
+}
+
+/// Each language has its own plural forms, which its Fluent file picks from.
+#[component]
+fn Demo01() -> impl IntoView {
+    let (count, set_count) = create_signal(1_u32);
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("i18n-plurals")}</h3>
+            <div class="field">
+                <label class="label" for="i18n-count">
+                    {t!("i18n-items")}
+                </label>
+                <div class="control">
+                    <input
+                        id="i18n-count"
+                        class="input"
+                        type="number"
+                        min="0"
+                        prop:value=count
+                        on:input=move |ev| set_count(event_target_value(&ev).parse().unwrap_or(0))
+                    />
+                </div>
+            </div>
+            <p>{t!("i18n-cart", count = count())}</p>
+        </div>
+    }
+}
+
+/// Numbers, amounts of money and percentages, written the way the language writes them.
+#[component]
+fn Demo02() -> impl IntoView {
+    let (amount, set_amount) = create_signal(1234.5_f64);
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("i18n-numbers")}</h3>
+            <div class="field">
+                <label class="label" for="i18n-amount">
+                    {t!("i18n-amount")}
+                </label>
+                <div class="control">
+                    <input
+                        id="i18n-amount"
+                        class="input"
+                        type="number"
+                        step="any"
+                        prop:value=amount
+                        on:input=move |ev| set_amount(event_target_value(&ev).parse().unwrap_or(0.0))
+                    />
+                </div>
+            </div>
+            <p>{t!("i18n-number", amount = amount())}</p>
+            <p>{t!("i18n-currency", amount = amount())}</p>
+            <p>{t!("i18n-percent", share = amount() / 1_000.0)}</p>
+            <p>{t!("i18n-rounded", amount = amount())}</p>
+        </div>
+    }
+}
@@ -1,8 +1,18 @@
+use crate::bulma::{disabled_if, BBox, BButton, BColor};
+use crate::data_table::{DataTable, TableColumn};
+use crate::history::{create_history, HistoryControls, DEFAULT_DEPTH};
+use crate::i18n::{t, t_string, use_i18n};
+use crate::modal::Confirmer;
+use crate::query_signal::{create_query_signal_with_options, QueryOptions};
+use crate::reorder::{move_item, Reorder, ReorderHandle};
 use leptos::*;
+use std::collections::HashSet;
 
 #[component]
 pub fn DemoBasicIteration() -> impl IntoView {
     let values = vec![0, 1, 2];
+    // every <BBox/> takes its children as a `move` closure, so each one needs its own copy
+    let values_to_wrap = values.clone();
 
     // create a list of 5 signals
     let length = 5;
@@ -22,43 +32,42 @@ pub fn DemoBasicIteration() -> impl IntoView {
 
     view! {
         <div class="section">
-            <h1 class="title">"Demo iteration: static views and dynamic views"</h1>
+            <h1 class="title">{t!("iteration-title")}</h1>
             <div class="container">
-                <h2 class="subtitle">"Static List"</h2>
-                <div class="box">
+                <h2 class="subtitle">{t!("iteration-static")}</h2>
+                <BBox>
                     <p>{values.clone()}</p>
-                </div>
+                </BBox>
 
-                <div class="box">
+                <BBox>
                     // or we can wrap them in <li>
-                    <p>"we can wrap them in <li>"</p>
+                    <p>{t!("iteration-wrap")}</p>
                     <ul>
-                        {values
+                        {values_to_wrap
                             .clone()
                             .into_iter()
                             .map(|n| view! { <li>{n}</li> })
                             .collect::<Vec<_>>()}
                     </ul>
                     // .collect_view() helper function that allows you to collect any iterator of T: IntoView into Vec<View>.
-                    <ul>{values.into_iter().map(|n| view! { <li>{n}</li> }).collect_view()}</ul>
+                    <ul>{values_to_wrap.into_iter().map(|n| view! { <li>{n}</li> }).collect_view()}</ul>
 
-                </div>
+                </BBox>
 
-                <div class="box">
-                    <p>
-                        "The fact that the list is static doesn’t mean the interface needs to be static. "
-                    </p>
+                <BBox>
+                    <p>{t!("iteration-static-interface")}</p>
                     <ul>{counter_buttons}</ul>
-                </div>
+                </BBox>
 
             </div>
 
             <div class="container">
-                <h2 class="subtitle">"Dynamic List"</h2>
-                <p>"Use this pattern if the rows in your list will change."</p>
-                <div class="box">
+                <h2 class="subtitle">{t!("iteration-dynamic")}</h2>
+                <p>{t!("iteration-dynamic-intro")}</p>
+                <p>{t!("iteration-reorder")}</p>
+                <BBox>
                     <DynamicList initial_length=5/>
-                </div>
+                </BBox>
             </div>
         </div>
     }
@@ -67,9 +76,13 @@ pub fn DemoBasicIteration() -> impl IntoView {
 /// A list of counters that allows you to add or
 /// remove counters.
 #[component]
-fn DynamicList(
+pub fn DynamicList(
     /// The number of counters to begin with.
     initial_length: usize,
+    /// Ask for confirmation in a modal before removing a counter. Needs a `Confirmer` in
+    /// context; without one, counters are removed right away.
+    #[prop(optional)]
+    confirm_remove: bool,
 ) -> impl IntoView {
     // This dynamic list will use the <For/> component.
     // <For/> is a keyed list. This means that each row
@@ -91,7 +104,28 @@ fn DynamicList(
     // now we store that initial list in a signal
     // this way, we'll be able to modify the list over time,
     // adding and removing counters, and it will change reactively
-    let (counters, set_counters) = create_signal(initial_counters);
+    let counters = create_rw_signal(initial_counters);
+    // adding, removing and moving counters can be undone; the counts themselves are not recorded
+    let history = create_history(counters, DEFAULT_DEPTH, 0.0);
+    // a removed counter is kept for undo; once no step has it, it can't come back
+    history.on_forget(move |step| {
+        for (id, (count, _)) in step {
+            let has_id =
+                |counters: &Vec<_>| counters.iter().any(|(counter_id, _)| *counter_id == id);
+            if !counters.with_untracked(has_id) && !history.any_step(has_id) {
+                count.dispose();
+            }
+        }
+    });
+    let (counters, set_counters) = counters.split();
+
+    let confirmer = use_context::<Confirmer>().filter(|_| confirm_remove);
+    let i18n = use_i18n();
+    // reordering only moves entries around: the ids, and with them the rows, stay the same
+    let reorder = Reorder::new(move |(from, to)| {
+        set_counters.update(|counters| move_item(counters, from, to))
+    });
+    let len = Signal::derive(move || counters.with(Vec::len));
 
     let add_counter = move |_| {
         // create a signal for the new counter
@@ -107,10 +141,13 @@ fn DynamicList(
     };
 
     view! {
-        <div>
-            <button class="button" on:click=add_counter>
-                "Add Counter"
-            </button>
+        <div on:keydown=move |ev| history.on_keydown(ev)>
+            <div class="buttons">
+                <BButton on:click=add_counter>
+                    {t!("iteration-add")}
+                </BButton>
+                <HistoryControls history=history/>
+            </div>
             <ul>
                 // The <For/> component is central here
                 // This allows for efficient, key list rendering
@@ -127,32 +164,58 @@ fn DynamicList(
                     // `children` receives each item from your `each` iterator
                     // and returns a view
                     children=move |(id, (count, set_count))| {
+                        let index = Signal::derive(move || {
+                            counters
+                                .with(|counters| {
+                                    counters
+                                        .iter()
+                                        .position(|(counter_id, _)| *counter_id == id)
+                                        .unwrap_or_default()
+                                })
+                        });
                         view! {
-                            <li>
-                                <button
-                                    class="button"
+                            <li
+                                data-reorder-list=reorder.row_id()
+                                data-reorder-index=index
+                                style=reorder.row_style(index)
+                            >
+                                <ReorderHandle reorder=reorder index=index len=len/>
+                                <BButton
                                     on:click=move |_| { set_count.update(|n| *n += 1) }
                                 >
                                     {count}
-                                </button>
-                                <button
-                                    class="button"
+                                </BButton>
+                                <BButton
                                     on:click=move |_| {
-                                        set_counters
-                                            .update(|counters| {
-                                                counters
-                                                    .retain(|(counter_id, (signal, _))| {
-                                                        if counter_id == &id {
-                                                            signal.dispose();
-                                                        }
-                                                        counter_id != &id
-                                                    })
-                                            });
+                                        let remove = move || {
+                                            set_counters
+                                                .update(|counters| {
+                                                    counters
+                                                        // the signal is disposed once the history lets go of it
+                                                        .retain(|(counter_id, _)| counter_id != &id)
+                                                });
+                                        };
+                                        let Some(confirmer) = confirmer else {
+                                            remove();
+                                            return;
+                                        };
+                                        // the dialog answers through a future, so wait for it off the handler
+                                        let question = confirmer
+                                            .confirm(
+                                                t_string!(
+                                                    i18n, "iteration-remove-question", count = count.get_untracked()
+                                                ),
+                                            );
+                                        spawn_local(async move {
+                                            if question.await {
+                                                remove();
+                                            }
+                                        });
                                     }
                                 >
 
-                                    "Remove"
-                                </button>
+                                    {t!("iteration-remove")}
+                                </BButton>
                             </li>
                         }
                     }
@@ -188,12 +251,11 @@ pub fn DemoComplexDataIteration() -> impl IntoView {
     ]);
 
     view! {
-        <h1>Demo iterating over mote complex data</h1>
+        <h1>{t!("iteration-complex-title")}</h1>
 
         // when we click, update each row,
         // doubling its value
-        <button
-            class="button"
+        <BButton
             on:click=move |_| {
                 set_data
                     .update(|data| {
@@ -204,9 +266,9 @@ pub fn DemoComplexDataIteration() -> impl IntoView {
             }
         >
 
-            "Update Values"
-        </button>
-        <p>"This won't work because each.value is not reactive type"</p>
+            {t!("iteration-update")}
+        </BButton>
+        <p>{t!("iteration-not-reactive")}</p>
         <For
             each=data
             key=|each| each.key.clone()
@@ -219,9 +281,7 @@ pub fn DemoComplexDataIteration() -> impl IntoView {
         // <For each=data key=|state| state.key.clone() let:child>
         // <p>{child.value}</p>
         // </For>
-        <p>
-            "Use `create_momo` to create a derived computation that only triggers a reactive update when its value has changed"
-        </p>
+        <p>{t!("iteration-memo")}</p>
         <For
             each=move || data().into_iter().enumerate()
             key=|(_index, state)| state.key.clone()
@@ -234,3 +294,118 @@ pub fn DemoComplexDataIteration() -> impl IntoView {
         />
     }
 }
+
+/// The same rows as above, but each row is its own signal inside a `DataTable`.
+#[component]
+pub fn DemoDataTable() -> impl IntoView {
+    let rows = create_rw_signal(
+        (1..=200)
+            .map(|index| {
+                create_rw_signal(DatabaseEntry {
+                    key: format!("entry-{index:03}"),
+                    value: index * 37 % 101,
+                })
+            })
+            .collect::<Vec<_>>(),
+    );
+    let selected = create_rw_signal(HashSet::<String>::new());
+
+    // both live in the URL, so a filtered table can be shared as a link
+    let (search, set_search) = create_query_signal_with_options::<String>(
+        "search",
+        QueryOptions {
+            replace: true,
+            debounce_ms: 300,
+        },
+    );
+    let (min_value, set_min_value) = create_query_signal_with_options::<i32>(
+        "min",
+        QueryOptions {
+            replace: false,
+            debounce_ms: 0,
+        },
+    );
+    let filtered_rows = Signal::derive(move || {
+        let search = search().to_lowercase();
+        let min_value = min_value();
+        rows.get()
+            .into_iter()
+            .filter(|row| row.with(|entry| entry.value >= min_value && entry.key.contains(&search)))
+            .collect::<Vec<_>>()
+    });
+
+    let (even, odd) = (t!("table-even"), t!("table-odd"));
+    let columns = vec![
+        TableColumn::new(t!("table-key"), |entry: &DatabaseEntry| entry.key.clone()),
+        TableColumn::new(t!("table-value"), |entry: &DatabaseEntry| {
+            entry.value.to_string()
+        })
+        .sort_by_key(|entry| entry.value),
+        TableColumn::new(t!("table-parity"), move |entry: &DatabaseEntry| {
+            if entry.value % 2 == 0 { even } else { odd }.get()
+        }),
+    ];
+
+    let double_random_row = move |_| {
+        rows.with_untracked(|rows| {
+            let index = (js_sys::Math::random() * rows.len() as f64) as usize;
+            // only this row's cells re-render
+            rows[index].update(|entry| entry.value *= 2);
+        });
+    };
+    let double_selected = move |_| {
+        selected.with_untracked(|selected| {
+            rows.with_untracked(|rows| {
+                for row in rows {
+                    if row.with_untracked(|entry| selected.contains(&entry.key)) {
+                        row.update(|entry| entry.value *= 2);
+                    }
+                }
+            })
+        });
+    };
+
+    view! {
+        <h1>{t!("table-title")}</h1>
+        <p>{t!("table-intro")}</p>
+        <div class="buttons">
+            <BButton on:click=double_random_row>{t!("table-double-random")}</BButton>
+            <BButton
+                color=BColor::Primary
+                state=disabled_if(move || selected.with(HashSet::is_empty))
+                on:click=double_selected
+            >
+                {t!("table-double-selected")}
+            </BButton>
+        </div>
+        <div class="field is-grouped">
+            <div class="control">
+                <label class="label is-small" for="data-table-search">
+                    {t!("table-key-contains")}
+                </label>
+                <input
+                    id="data-table-search"
+                    class="input is-small"
+                    type="search"
+                    prop:value=search
+                    on:input=move |ev| set_search(event_target_value(&ev))
+                />
+            </div>
+            <div class="control">
+                <label class="label is-small" for="data-table-min">
+                    {t!("table-value-at-least")}
+                </label>
+                <input
+                    id="data-table-min"
+                    class="input is-small"
+                    type="number"
+                    prop:value=min_value
+                    on:change=move |ev| set_min_value(event_target_value(&ev).parse().unwrap_or_default())
+                />
+            </div>
+        </div>
+        <p>{t!("table-url")}</p>
+        <p>{t!("table-selected", count = selected.with(HashSet::len))}</p>
+        <DataTable rows=filtered_rows columns=columns row_key=|entry: &DatabaseEntry| entry.key.clone() selected=selected/>
+    }
+}
@@ -0,0 +1,64 @@
+use super::demo_iteration::DynamicList;
+use crate::bulma::{BBox, BButton, BColor};
+use crate::i18n::t;
+use crate::modal::Modal;
+use leptos::*;
+
+#[component]
+pub fn DemoModal() -> impl IntoView {
+    view! {
+        <div class="section">
+            <h1 class="title">{t!("modal-title")}</h1>
+            <p>{t!("modal-intro")}</p>
+            <ul>
+                <li>
+                    <Demo01/>
+                </li>
+                <li>
+                    <Demo02/>
+                </li>
+            </ul>
+        </div>
+    }
+}
+
+#[component]
+pub fn Demo01() -> impl IntoView {
+    let (open, set_open) = create_signal(false);
+
+    view! {
+        <div class="container">
+            <h2 class="subtitle">{t!("modal-plain")}</h2>
+            <BButton color=BColor::Primary on:click=move |_| set_open(true)>
+                {t!("modal-open")}
+            </BButton>
+            <Modal open on_close=move |_| set_open(false) labelled_by="demo-modal-title">
+                <div class="modal-content">
+                    <BBox>
+                        <h3 class="title" id="demo-modal-title">
+                            {t!("modal-hello")}
+                        </h3>
+                        <p>{t!("modal-try")}</p>
+                        <input class="input" type="text" placeholder=t!("modal-focus")/>
+                        <BButton on:click=move |_| set_open(false)>
+                            {t!("modal-close")}
+                        </BButton>
+                    </BBox>
+                </div>
+            </Modal>
+        </div>
+    }
+}
+
+/// Same list as in the iteration demo, but "Remove" awaits a confirm dialog first.
+#[component]
+pub fn Demo02() -> impl IntoView {
+    view! {
+        <div class="container">
+            <h2 class="subtitle">{t!("modal-confirm-remove")}</h2>
+            <BBox>
+                <DynamicList initial_length=3 confirm_remove=true/>
+            </BBox>
+        </div>
+    }
+}
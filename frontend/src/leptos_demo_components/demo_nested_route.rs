@@ -1,17 +1,25 @@
+use crate::auto_submit::AutoSubmitForm;
+use crate::bulma::{
+    disabled_if, BBox, BButton, BColor, Color, Message, Panel, PanelBlock, Tab, Tabs, TabsStyle,
+};
+use crate::i18n::{t, t_split};
+use crate::query_signal::create_query_signal;
+use crate::websocket::{create_socket, ConnectionState, ConnectionStateTag, RECONNECT_POLICY};
+use demo_protocol::{ChatEvent, ChatRequest};
 use leptos::*;
 use leptos_router::*;
 
 #[component(transparent)]
 pub fn RoutesForDemoNestedRoute() -> impl IntoView {
     view! {
-        <Route path="" view=|| view! { <p>"Select to see more"</p> }>
+        <Route path="" view=|| view! { <p>{t!("nested-select")}</p> }>
             <Route
                 path="home"
                 view=|| {
                     view! {
-                        <div class="container box">
-                            <h3>"Nested Route Home"</h3>
-                        </div>
+                        <BBox class="container">
+                            <h3>{t!("nested-home")}</h3>
+                        </BBox>
                     }
                 }
             />
@@ -19,18 +27,18 @@ pub fn RoutesForDemoNestedRoute() -> impl IntoView {
             <Route path="contacts" view=ContactList>
                 // if no id specified, fall back
                 <Route path=":id" view=ContactInfo>
-                    <Route path="" view=|| view! { <div class="tab">"(Contact Info)"</div> }/>
                     <Route
-                        path="conversations"
-                        view=|| view! { <div class="tab">"(Conversations)"</div> }
+                        path=""
+                        view=|| view! { <div class="tab">{t!("nested-contact-info-placeholder")}</div> }
                     />
+                    <Route path="conversations" view=Conversations/>
                 </Route>
                 // if no id specified, fall back
                 <Route
                     path=""
                     view=|| {
                         view! {
-                            <div class="select-user">"Select a user to view contact info."</div>
+                            <div class="select-user">{t!("nested-select-user")}</div>
                         }
                     }
                 />
@@ -46,18 +54,18 @@ pub fn RoutesForDemoNestedRoute() -> impl IntoView {
 pub fn DemoNestedRoute() -> impl IntoView {
     view! {
         <div class="section">
-            <h3 class="title">"Demo nested route"</h3>
+            <h1 class="title">{t!("nested-title")}</h1>
 
             <div class="menu">
                 <ul class="menu-list">
                     <li>
-                        <A href="home">"Demo route home"</A>
+                        <A href="home">{t!("nested-route-home")}</A>
                     </li>
                     <li>
-                        <A href="contacts">"Contacts"</A>
+                        <A href="contacts">{t!("nested-contacts")}</A>
                     </li>
                     <li>
-                        <A href="form_example">"Form Example"</A>
+                        <A href="form_example">{t!("nested-form-example")}</A>
                     </li>
                 </ul>
             </div>
@@ -79,7 +87,7 @@ pub fn FormExample() -> impl IntoView {
     let select = move || query().get("select").cloned().unwrap_or_default();
 
     view! {
-        <div class="container box">
+        <BBox class="container">
             <table class="table">
                 <tr>
                     <td>
@@ -102,98 +110,110 @@ pub fn FormExample() -> impl IntoView {
             </table>
 
             // <Form/> will navigate whenever submitted
-            <h2>"Manual Submission"</h2>
+            <h2>{t!("nested-manual")}</h2>
             <Form method="GET" action="">
                 // input names determine query string key
                 <ul>
                     <li>
-                        <input type="text" name="name" value=name/>
+                        <label>
+                            {t!("nested-name")} " " <input type="text" name="name" value=name/>
+                        </label>
                     </li>
                     <li>
-                        <input type="number" name="number" value=number/>
+                        <label>
+                            {t!("nested-number")} " " <input type="number" name="number" value=number/>
+                        </label>
                     </li>
                     <li>
-                        <select name="select">
-                            // `selected` will set which starts as selected
-                            <option selected=move || select() == "A">"A"</option>
-                            <option selected=move || select() == "B">"B"</option>
-                            <option selected=move || select() == "C">"C"</option>
-                        </select>
+                        <label>
+                            {t!("nested-option")} " "
+                            <select name="select">
+                                // `selected` will set which starts as selected
+                                <option selected=move || select() == "A">"A"</option>
+                                <option selected=move || select() == "B">"B"</option>
+                                <option selected=move || select() == "C">"C"</option>
+                            </select>
+                        </label>
                     </li>
                 </ul>
                 // submitting should cause a client-side
                 // navigation, not a full reload
-                <input type="submit"/>
+                <input type="submit" value=t!("nested-submit")/>
             </Form>
 
-            // This <Form/> uses some JavaScript to submit
-            // on every input
-            <h2>"Automatic Submission"</h2>
-            <Form method="GET" action="">
+            // This form submits itself whenever a field changes.
+            // The listeners are attached from Rust rather than inline
+            // `oninput` attributes, which the page's CSP would block
+            <h2>{t!("nested-automatic")}</h2>
+            <AutoSubmitForm action="">
                 <ul>
                     <li>
-                        <input
-                            type="text"
-                            name="name"
-                            value=name
-                            // this oninput attribute will cause the
-                            // form to submit on every input to the field
-                            oninput="this.form.requestSubmit()"
-                        />
+                        <label>
+                            {t!("nested-name")} " " <input type="text" name="name" value=name/>
+                        </label>
                     </li>
                     <li>
-                        <input
-                            type="number"
-                            name="number"
-                            value=number
-                            oninput="this.form.requestSubmit()"
-                        />
+                        <label>
+                            {t!("nested-number")} " " <input type="number" name="number" value=number/>
+                        </label>
                     </li>
                     <li>
-                        <select name="select" onchange="this.form.requestSubmit()">
-                            <option selected=move || select() == "A">"A"</option>
-                            <option selected=move || select() == "B">"B"</option>
-                            <option selected=move || select() == "C">"C"</option>
-                        </select>
+                        <label>
+                            {t!("nested-option")} " "
+                            <select name="select">
+                                <option selected=move || select() == "A">"A"</option>
+                                <option selected=move || select() == "B">"B"</option>
+                                <option selected=move || select() == "C">"C"</option>
+                            </select>
+                        </label>
                     </li>
                 </ul>
-
-                // submitting should cause a client-side
-                // navigation, not a full reload
-                <input type="submit"/>
-            </Form>
-        </div>
+            </AutoSubmitForm>
+        </BBox>
     }
 }
 
 #[component]
 pub fn ContactList() -> impl IntoView {
+    // the filter is kept in `?filter=`, so a filtered list can be bookmarked
+    let (filter, set_filter) = create_query_signal::<String>("filter");
+    let contacts = [("alice", "Alice"), ("bob", "Bob"), ("steve", "Steve")];
+    let shown = move |name: &str| name.to_lowercase().contains(&filter().to_lowercase());
+
     view! {
-        <div class="container box">
+        <BBox class="container">
             <div class="contact-list">
                 // here's our contact list component itself
-                <h3>"Contacts"</h3>
-                <div class="contact-list-contacts">
-                    <ul>
-                        <li>
-                            <A href="alice">"Alice"</A>
-                        </li>
-                        <li>
-
-                            <A href="bob">"Bob"</A>
-                        </li>
-                        <li>
-                            <A href="steve">"Steve"</A>
-                        </li>
-                    </ul>
-
-                </div>
+                <Panel heading=t!("nested-contacts")>
+                    <PanelBlock>
+                        <input
+                            class="input is-small"
+                            type="search"
+                            placeholder=t!("nested-filter")
+                            aria-label=t!("nested-filter-contacts")
+                            prop:value=filter
+                            on:input=move |ev| set_filter(event_target_value(&ev))
+                        />
+                    </PanelBlock>
+                    {contacts
+                        .into_iter()
+                        .map(|(id, name)| {
+                            view! {
+                                <Show when=move || shown(name)>
+                                    <PanelBlock>
+                                        <A href=id>{name}</A>
+                                    </PanelBlock>
+                                </Show>
+                            }
+                        })
+                        .collect_view()}
+                </Panel>
 
                 // <Outlet/> will show the nested child route
                 // we can position this outlet wherever we want within the layout
                 <Outlet/>
             </div>
-        </div>
+        </BBox>
     }
 }
 
@@ -203,23 +223,36 @@ pub fn ContactInfo() -> impl IntoView {
     let params = use_params_map();
     let id = move || params.with(|params| params.get("id").cloned().unwrap_or_default());
 
+    // the tab for the nested route we are on gets highlighted
+    let location = use_location();
+    let on_conversations = move || {
+        location
+            .pathname
+            .with(|path| path.ends_with("/conversations"))
+    };
+
     // imagine we're loading data from an API here
+    let not_found = t!("nested-not-found");
     let name = move || match id().as_str() {
-        "alice" => "Alice",
-        "bob" => "Bob",
-        "steve" => "Steve",
-        _ => "User not found.",
+        "alice" => "Alice".to_string(),
+        "bob" => "Bob".to_string(),
+        "steve" => "Steve".to_string(),
+        _ => not_found.get(),
     };
 
     view! {
         <h4>{name}</h4>
         <div class="contact-info">
-            <div class="tabs">
-                <A href="" exact=true>
-                    "Contact Info"
-                </A>
-                <A href="conversations">"Conversations"</A>
-            </div>
+            <Tabs style=TabsStyle::Boxed>
+                <Tab active=Signal::derive(move || !on_conversations())>
+                    <A href="" exact=true>
+                        {t!("nested-contact-info")}
+                    </A>
+                </Tab>
+                <Tab active=Signal::derive(on_conversations)>
+                    <A href="conversations">{t!("nested-conversations")}</A>
+                </Tab>
+            </Tabs>
 
             // <Outlet/> here is the tabs that are nested
             // underneath the /contacts/:id route
@@ -227,3 +260,115 @@ pub fn ContactInfo() -> impl IntoView {
         </div>
     }
 }
+
+/// The name the demo server knows us by.
+const CHAT_NAME: &str = "you";
+
+/// The chat of the demo server, on the same host: Trunk proxies `/chat` to it.
+fn chat_url(contact: &str) -> String {
+    let location = window().location();
+    let scheme = match location.protocol().as_deref() {
+        Ok("https:") => "wss",
+        _ => "ws",
+    };
+    let host = location.host().unwrap_or_default();
+    // contacts come from the URL, so they may hold a `/`, `?` or `#` of their own
+    let contact = js_sys::encode_uri_component(contact);
+    format!("{scheme}://{host}/chat/{contact}?name={CHAT_NAME}")
+}
+
+#[component]
+pub fn Conversations() -> impl IntoView {
+    let params = use_params_map();
+    let id = move || params.with(|params| params.get("id").cloned().unwrap_or_default());
+
+    // the route stays mounted when only `:id` changes, so connect anew per contact here
+    move || {
+        let contact = id();
+        view! { <Chat contact/> }
+    }
+}
+
+#[component]
+fn Chat(contact: String) -> impl IntoView {
+    let messages = create_rw_signal(Vec::<(u64, String, String)>::new());
+    let socket = create_socket::<ChatEvent, ChatRequest>(
+        chat_url(&contact),
+        RECONNECT_POLICY,
+        move |event| match event {
+            ChatEvent::Said { id, from, text, .. } => messages.update(|messages| {
+                // the server replays the conversation on every reconnect
+                if !messages.iter().any(|(seen, _, _)| *seen == id) {
+                    messages.push((id, from, text));
+                }
+            }),
+        },
+    );
+    let state = socket.state();
+    let (draft, set_draft) = create_signal(String::new());
+    let unreachable = t_split!("chat-unreachable", command);
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let text = draft.get_untracked();
+        if !text.trim().is_empty() && socket.send(&ChatRequest::Say { text }).is_ok() {
+            set_draft(String::new());
+        }
+    };
+
+    view! {
+        <div class="tab">
+            <p class="mb-2">
+                <ConnectionStateTag state/>
+            </p>
+            <Show when=move || state() == ConnectionState::Closed>
+                <Message color=Color::Warning>
+                    <p>
+                        {move || unreachable.with(|(before, _)| before.clone())}
+                        <code>"cargo run -p demo-server"</code>
+                        {move || unreachable.with(|(_, after)| after.clone())}
+                    </p>
+                    <BButton on:click=move |_| socket.reconnect()>{t!("reconnect")}</BButton>
+                </Message>
+            </Show>
+            <BBox>
+                <div style="height: 16rem; overflow-y: auto;">
+                    <For
+                        each=messages
+                        key=|(id, _, _)| *id
+                        children=|(_, from, text)| {
+                            let mine = from == CHAT_NAME;
+                            view! {
+                                <p class:has-text-right=mine>
+                                    <strong>{from}</strong>
+                                    ": "
+                                    {text}
+                                </p>
+                            }
+                        }
+                    />
+                </div>
+            </BBox>
+            <form class="field has-addons" on:submit=on_submit>
+                <div class="control is-expanded">
+                    <input
+                        class="input"
+                        type="text"
+                        placeholder=t!("chat-placeholder")
+                        aria-label=t!("chat-message")
+                        prop:value=draft
+                        on:input=move |ev| set_draft(event_target_value(&ev))
+                    />
+                </div>
+                <div class="control">
+                    <BButton
+                        color=BColor::Primary
+                        state=disabled_if(move || state() != ConnectionState::Open)
+                    >
+                        {t!("chat-send")}
+                    </BButton>
+                </div>
+            </form>
+        </div>
+    }
+}
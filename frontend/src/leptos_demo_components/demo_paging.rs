@@ -0,0 +1,227 @@
+use crate::bulma::{BButton, BSize, Pagination};
+use crate::fallible::{ApiError, FailureMode, LoadError};
+use crate::i18n::t;
+use crate::network::{use_network, Network, NetworkPanel};
+use crate::paging::{create_infinite_list, InfiniteScroll, Page, PageNumber};
+use crate::query_signal::{create_query_signal, create_query_signal_with_options, QueryOptions};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+/// Items the fake server has. Far too many to send at once.
+const ITEM_COUNT: u32 = 10_000;
+const PER_PAGE: usize = 20;
+
+const ADJECTIVES: [&str; 8] = [
+    "Red", "Quiet", "Swift", "Golden", "Tiny", "Brave", "Silver", "Lazy",
+];
+const NOUNS: [&str; 8] = [
+    "Fox", "River", "Lamp", "Garden", "Comet", "Teapot", "Falcon", "Pebble",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Item {
+    id: u32,
+    name: String,
+    price_cents: u32,
+}
+
+/// The fake server's items are made up from their id, so there is nothing to store.
+fn item(id: u32) -> Item {
+    let index = id as usize;
+    Item {
+        id,
+        name: format!(
+            "{} {} #{id}",
+            ADJECTIVES[index % ADJECTIVES.len()],
+            NOUNS[index / ADJECTIVES.len() % NOUNS.len()]
+        ),
+        price_cents: id * 7_919 % 10_000,
+    }
+}
+
+/// Page-based: page `index` counting from 0. Past the end, the page is empty, but still tells
+/// the total.
+async fn fetch_page(index: usize, network: Network) -> Result<Page<Item, PageNumber>, ApiError> {
+    network.request(1, FailureMode::Never).await?;
+    let first = (index * PER_PAGE).min(ITEM_COUNT as usize);
+    let last = (first + PER_PAGE).min(ITEM_COUNT as usize);
+    Ok(Page {
+        items: (first as u32..last as u32).map(item).collect(),
+        next: (last < ITEM_COUNT as usize).then_some(PageNumber::new(index + 2)),
+        total: ITEM_COUNT as usize,
+    })
+}
+
+/// Cursor-based: the items after the one with id `after`, from the start without one.
+async fn fetch_after(after: Option<u32>, network: Network) -> Result<Page<Item, u32>, ApiError> {
+    network.request(1, FailureMode::Never).await?;
+    let first = after.map_or(0, |id| id + 1);
+    let items = (first..ITEM_COUNT)
+        .take(PER_PAGE)
+        .map(item)
+        .collect::<Vec<_>>();
+    let next = items
+        .last()
+        .map(|item| item.id)
+        .filter(|id| id + 1 < ITEM_COUNT);
+    Ok(Page {
+        items,
+        next,
+        total: ITEM_COUNT as usize,
+    })
+}
+
+fn price(item: &Item) -> Signal<String> {
+    let price = f64::from(item.price_cents) / 100.0;
+    t!("paging-price-value", price = price)
+}
+
+#[component]
+pub fn DemoPaging() -> impl IntoView {
+    view! {
+        <div class="section">
+            <h1 class="title">{t!("paging-title")}</h1>
+            <p>{t!("paging-intro", total = ITEM_COUNT, per_page = PER_PAGE)}</p>
+            <NetworkPanel/>
+            <ul>
+                <li>
+                    <Demo01/>
+                </li>
+                <li>
+                    <Demo02/>
+                </li>
+            </ul>
+        </div>
+    }
+}
+
+/// A page at a time, with the page in the URL: reload, share a link, or go Back a page.
+#[component]
+fn Demo01() -> impl IntoView {
+    let network = use_network();
+    let (page, set_page) = create_query_signal_with_options::<PageNumber>(
+        "page",
+        QueryOptions {
+            // every page gets a history entry, so Back goes to the previous page
+            replace: false,
+            ..Default::default()
+        },
+    );
+    let items = create_resource(page, move |page| fetch_page(page.index(), network));
+    // a page past the end, e.g. from an old link, turns into the last page
+    let (_, replace_page) = create_query_signal::<PageNumber>("page");
+    create_effect(move |_| {
+        let Some(Ok(loaded)) = items.get() else {
+            return;
+        };
+        let last = loaded.page_count(PER_PAGE);
+        if page.get_untracked().get() > last {
+            replace_page(PageNumber::new(last));
+        }
+    });
+    // keep the page count of the last page seen, so the pagination stays while the next one loads
+    let page_count = create_memo(move |previous: Option<&usize>| {
+        items
+            .get()
+            .and_then(Result::ok)
+            .map(|items| items.page_count(PER_PAGE))
+            .or(previous.copied())
+            .unwrap_or(1)
+    });
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("paging-pagination")}</h3>
+            <Transition fallback=move || view! { <p>{t!("list-loading")}</p> }>
+                {move || {
+                    items
+                        .get()
+                        .map(|page| match page {
+                            Ok(page) => view! { <ItemTable items=page.items/> }.into_view(),
+                            Err(error) => {
+                                view! { <LoadError error on_retry=move |_| items.refetch()/> }
+                                    .into_view()
+                            }
+                        })
+                }}
+            </Transition>
+            <Pagination
+                current=Signal::derive(move || page().get())
+                total=page_count
+                on_change=Callback::new(move |page| set_page(PageNumber::new(page)))
+                size=BSize::Small
+                centered=true
+            />
+        </div>
+    }
+}
+
+#[component]
+fn ItemTable(items: Vec<Item>) -> impl IntoView {
+    view! {
+        <table class="table is-fullwidth is-narrow">
+            <thead>
+                <tr>
+                    <th>"#"</th>
+                    <th>{t!("paging-name")}</th>
+                    <th class="has-text-right">{t!("paging-price")}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {items
+                    .into_iter()
+                    .map(|item| {
+                        view! {
+                            <tr>
+                                <td>{item.id}</td>
+                                <td>{item.name.clone()}</td>
+                                <td class="has-text-right">{price(&item)}</td>
+                            </tr>
+                        }
+                    })
+                    .collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+/// The next page loads as the end of the list scrolls into view.
+#[component]
+fn Demo02() -> impl IntoView {
+    let network = use_network();
+    let list = create_infinite_list(move |after| fetch_after(after, network));
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("paging-infinite-scroll")}</h3>
+            <div class="level">
+                <p class="level-left">
+                    {move || match list.total().get() {
+                        Some(total) => {
+                            t!("paging-loaded", loaded = list.items().with(Vec::len), total = total)
+                        }
+                        None => t!("paging-nothing-loaded"),
+                    }}
+                </p>
+                <BButton size=BSize::Small class="level-right" on:click=move |_| list.reset()>
+                    {t!("paging-start-over")}
+                </BButton>
+            </div>
+            <InfiniteScroll
+                list
+                key=|item: &Item| item.id
+                children=|item: Item| {
+                    view! {
+                        <div
+                            class="is-flex is-justify-content-space-between px-3 py-2"
+                            style="border-bottom: 1px solid hsl(0, 0%, 93%);"
+                        >
+                            <span>{item.name.clone()}</span>
+                            <span class="has-text-grey">{price(&item)}</span>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
@@ -1,3 +1,5 @@
+use crate::bulma::{BBox, BButton};
+use crate::i18n::t;
 use leptos::ev::MouseEvent;
 use leptos::*;
 
@@ -5,13 +7,9 @@ use leptos::*;
 pub fn DemoParentChildrenCommunication() -> impl IntoView {
     view! {
         <div class="section">
-            <h1 class="title">Demo parent children communication</h1>
-            <p>
-                It is easy for the parent to communicate to the child: pass ReadSignal, or a Signal, or even a MaybeSignal as a prob
-            </p>
-            <p>
-                How can a child send notifications about events or state changes back up to the parent?
-            </p>
+            <h1 class="title">{t!("parent-title")}</h1>
+            <p>{t!("parent-down")}</p>
+            <p>{t!("parent-up")}</p>
 
             <ul>
                 <li>
@@ -38,12 +36,12 @@ pub fn Approch01() -> impl IntoView {
     let (toggled, set_toggled) = create_signal(false);
     view! {
         <div class="container">
-            <p class="subtitle">approch01</p>
-            <p>Pass WriteSignal from parent down to the child</p>
-            <div class="box">
-                <p>"Toggled? " {toggled}</p>
+            <p class="subtitle">{t!("parent-approach", number = 1)}</p>
+            <p>{t!("parent-write-signal")}</p>
+            <BBox>
+                <p>{t!("parent-toggled", toggled = toggled().to_string())}</p>
                 <Approch01Child setter=set_toggled/>
-            </div>
+            </BBox>
         </div>
     }
 }
@@ -51,9 +49,9 @@ pub fn Approch01() -> impl IntoView {
 #[component]
 pub fn Approch01Child(setter: WriteSignal<bool>) -> impl IntoView {
     view! {
-        <button class="button" on:click=move |_| setter.update(|value| *value = !*value)>
-            "Toggle"
-        </button>
+        <BButton on:click=move |_| setter.update(|value| *value = !*value)>
+            {t!("parent-toggle")}
+        </BButton>
     }
 }
 
@@ -62,23 +60,23 @@ pub fn Approch02() -> impl IntoView {
     let (toggled, set_toggled) = create_signal(false);
     view! {
         <div class="container ">
-            <p class="subtitle">approch02</p>
-            <p>Use callbback or closure</p>
-            <div class="box">
-                <p>"Toggled? " {toggled}</p>
-                <div class="box">
-                    <p>Approch02v1</p>
+            <p class="subtitle">{t!("parent-approach", number = 2)}</p>
+            <p>{t!("parent-callback")}</p>
+            <BBox>
+                <p>{t!("parent-toggled", toggled = toggled().to_string())}</p>
+                <BBox>
+                    <p>{t!("parent-approach-version", number = 2, version = 1)}</p>
                     <Approch02Child on_click=move |_| set_toggled.update(|value| *value = !*value)/>
-                </div>
+                </BBox>
 
-                <div class="box">
-                    <p>Approch02v2</p>
+                <BBox>
+                    <p>{t!("parent-approach-version", number = 2, version = 2)}</p>
                     <Approch02ChildV2 on_click=move |_| {
                         set_toggled.update(|value| *value = !*value)
                     }/>
-                </div>
+                </BBox>
 
-            </div>
+            </BBox>
         </div>
     }
 }
@@ -86,9 +84,9 @@ pub fn Approch02() -> impl IntoView {
 #[component]
 pub fn Approch02Child(#[prop(into)] on_click: Callback<MouseEvent>) -> impl IntoView {
     view! {
-        <button class="button" on:click=on_click>
-            "Toggle"
-        </button>
+        <BButton on:click=on_click>
+            {t!("parent-toggle")}
+        </BButton>
     }
 }
 
@@ -98,9 +96,9 @@ where
     F: Fn(MouseEvent) + 'static,
 {
     view! {
-        <button class="button" on:click=on_click>
-            Toggle
-        </button>
+        <BButton on:click=on_click>
+            {t!("parent-toggle")}
+        </BButton>
     }
 }
 
@@ -109,19 +107,19 @@ pub fn Approch03() -> impl IntoView {
     let (toggled, set_toggled) = create_signal(false);
     view! {
         <div class="container">
-            <p class="subtitle">aproch03</p>
-            <p>Use event listener</p>
-            <div class="box">
-                <p>"Toggled? " {toggled}</p>
+            <p class="subtitle">{t!("parent-approach", number = 3)}</p>
+            <p>{t!("parent-event-listener")}</p>
+            <BBox>
+                <p>{t!("parent-toggled", toggled = toggled().to_string())}</p>
                 <Approch03Child on:click=move |_| { set_toggled.update(|value| *value = !*value) }/>
-            </div>
+            </BBox>
         </div>
     }
 }
 
 #[component]
 pub fn Approch03Child() -> impl IntoView {
-    view! { <button class="button">"Toggle"</button> }
+    view! { <BButton>{t!("parent-toggle")}</BButton> }
 }
 
 #[component]
@@ -133,16 +131,14 @@ pub fn Approch04() -> impl IntoView {
 
     view! {
         <div class="container">
-            <p class="subtitle">approch04</p>
-            <p>Providing a context</p>
-            <p>
-                Contexts are identified by the type of the data you provide and they exist in a top-down tree that follows the contours of your UI tree.
-            </p>
-            <div class="box">
-                <p>"Toggled? " {toggled}</p>
+            <p class="subtitle">{t!("parent-approach", number = 4)}</p>
+            <p>{t!("parent-context")}</p>
+            <p>{t!("parent-context-intro")}</p>
+            <BBox>
+                <p>{t!("parent-toggled", toggled = toggled().to_string())}</p>
 
                 <Approch04Layout/>
-            </div>
+            </BBox>
         </div>
     }
 }
@@ -151,7 +147,7 @@ pub fn Approch04() -> impl IntoView {
 pub fn Approch04Layout() -> impl IntoView {
     view! {
         <div>
-            <p>My layout</p>
+            <p>{t!("parent-layout")}</p>
         </div>
         <Approch04Content/>
     }
@@ -173,8 +169,8 @@ pub fn Approch04Button() -> impl IntoView {
     // in this case, I .expect() because I know I provided it
     let setter = use_context::<WriteSignal<bool>>().expect("WriteSignal<bool> provided");
     view! {
-        <button class="button" on:click=move |_| setter.update(|value| *value = !*value)>
-            "Toggle"
-        </button>
+        <BButton on:click=move |_| setter.update(|value| *value = !*value)>
+            {t!("parent-toggle")}
+        </BButton>
     }
 }
@@ -1,10 +1,11 @@
+use crate::i18n::{t, t_split};
 use leptos::html::Input;
 use leptos::*;
 
 #[component]
 pub fn DemoReactivity() -> impl IntoView {
     view! {
-        <h1>Demo Reactivity</h1>
+        <h1>{t!("reactivity-title")}</h1>
         <ul>
             <li>
                 <Demo01/>
@@ -36,7 +37,7 @@ pub fn Demo01() -> impl IntoView {
         set_names.update(|names| names.push("Alice".to_string()));
     }
 
-    view! { <p>Demo01: usage of with and update</p> }
+    view! { <p>{t!("reactivity-with-update")}</p> }
 }
 
 #[component]
@@ -55,8 +56,8 @@ pub fn Demo02() -> impl IntoView {
     let name = move || with!(|first, middle, last| format!("{first} {middle} {last}"));
 
     view! {
-        <p>Demo02: with! macro</p>
-        <p>name is: {name}</p>
+        <p>{t!("reactivity-with-macro")}</p>
+        <p>{t!("reactivity-name", name = name())}</p>
     }
 }
 
@@ -81,7 +82,7 @@ pub fn Demo03() -> impl IntoView {
         set_favorite_number(0);
     };
 
-    view! { <h3>Making singals depends on each other</h3> }
+    view! { <h3>{t!("reactivity-depend")}</h3> }
 }
 
 #[derive(Copy, Clone)]
@@ -122,13 +123,10 @@ fn CreateAnEffect() -> impl IntoView {
     });
 
     view! {
-        <h1>
-            <code>"create_effect"</code>
-            " Version"
-        </h1>
+        <h2>{t!("reactivity-version", code = "create_effect")}</h2>
         <form>
             <label>
-                "First Name"
+                {t!("reactivity-first-name")}
                 <input
                     type="text"
                     name="first"
@@ -137,7 +135,7 @@ fn CreateAnEffect() -> impl IntoView {
                 />
             </label>
             <label>
-                "Last Name"
+                {t!("reactivity-last-name")}
                 <input
                     type="text"
                     name="last"
@@ -146,7 +144,7 @@ fn CreateAnEffect() -> impl IntoView {
                 />
             </label>
             <label>
-                "Show Last Name"
+                {t!("reactivity-show-last-name")}
                 <input
                     type="checkbox"
                     name="use_last"
@@ -183,12 +181,13 @@ fn ManualVersion() -> impl IntoView {
     };
 
     view! {
-        <h1>"Manual Version"</h1>
+        <h2>{t!("reactivity-manual")}</h2>
         <form on:change=on_change>
-            <label>"First Name" <input type="text" name="first" node_ref=first/></label>
-            <label>"Last Name" <input type="text" name="last" node_ref=last/></label>
+            <label>{t!("reactivity-first-name")} <input type="text" name="first" node_ref=first/></label>
+            <label>{t!("reactivity-last-name")} <input type="text" name="last" node_ref=last/></label>
             <label>
-                "Show Last Name" <input type="checkbox" name="use_last" checked node_ref=use_last/>
+                {t!("reactivity-show-last-name")}
+                <input type="checkbox" name="use_last" checked node_ref=use_last/>
             </label>
         </form>
     }
@@ -211,13 +210,16 @@ fn EffectVsDerivedSignal() -> impl IntoView {
     // Do this
     let my_optional_value =
         move || (!my_value.with(String::is_empty)).then(|| Some(my_value.get()));
+    let is = t_split!("reactivity-is", code);
 
     view! {
-        <input prop:value=my_value on:input=move |ev| set_my_value(event_target_value(&ev))/>
+        <input aria-label=t!("reactivity-value") prop:value=my_value on:input=move |ev| set_my_value(event_target_value(&ev))/>
 
         <p>
+            {move || is.with(|(before, _)| before.clone())}
             <code>"my_optional_value"</code>
-            " is "
+            {move || is.with(|(_, after)| after.clone())}
+            " "
             <code>
                 <Show when=move || my_optional_value().is_some() fallback=|| view! { "None" }>
                     "Some(\""
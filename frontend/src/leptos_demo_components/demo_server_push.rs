@@ -0,0 +1,145 @@
+use crate::bulma::{BBox, BButton, Color, Column, Columns, Message};
+use crate::event_source::create_event_stream;
+use crate::i18n::{t, t_split};
+use crate::websocket::{ConnectionState, ConnectionStateTag, RECONNECT_POLICY};
+use demo_protocol::Metrics;
+use leptos::*;
+
+/// The metrics feed of the demo server, on the same host: Trunk proxies `/events` to it.
+const METRICS_URL: &str = "/events/metrics";
+/// Seconds of metrics the charts show.
+const HISTORY_LEN: usize = 60;
+
+#[component]
+pub fn DemoServerPush() -> impl IntoView {
+    let metrics = create_event_stream::<Metrics>(METRICS_URL, "metrics", RECONNECT_POLICY);
+    let state = metrics.state();
+    let latest = metrics.latest();
+
+    // every event, including the ones replayed after a reconnect, adds a second to the charts
+    let history = create_rw_signal(Vec::<Metrics>::new());
+    create_effect(move |_| {
+        if let Some(latest) = latest.get() {
+            history.update(|history| {
+                if history.len() == HISTORY_LEN {
+                    history.remove(0);
+                }
+                history.push(latest);
+            });
+        }
+    });
+    let series = move |value: fn(&Metrics) -> f64| {
+        Signal::derive(move || history.with(|history| history.iter().map(value).collect()))
+    };
+    let no_event = t!("push-no-event");
+    let unreachable = t_split!("push-unreachable", command);
+
+    view! {
+        <div class="section">
+            <h1 class="title">{t!("push-title")}</h1>
+            <p>{t!("push-intro")}</p>
+            <div class="level mt-4">
+                <div class="level-left">
+                    <ConnectionStateTag state/>
+                </div>
+                <p class="level-right has-text-grey">
+                    {t!(
+                        "push-last-event",
+                        id = metrics.last_event_id().get().unwrap_or_else(|| no_event.get()),
+                    )}
+                </p>
+            </div>
+            <Show when=move || state() == ConnectionState::Closed>
+                <Message color=Color::Warning>
+                    <p>
+                        {move || unreachable.with(|(before, _)| before.clone())}
+                        <code>"cargo run -p demo-server"</code>
+                        {move || unreachable.with(|(_, after)| after.clone())}
+                    </p>
+                    <BButton on:click=move |_| metrics.reconnect()>{t!("reconnect")}</BButton>
+                </Message>
+            </Show>
+            <Columns>
+                <Column>
+                    <MetricCard
+                        heading=t!("push-cpu")
+                        value=Signal::derive(move || {
+                            latest.get().map(|latest| format!("{:.0} %", latest.cpu_percent))
+                        })
+                        series=series(|metrics| metrics.cpu_percent)
+                        max=100.0
+                    />
+                </Column>
+                <Column>
+                    <MetricCard
+                        heading=t!("push-memory")
+                        value=Signal::derive(move || {
+                            latest.get().map(|latest| format!("{:.0} MB", latest.memory_mb))
+                        })
+                        series=series(|metrics| metrics.memory_mb)
+                        max=1024.0
+                    />
+                </Column>
+                <Column>
+                    <MetricCard
+                        heading=t!("push-requests")
+                        value=Signal::derive(move || {
+                            latest.get().map(|latest| format!("{} /s", latest.requests_per_second))
+                        })
+                        series=series(|metrics| metrics.requests_per_second as f64)
+                        max=500.0
+                    />
+                </Column>
+            </Columns>
+        </div>
+    }
+}
+
+/// The current value of a metric, over a sparkline of its last `HISTORY_LEN` seconds.
+#[component]
+fn MetricCard(
+    heading: Signal<String>,
+    value: Signal<Option<String>>,
+    series: Signal<Vec<f64>>,
+    /// The value at the top of the sparkline.
+    max: f64,
+) -> impl IntoView {
+    // the newest value is on the right, and older ones scroll off to the left
+    let points = move || {
+        series.with(|series| {
+            let offset = HISTORY_LEN - series.len();
+            series
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let y = 100.0 - (value / max).clamp(0.0, 1.0) * 100.0;
+                    format!("{},{y:.1}", offset + i)
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    };
+
+    view! {
+        <BBox>
+            <p class="heading">{heading}</p>
+            <p class="title">{move || value.get().unwrap_or_else(|| "-".to_string())}</p>
+            // the value is right above, this only shows how it went
+            <svg
+                aria-hidden="true"
+                viewBox=format!("0 0 {} 100", HISTORY_LEN - 1)
+                preserveAspectRatio="none"
+                style="width: 100%; height: 4rem;"
+                class="has-text-primary"
+            >
+                <polyline
+                    points=points
+                    fill="none"
+                    stroke="currentColor"
+                    stroke-width="2"
+                    vector-effect="non-scaling-stroke"
+                ></polyline>
+            </svg>
+        </BBox>
+    }
+}
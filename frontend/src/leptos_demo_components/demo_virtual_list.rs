@@ -0,0 +1,105 @@
+use crate::bulma::{BBox, BButton, BSize, BTag};
+use crate::i18n::t;
+use crate::virtual_list::VirtualList;
+use leptos::*;
+
+const ROW_COUNT: usize = 100_000;
+
+#[derive(Debug, Clone)]
+struct Row {
+    id: usize,
+    label: String,
+}
+
+fn generate_rows() -> Vec<Row> {
+    (0..ROW_COUNT)
+        .map(|id| Row {
+            id,
+            // vary the text length so the measured list gets rows of different heights
+            label: "lorem ipsum ".repeat(id % 7 + 1),
+        })
+        .collect()
+}
+
+#[component]
+pub fn DemoVirtualList() -> impl IntoView {
+    view! {
+        <div class="section">
+            <h1 class="title">{t!("virtual-title")}</h1>
+            <p>{t!("virtual-intro")}</p>
+            <ul>
+                <li>
+                    <Demo01/>
+                </li>
+                <li>
+                    <Demo02/>
+                </li>
+            </ul>
+        </div>
+    }
+}
+
+/// Every row has the same height, so positions are simply `index * row_height`.
+#[component]
+pub fn Demo01() -> impl IntoView {
+    let (rows, set_rows) = create_signal(generate_rows());
+
+    view! {
+        <div class="container">
+            <h2 class="subtitle">{t!("virtual-fixed")}</h2>
+            <BButton on:click=move |_| set_rows.update(|rows| rows.swap(0, 1))>{t!("virtual-swap")}</BButton>
+            <BBox>
+                <VirtualList
+                    items=rows
+                    key=|row: &Row| row.id
+                    row_height=40.0
+                    children=move |row: Row| view! { <CounterRow id=row.id/> }
+                />
+            </BBox>
+        </div>
+    }
+}
+
+/// Rows are measured once they are mounted; rows never seen use the estimate.
+#[component]
+pub fn Demo02() -> impl IntoView {
+    let (rows, _set_rows) = create_signal(generate_rows());
+
+    view! {
+        <div class="container">
+            <h2 class="subtitle">{t!("virtual-measured")}</h2>
+            <BBox>
+                <VirtualList
+                    items=rows
+                    key=|row: &Row| row.id
+                    row_height=30.0
+                    measure=true
+                    children=move |row: Row| {
+                        view! {
+                            <p class="py-1" style="width: 12rem;">
+                                <BTag>{row.id}</BTag>
+                                " "
+                                {row.label}
+                            </p>
+                        }
+                    }
+                />
+            </BBox>
+        </div>
+    }
+}
+
+/// A row with its own local state, to show that keyed rows are moved rather than re-rendered.
+#[component]
+fn CounterRow(id: usize) -> impl IntoView {
+    let (count, set_count) = create_signal(0);
+
+    view! {
+        <div class="is-flex is-align-items-center" style="gap: 1rem;">
+            <BTag>{id}</BTag>
+            <BButton size=BSize::Small on:click=move |_| set_count.update(|n| *n += 1)>
+                {t!("virtual-clicked", count = count())}
+            </BButton>
+        </div>
+    }
+}
@@ -0,0 +1,298 @@
+use crate::bulma::{disabled_if, BButton, BColor, BSize, Color, Message, Progress};
+use crate::i18n::t;
+use crate::worker::tasks::{CountPrimes, MandelbrotTile, PrimeCount, Task};
+use crate::worker::{create_task_worker, create_worker_resource, TaskWorker, WorkerError};
+use gloo_timers::callback::Interval;
+use leptos::html::Canvas;
+use leptos::*;
+use wasm_bindgen::{Clamped, JsCast};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData};
+
+const PRIME_LIMITS: [u32; 4] = [1_000_000, 5_000_000, 10_000_000, 20_000_000];
+const TILE_WIDTH: u32 = 480;
+const TILE_HEIGHT: u32 = 320;
+const ITERATIONS: [u32; 4] = [100, 500, 2_000, 10_000];
+
+fn whole_set() -> MandelbrotTile {
+    MandelbrotTile {
+        center: (-0.6, 0.0),
+        scale: 3.2 / f64::from(TILE_WIDTH),
+        width: TILE_WIDTH,
+        height: TILE_HEIGHT,
+        max_iterations: 500,
+    }
+}
+
+#[component]
+pub fn DemoWorker() -> impl IntoView {
+    // one worker for both demos: their tasks take turns
+    let worker = create_task_worker();
+
+    view! {
+        <div class="section">
+            <h1 class="title">{t!("worker-title")}</h1>
+            <p>{t!("worker-intro")}</p>
+            <UiClock/>
+            <div class="level">
+                <p class="level-left">{t!("worker-order")}</p>
+                <BButton size=BSize::Small class="level-right" on:click=move |_| worker.cancel_all()>
+                    {t!("worker-cancel-all")}
+                </BButton>
+            </div>
+            <ul>
+                <li>
+                    <Demo01 worker/>
+                </li>
+                <li>
+                    <Demo02 worker/>
+                </li>
+            </ul>
+        </div>
+    }
+}
+
+/// Ticks as long as the main thread is free to run it.
+#[component]
+fn UiClock() -> impl IntoView {
+    let started = js_sys::Date::now();
+    let (now, set_now) = create_signal(started);
+    let ticker = store_value(Some(Interval::new(100, move || {
+        set_now(js_sys::Date::now());
+    })));
+    on_cleanup(move || ticker.set_value(None));
+
+    view! {
+        <p class="is-family-monospace mb-4">
+            {t!("worker-clock", seconds = (now() - started) / 1_000.0)}
+        </p>
+    }
+}
+
+fn worker_error(error: WorkerError) -> View {
+    let color = match error {
+        WorkerError::Cancelled => Color::Warning,
+        _ => Color::Danger,
+    };
+    view! {
+        <Message color>
+            <p>{error.message()}</p>
+        </Message>
+    }
+    .into_view()
+}
+
+fn describe(count: PrimeCount) -> Signal<String> {
+    match count.largest {
+        Some(largest) => t!(
+            "worker-primes",
+            count = count.count,
+            below = count.below,
+            largest = largest,
+        ),
+        None => t!("worker-no-primes", below = count.below),
+    }
+}
+
+/// A resource the worker computes, with a progress bar meanwhile.
+#[component]
+fn Demo01(worker: TaskWorker) -> impl IntoView {
+    let (below, set_below) = create_signal(PRIME_LIMITS[1]);
+    let (primes, progress) = create_worker_resource(worker, below, |below| CountPrimes { below });
+    // the same work on the main thread, to compare
+    let (on_main_thread, set_on_main_thread) = create_signal(None::<(PrimeCount, f64)>);
+    let count_on_main_thread = move |_| {
+        let started = js_sys::Date::now();
+        let count = CountPrimes {
+            below: below.get_untracked(),
+        }
+        .run(&mut |_| {});
+        set_on_main_thread(Some((count, js_sys::Date::now() - started)));
+    };
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("worker-primes-title")}</h3>
+            <div class="field is-grouped">
+                <div class="control">
+                    <div class="select is-small">
+                        <select
+                            aria-label=t!("worker-count-below")
+                            on:change=move |ev| {
+                                if let Ok(below) = event_target_value(&ev).parse() {
+                                    set_below(below);
+                                }
+                            }
+                        >
+                            {PRIME_LIMITS
+                                .into_iter()
+                                .map(|limit| {
+                                    view! {
+                                        <option value=limit selected=move || below() == limit>
+                                            {t!("worker-below", limit = limit)}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </div>
+                </div>
+                <div class="control">
+                    <BButton size=BSize::Small on:click=move |_| primes.refetch()>
+                        {t!("worker-count-in-worker")}
+                    </BButton>
+                </div>
+                <div class="control">
+                    <BButton size=BSize::Small color=BColor::Warning on:click=count_on_main_thread>
+                        {t!("worker-count-on-main")}
+                    </BButton>
+                </div>
+            </div>
+            <Show when=move || primes.loading().get()>
+                <Progress value=progress color=Color::Primary size=BSize::Small/>
+            </Show>
+            <Transition fallback=|| ()>
+                {move || {
+                    primes
+                        .get()
+                        .map(|count| match count {
+                            Ok(count) => view! { <p>{describe(count)}</p> }.into_view(),
+                            Err(error) => worker_error(error),
+                        })
+                }}
+            </Transition>
+            {move || {
+                on_main_thread()
+                    .map(|(count, ms)| {
+                        let result = describe(count);
+                        view! {
+                            <p class="has-text-grey">
+                                {t!("worker-on-main", result = result.get(), ms = ms)}
+                            </p>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+fn draw(canvas: &HtmlCanvasElement, pixels: &[u8]) {
+    let Some(context) = canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|context| context.dyn_into::<CanvasRenderingContext2d>().ok())
+    else {
+        return;
+    };
+    if let Ok(image) =
+        ImageData::new_with_u8_clamped_array_and_sh(Clamped(pixels), TILE_WIDTH, TILE_HEIGHT)
+    {
+        _ = context.put_image_data(&image, 0.0, 0.0);
+    }
+}
+
+/// A tile of the Mandelbrot set, rendered anew on every zoom. The previous one stays until then,
+/// and a zoom before the rendering is done cancels it.
+#[component]
+fn Demo02(worker: TaskWorker) -> impl IntoView {
+    let tile = create_rw_signal(whole_set());
+    let (image, progress) = create_worker_resource(worker, tile, |tile| tile);
+
+    let canvas = create_node_ref::<Canvas>();
+    create_effect(move |_| {
+        let Some(canvas) = canvas.get() else {
+            return;
+        };
+        image.with(|image| {
+            if let Some(Ok(pixels)) = image {
+                draw(&canvas, pixels);
+            }
+        });
+    });
+
+    let zoom_in = move |ev: ev::MouseEvent| {
+        let Some(canvas) = canvas.get_untracked() else {
+            return;
+        };
+        // the canvas may be shown smaller than it is
+        let ratio = f64::from(TILE_WIDTH) / f64::from(canvas.client_width().max(1));
+        tile.update(|tile| {
+            tile.center = tile.point(
+                f64::from(ev.offset_x()) * ratio,
+                f64::from(ev.offset_y()) * ratio,
+            );
+            tile.scale /= 2.0;
+        });
+    };
+    let zoom = move || whole_set().scale / tile.with(|tile| tile.scale);
+
+    view! {
+        <div class="container">
+            <h3 class="subtitle">{t!("worker-mandelbrot")}</h3>
+            <div class="field is-grouped">
+                <div class="control">
+                    <div class="select is-small">
+                        <select
+                            aria-label=t!("worker-iterations-label")
+                            on:change=move |ev| {
+                                if let Ok(iterations) = event_target_value(&ev).parse() {
+                                    tile.update(|tile| tile.max_iterations = iterations);
+                                }
+                            }
+                        >
+                            {ITERATIONS
+                                .into_iter()
+                                .map(|iterations| {
+                                    view! {
+                                        <option
+                                            value=iterations
+                                            selected=move || {
+                                                tile.with(|tile| tile.max_iterations == iterations)
+                                            }
+                                        >
+                                            {t!("worker-iterations", iterations = iterations)}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </div>
+                </div>
+                <div class="control">
+                    <BButton
+                        size=BSize::Small
+                        state=disabled_if(move || zoom() <= 1.0)
+                        on:click=move |_| tile.update(|tile| tile.scale *= 2.0)
+                    >
+                        {t!("worker-zoom-out")}
+                    </BButton>
+                </div>
+                <div class="control">
+                    <BButton size=BSize::Small on:click=move |_| tile.set(whole_set())>
+                        {t!("worker-reset")}
+                    </BButton>
+                </div>
+                <p class="control has-text-grey">{t!("worker-zoom", zoom = zoom())}</p>
+            </div>
+            <Show when=move || image.loading().get()>
+                <Progress value=progress color=Color::Primary size=BSize::Small/>
+            </Show>
+            {move || {
+                image
+                    .with(|image| match image {
+                        Some(Err(error)) => Some(worker_error(error.clone())),
+                        _ => None,
+                    })
+            }}
+            <canvas
+                node_ref=canvas
+                width=TILE_WIDTH
+                height=TILE_HEIGHT
+                style="max-width: 100%; cursor: zoom-in;"
+                role="img"
+                aria-label=t!("worker-canvas")
+                on:click=zoom_in
+            ></canvas>
+        </div>
+    }
+}
@@ -1,25 +1,41 @@
 use super::demo_async::DemoAsync;
 use super::demo_basics::{BasicComponent, ComponentsAndProps};
+use super::demo_benchmark::DemoBenchmark;
 use super::demo_control_flow::DemoControlFlow;
 use super::demo_error_handling::DemoErrorHandling;
 use super::demo_form_and_input::DemoFormAndInput;
-use super::demo_iteration::DemoBasicIteration;
+use super::demo_i18n::DemoI18n;
+use super::demo_iteration::{DemoBasicIteration, DemoDataTable};
+use super::demo_modal::DemoModal;
 use super::demo_nested_route::DemoNestedRoute;
+use super::demo_paging::DemoPaging;
 use super::demo_parent_children_communication::DemoParentChildrenCommunication;
 use super::demo_reactivity::DemoReactivity;
+use super::demo_server_push::DemoServerPush;
+use super::demo_virtual_list::DemoVirtualList;
+use super::demo_worker::DemoWorker;
+use crate::bulma::{Column, ColumnSize, Columns};
+use crate::i18n::t;
 use leptos::*;
 
 pub mod demo_async;
 pub mod demo_basics;
+pub mod demo_benchmark;
 pub mod demo_control_flow;
 pub mod demo_error_handling;
 pub mod demo_form_and_input;
+pub mod demo_i18n;
 pub mod demo_iteration;
+pub mod demo_modal;
 pub mod demo_nested_route;
+pub mod demo_paging;
 pub mod demo_parent_children_communication;
 pub mod demo_reactivity;
+pub mod demo_server_push;
+pub mod demo_virtual_list;
+pub mod demo_worker;
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct LeptosDemoMenu {
     pub demo_name: String,
 }
@@ -41,20 +57,28 @@ pub fn LeptosDemoMenu() -> impl IntoView {
     let menu =
         use_context::<ReadSignal<LeptosDemoMenu>>().expect("ReadSignal<LeptosDemoMenu> provided");
 
-    let menu_items: Vec<(&str, &str)> = vec![
-        ("basic_component", "basic components"),
-        ("components_and_pros", "components and props"),
-        ("demo_basic_iteration", "basic iterator"),
-        ("demo_form_and_input", "form and input"),
-        ("demo_error_handling", "error handling"),
-        ("demo_reactivity", "reactivity"),
+    let menu_items: Vec<(&str, Signal<String>)> = vec![
+        ("basic_component", t!("menu-basic-component")),
+        ("components_and_pros", t!("menu-components-and-props")),
+        ("demo_basic_iteration", t!("menu-basic-iteration")),
+        ("demo_form_and_input", t!("menu-form-and-input")),
+        ("demo_error_handling", t!("menu-error-handling")),
+        ("demo_reactivity", t!("menu-reactivity")),
         (
             "demo_parent_children_communication",
-            "parent child communication",
+            t!("menu-parent-child-communication"),
         ),
-        ("demo_async", "demo async"),
-        ("control_flow", "demo control flow"),
-        ("demo_nested_route", "demo nested route"),
+        ("demo_async", t!("menu-async")),
+        ("control_flow", t!("menu-control-flow")),
+        ("demo_nested_route", t!("menu-nested-route")),
+        ("demo_modal", t!("menu-modal")),
+        ("demo_virtual_list", t!("menu-virtual-list")),
+        ("demo_benchmark", t!("menu-benchmark")),
+        ("demo_data_table", t!("menu-data-table")),
+        ("demo_paging", t!("menu-paging")),
+        ("demo_server_push", t!("menu-server-push")),
+        ("demo_worker", t!("menu-worker")),
+        ("demo_i18n", t!("menu-i18n")),
     ];
 
     let selected_menu_class = move |path: &str| {
@@ -66,40 +90,44 @@ pub fn LeptosDemoMenu() -> impl IntoView {
     };
 
     view! {
-        <div class="columns">
-            <div class="menu column is-one-fifth">
-                <p class="menu-label">Demo index</p>
-                <ul class="menu-list">
-
-                    {menu_items
-                        .into_iter()
-                        .map(move |(path, label)| {
-                            view! {
-                                <li>
-                                    // Be careful about this: whenever we use signal to acheive reactivity, we need to use to use closure
-                                    // This is not working:
-                                    // <A class=selected_menu_class(path) href=path>
-                                    // {(*label).to_string()}
-                                    // </A>
-                                    // Because selected_menu_class(path) give us a value not a closure!
-                                    <A class=move || { selected_menu_class(path) } href=path>
-                                        {(*label).to_string()}
-                                    </A>
-                                </li>
-                            }
-                        })
-                        .collect::<Vec<_>>()}
-
-                </ul>
+        <Columns>
+            <Column size=ColumnSize::OneFifth>
+                <nav class="menu" aria-labelledby="demo-menu-label">
+                    <p id="demo-menu-label" class="menu-label">
+                        {t!("menu-title")}
+                    </p>
+                    <ul class="menu-list">
+
+                        {menu_items
+                            .into_iter()
+                            .map(move |(path, label)| {
+                                view! {
+                                    <li>
+                                        // Be careful about this: whenever we use signal to acheive reactivity, we need to use to use closure
+                                        // This is not working:
+                                        // <A class=selected_menu_class(path) href=path>
+                                        // {label}
+                                        // </A>
+                                        // Because selected_menu_class(path) give us a value not a closure!
+                                        <A class=move || { selected_menu_class(path) } href=path>
+                                            {label}
+                                        </A>
+                                    </li>
+                                }
+                            })
+                            .collect::<Vec<_>>()}
+
+                    </ul>
+                </nav>
 
             // <Outlet/> will show the nested child route
             // we can position this outlet wherever we want
             // within the layout
-            </div>
-            <div class="section column">
+            </Column>
+            <Column class="section">
                 <Outlet/>
-            </div>
-        </div>
+            </Column>
+        </Columns>
     }
 }
 
@@ -171,6 +199,46 @@ pub fn LeptosDemoContent() -> impl IntoView {
                 <DemoNestedRoute/>
             </div>
         },
+        "demo_modal" => view! {
+            <div>
+                <DemoModal/>
+            </div>
+        },
+        "demo_virtual_list" => view! {
+            <div>
+                <DemoVirtualList/>
+            </div>
+        },
+        "demo_benchmark" => view! {
+            <div>
+                <DemoBenchmark/>
+            </div>
+        },
+        "demo_data_table" => view! {
+            <div>
+                <DemoDataTable/>
+            </div>
+        },
+        "demo_paging" => view! {
+            <div>
+                <DemoPaging/>
+            </div>
+        },
+        "demo_server_push" => view! {
+            <div>
+                <DemoServerPush/>
+            </div>
+        },
+        "demo_worker" => view! {
+            <div>
+                <DemoWorker/>
+            </div>
+        },
+        "demo_i18n" => view! {
+            <div>
+                <DemoI18n/>
+            </div>
+        },
         _ => view! {
             <div>
                 <p class="subtitle">"ComponentNotFound"</p>
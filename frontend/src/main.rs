@@ -1,11 +1,43 @@
+mod a11y;
+mod abortable;
+mod auto_submit;
+mod bulma;
 mod bulma_playground;
+mod data_table;
+mod event_source;
+mod fallible;
+mod history;
+mod i18n;
 mod leptos_demo_components;
+mod modal;
+mod network;
+mod paging;
+mod persisted;
+mod pwa;
+mod query_cache;
+mod query_signal;
+mod reorder;
+mod timeline;
+mod timing;
+mod toast;
+mod virtual_list;
+mod websocket;
+mod worker;
 
 use crate::demo_nested_route::RoutesForDemoNestedRoute;
+use a11y::{RouteAnnouncer, SkipLink, MAIN_ID};
+use bulma::{BBox, BButton, BButtons, BColor, Card, NavbarDropdown};
 use bulma_playground::*;
+use i18n::{t, t_split, I18n, LanguageSwitcher};
 use leptos::*;
 use leptos_demo_components::*;
 use leptos_router::*;
+use modal::{ConfirmDialog, Confirmer};
+use network::Network;
+use persisted::{create_persisted_signal, StorageKey};
+use pwa::UpdateBanner;
+use query_cache::QueryClient;
+use toast::{NotificationStack, Notifier};
 
 fn main() {
     console_error_panic_hook::set_once();
@@ -14,54 +46,36 @@ fn main() {
 
 #[component]
 fn App() -> impl IntoView {
+    // each tab highlights the demo it shows, so the menu isn't stored
     let (menu, set_menu) = create_signal(LeptosDemoMenu::new(""));
+    // remembers the last demo, so /demos can offer to go back to it after a reload; the
+    // /demos page another tab shows shouldn't change under it, so tabs don't sync it
+    let last_demo = create_persisted_signal(
+        StorageKey::new("last-demo", 1).without_sync(),
+        String::new(),
+    );
+    create_effect(move |_| {
+        let demo_name = menu.with(|menu| menu.demo_name.clone());
+        if !demo_name.is_empty() {
+            last_demo.set(demo_name);
+        }
+    });
     provide_context(set_menu);
     provide_context(menu);
+    provide_context(Notifier::default());
+    provide_context(Confirmer::default());
+    provide_context(QueryClient::default());
+    provide_context(Network::default());
+    provide_context(I18n::default());
 
     let read_demo_name = move || menu().demo_name;
     view! {
         <Router>
+            <SkipLink/>
             <header>
-                <nav class="navbar" role="navigation" aria-label="main navigation">
-                    <div class="navbar-brand"></div>
-
-                    <div id="navbarBasicExample" class="navbar-menu">
-                        <div class="navbar-start">
-                            <a class="navbar-item" href="/">
-                                Home
-                            </a>
-
-                            <a class="navbar-item" href="/demos">
-                                Leptos frontend demos
-                            </a>
-
-                            <div class="navbar-item has-dropdown is-hoverable">
-                                <a class="navbar-link">More</a>
-
-                                <div class="navbar-dropdown">
-                                    <a class="navbar-item">About</a>
-                                    <a class="navbar-item is-selected">Jobs</a>
-                                    <a class="navbar-item">Contact</a>
-                                    <hr class="navbar-divider"/>
-                                    <a class="navbar-item">Report an issue</a>
-                                </div>
-                            </div>
-                        </div>
-
-                        <div class="navbar-end">
-                            <div class="navbar-item">
-                                <div class="buttons">
-                                    <a class="button is-primary">
-                                        <strong>Sign up</strong>
-                                    </a>
-                                    <a class="button is-light">Log in</a>
-                                </div>
-                            </div>
-                        </div>
-                    </div>
-                </nav>
+                <Nav/>
             </header>
-            <main>
+            <main id=MAIN_ID>
                 <div class="bd-docs">
                     <Routes>
                         <Route path="/" view=HomePageDiv/>
@@ -78,17 +92,29 @@ fn App() -> impl IntoView {
 
                             <Route
                                 path=""
-                                view=|| {
+                                view=move || {
                                     let setter = use_context::<WriteSignal<LeptosDemoMenu>>()
                                         .expect("WriteSignal<LeptosDemoMenu> provided");
+                                    let last_demo = last_demo.get_untracked();
                                     setter
                                         .update(|value| {
                                             *value = LeptosDemoMenu::new("");
                                         });
                                     view! {
                                         <div>
-                                            <p>"This is the default view for /demos"</p>
-                                            <p>"Select a demo to see detail"</p>
+                                            <p>{t!("demos-default")}</p>
+                                            <p>{t!("demos-select")}</p>
+                                            {(!last_demo.is_empty())
+                                                .then(|| {
+                                                    let text = t_split!("demos-last-visit", demo);
+                                                    view! {
+                                                        <p>
+                                                            {move || text.with(|(before, _)| before.clone())}
+                                                            <A href=last_demo.clone()>{last_demo.clone()}</A>
+                                                            {move || text.with(|(_, after)| after.clone())}
+                                                        </p>
+                                                    }
+                                                })}
                                         </div>
                                     }
                                 }
@@ -119,19 +145,19 @@ fn App() -> impl IntoView {
 
                                                 fallback=|| {
                                                     view! {
-                                                        <div class="box">
-                                                            <p class="subtitle">"default view for other sub-routes"</p>
-                                                        </div>
+                                                        <BBox>
+                                                            <p class="subtitle">{t!("demos-sub-route-default")}</p>
+                                                        </BBox>
                                                     }
                                                 }
                                             >
 
-                                                <div class="box">
+                                                <BBox>
                                                     <p class="subtitle">
-                                                        "default view for demos/demo_nested_route"
+                                                        {t!("demos-nested-route-default")}
                                                     </p>
-                                                    <p>"select one to go further route"</p>
-                                                </div>
+                                                    <p>{t!("demos-nested-route-select")}</p>
+                                                </BBox>
                                             </Show>
                                         }
                                     }
@@ -141,9 +167,9 @@ fn App() -> impl IntoView {
                                     path="home"
                                     view=|| {
                                         view! {
-                                            <div class="container box">
-                                                <h3>"Nested Route Home"</h3>
-                                            </div>
+                                            <BBox class="container">
+                                                <h3>{t!("nested-home")}</h3>
+                                            </BBox>
                                         }
                                     }
                                 />
@@ -153,11 +179,11 @@ fn App() -> impl IntoView {
                                     <Route path=":id" view=demo_nested_route::ContactInfo>
                                         <Route
                                             path=""
-                                            view=|| view! { <div class="tab">"(Contact Info)"</div> }
+                                            view=|| view! { <div class="tab">{t!("nested-contact-info-placeholder")}</div> }
                                         />
                                         <Route
                                             path="conversations"
-                                            view=|| view! { <div class="tab">"(Conversations)"</div> }
+                                            view=demo_nested_route::Conversations
                                         />
                                     </Route>
                                     // if no id specified, fall back
@@ -166,7 +192,7 @@ fn App() -> impl IntoView {
                                         view=|| {
                                             view! {
                                                 <div class="select-user">
-                                                    "Select a user to view contact info."
+                                                    {t!("nested-select-user")}
                                                 </div>
                                             }
                                         }
@@ -179,12 +205,16 @@ fn App() -> impl IntoView {
                             </Route>
 
                         </Route>
-                        <Route path="/*any" view=|| view! { <h1>"Route Not Found"</h1> }/>
+                        <Route path="/*any" view=|| view! { <h1>{t!("not-found")}</h1> }/>
                     </Routes>
                 // all our routes will appear inside <main>
 
                 </div>
             </main>
+            <NotificationStack/>
+            <ConfirmDialog/>
+            <UpdateBanner/>
+            <RouteAnnouncer/>
         </Router>
     }
 }
@@ -194,45 +224,87 @@ pub fn HomePageDiv() -> impl IntoView {
     view! {
         <div class="section">
 
-            <h2 class="title">"Home Page"</h2>
-            <BulmaPlayground/>
+            <h1 class="title">{t!("home-title")}</h1>
+            <Card title=t!("home-playground")>
+                <BulmaPlayground/>
+            </Card>
         </div>
     }
 }
 
+/// The navbar. On narrow screens its menu folds away behind a burger.
 #[component]
 pub fn Nav() -> impl IntoView {
+    let (menu_open, set_menu_open) = create_signal(false);
+    // following a link from the unfolded menu folds it again
+    let on_menu_click = move |ev: ev::MouseEvent| {
+        let on_link = event_target::<web_sys::Element>(&ev)
+            .closest("a")
+            .ok()
+            .flatten()
+            .is_some();
+        if on_link {
+            set_menu_open(false);
+        }
+    };
+
     view! {
-        <nav class="navbar" role="navigation" aria-label="main navigation">
-            <div class="navbar-brand"></div>
+        <nav class="navbar" aria-label=t!("nav-main")>
+            <div class="navbar-brand">
+                <button
+                    type="button"
+                    class="navbar-burger"
+                    class:is-active=menu_open
+                    aria-label=t!("nav-menu")
+                    aria-controls="navbar-menu"
+                    aria-expanded=move || menu_open().to_string()
+                    on:click=move |_| set_menu_open.update(|open| *open = !*open)
+                >
+                    <span aria-hidden="true"></span>
+                    <span aria-hidden="true"></span>
+                    <span aria-hidden="true"></span>
+                    <span aria-hidden="true"></span>
+                </button>
+            </div>
 
-            <div id="navbarBasicExample" class="navbar-menu">
+            <div
+                id="navbar-menu"
+                class="navbar-menu"
+                class:is-active=menu_open
+                on:click=on_menu_click
+            >
                 <div class="navbar-start">
-                    <a class="navbar-item">Home</a>
-
-                    <a class="navbar-item">Documentation</a>
+                    <a class="navbar-item" href="/">
+                        {t!("nav-home")}
+                    </a>
 
-                    <div class="navbar-item has-dropdown is-hoverable">
-                        <a class="navbar-link">More</a>
+                    <a class="navbar-item" href="/demos">
+                        {t!("nav-demos")}
+                    </a>
 
-                        <div class="navbar-dropdown">
-                            <a class="navbar-item">About</a>
-                            <a class="navbar-item is-selected">Jobs</a>
-                            <a class="navbar-item">Contact</a>
-                            <hr class="navbar-divider"/>
-                            <a class="navbar-item">Report an issue</a>
-                        </div>
-                    </div>
+                    // placeholders: there are no such pages, so they are greyed out text, not links
+                    <NavbarDropdown label=t!("nav-more")>
+                        <span class="navbar-item has-text-grey">{t!("nav-about")}</span>
+                        <span class="navbar-item is-selected has-text-grey">{t!("nav-jobs")}</span>
+                        <span class="navbar-item has-text-grey">{t!("nav-contact")}</span>
+                        <hr class="navbar-divider"/>
+                        <span class="navbar-item has-text-grey">{t!("nav-report-issue")}</span>
+                    </NavbarDropdown>
                 </div>
 
                 <div class="navbar-end">
                     <div class="navbar-item">
-                        <div class="buttons">
-                            <a class="button is-primary">
-                                <strong>Sign up</strong>
-                            </a>
-                            <a class="button is-light">Log in</a>
-                        </div>
+                        <LanguageSwitcher/>
+                    </div>
+                    <div class="navbar-item">
+                        <BButtons>
+                            <BButton button_type="button" color=BColor::Primary>
+                                <strong>{t!("nav-sign-up")}</strong>
+                            </BButton>
+                            <BButton button_type="button" is_light=true>
+                                {t!("nav-log-in")}
+                            </BButton>
+                        </BButtons>
                     </div>
                 </div>
             </div>
@@ -0,0 +1,211 @@
+use crate::bulma::{BButton, BColor};
+use crate::i18n::t;
+use futures::channel::oneshot;
+use leptos::ev::KeyboardEvent;
+use leptos::html::Div;
+use leptos::*;
+use std::future::Future;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+/// Everything inside the dialog a keyboard user can land on with Tab.
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+                         select:not([disabled]), textarea:not([disabled]), \
+                         [tabindex]:not([tabindex='-1'])";
+
+/// A Bulma `modal` rendered into a portal at body level, so no parent layout can crop it.
+///
+/// Children go right after the backdrop: pass a `modal-content` for free-form content
+/// or a `modal-card` for the header/body/footer layout.
+#[component]
+pub fn Modal(
+    /// Whether the modal is shown.
+    #[prop(into)]
+    open: Signal<bool>,
+    /// Called when the user asks to close it: Escape or a click on the backdrop.
+    #[prop(into)]
+    on_close: Callback<()>,
+    /// Id of the element labelling the dialog, usually its title.
+    #[prop(optional)]
+    labelled_by: Option<&'static str>,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = store_value(children);
+
+    view! {
+        <Show when=open>
+            <Portal>
+                <ModalDialog on_close labelled_by>
+                    {children.with_value(|children| children())}
+                </ModalDialog>
+            </Portal>
+        </Show>
+    }
+}
+
+/// The mounted part of `Modal`. Its setup and cleanup are where focus is taken and given back.
+#[component]
+fn ModalDialog(
+    on_close: Callback<()>,
+    labelled_by: Option<&'static str>,
+    children: Children,
+) -> impl IntoView {
+    let dialog_ref = create_node_ref::<Div>();
+
+    // remember who opened us, so focus goes back there when we close
+    let trigger = document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+    on_cleanup(move || {
+        if let Some(trigger) = trigger {
+            _ = trigger.focus();
+        }
+    });
+
+    // move focus into the dialog as soon as it is in the DOM
+    dialog_ref.on_load(move |dialog| {
+        request_animation_frame(move || {
+            let first = focusable_elements(&dialog).into_iter().next();
+            _ = first.unwrap_or_else(|| (*dialog).clone().into()).focus();
+        });
+    });
+
+    // Escape closes the modal wherever focus currently is
+    let escape = window_event_listener(ev::keydown, move |ev| {
+        if ev.key() == "Escape" {
+            on_close(());
+        }
+    });
+    on_cleanup(move || escape.remove());
+
+    // keep Tab and Shift+Tab cycling inside the dialog
+    let trap_focus = move |ev: KeyboardEvent| {
+        if ev.key() != "Tab" {
+            return;
+        }
+        let Some(dialog) = dialog_ref.get() else {
+            return;
+        };
+        let focusable = focusable_elements(&dialog);
+        let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+            ev.prevent_default();
+            return;
+        };
+        let active = document().active_element();
+        let at = |element: &HtmlElement| active.as_ref() == Some(element.unchecked_ref());
+        if ev.shift_key() && at(first) {
+            ev.prevent_default();
+            _ = last.focus();
+        } else if !ev.shift_key() && at(last) {
+            ev.prevent_default();
+            _ = first.focus();
+        }
+    };
+
+    view! {
+        <div
+            class="modal is-active"
+            role="dialog"
+            aria-modal="true"
+            aria-labelledby=labelled_by
+            tabindex="-1"
+            node_ref=dialog_ref
+            on:keydown=trap_focus
+        >
+            <div class="modal-background" on:click=move |_| on_close(())></div>
+            {children()}
+        </div>
+    }
+}
+
+fn focusable_elements(dialog: &HtmlElement) -> Vec<HtmlElement> {
+    let Ok(nodes) = dialog.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.get(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Confirm/cancel dialogs that can be awaited. Provided at the App root and
+/// rendered by `<ConfirmDialog/>`.
+#[derive(Clone, Copy)]
+pub struct Confirmer {
+    message: RwSignal<Option<String>>,
+    answer: StoredValue<Option<oneshot::Sender<bool>>>,
+}
+
+impl Confirmer {
+    pub fn new() -> Self {
+        Confirmer {
+            message: create_rw_signal(None),
+            answer: store_value(None),
+        }
+    }
+
+    /// Shows `message` and resolves to `true` if the user confirms.
+    /// Closing the dialog any other way, or asking again before it is answered, resolves to `false`.
+    pub fn confirm(&self, message: impl Into<String>) -> impl Future<Output = bool> {
+        let (sender, receiver) = oneshot::channel();
+        // replacing the sender drops the previous one, which cancels that question
+        self.answer.set_value(Some(sender));
+        self.message.set(Some(message.into()));
+        async move { receiver.await.unwrap_or(false) }
+    }
+
+    fn respond(&self, confirmed: bool) {
+        if let Some(sender) = self.answer.try_update_value(Option::take).flatten() {
+            _ = sender.send(confirmed);
+        }
+        self.message.set(None);
+    }
+}
+
+impl Default for Confirmer {
+    fn default() -> Self {
+        Confirmer::new()
+    }
+}
+
+pub fn use_confirmer() -> Confirmer {
+    use_context::<Confirmer>().expect("Confirmer provided")
+}
+
+/// Renders the question currently asked through the `Confirmer` in context.
+#[component]
+pub fn ConfirmDialog() -> impl IntoView {
+    let confirmer = use_confirmer();
+    let open = Signal::derive(move || confirmer.message.with(Option::is_some));
+    let message = move || confirmer.message.get().unwrap_or_default();
+
+    view! {
+        <Modal open on_close=move |_| confirmer.respond(false) labelled_by="confirm-dialog-title">
+            <div class="modal-card">
+                <header class="modal-card-head">
+                    <p class="modal-card-title" id="confirm-dialog-title">
+                        {t!("confirm-title")}
+                    </p>
+                    <button
+                        class="delete"
+                        aria-label=t!("close")
+                        on:click=move |_| confirmer.respond(false)
+                    ></button>
+                </header>
+                <section class="modal-card-body">
+                    <p>{message}</p>
+                </section>
+                <footer class="modal-card-foot">
+                    <div class="buttons">
+                        <BButton color=BColor::Danger on:click=move |_| confirmer.respond(true)>
+                            {t!("confirm-ok")}
+                        </BButton>
+                        <BButton on:click=move |_| confirmer.respond(false)>
+                            {t!("confirm-cancel")}
+                        </BButton>
+                    </div>
+                </footer>
+            </div>
+        </Modal>
+    }
+}
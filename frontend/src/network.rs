@@ -0,0 +1,282 @@
+use crate::a11y::unique_id;
+use crate::bulma::{BBox, BButton, BButtons, BColor, BSize};
+use crate::fallible::{ApiError, FailureMode};
+use crate::i18n::t;
+use crate::persisted::{create_persisted_signal, StorageKey};
+use gloo_timers::future::TimeoutFuture;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+/// How the simulated network behaves. Every fake loader goes through it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NetworkConditions {
+    /// The time one round trip to the server takes.
+    pub latency_ms: u32,
+    /// Up to this much is added to every round trip at random.
+    pub jitter_ms: u32,
+    /// The share of requests that fail, 0.0 to 1.0, on top of what a demo injects itself.
+    pub failure_rate: f64,
+    /// Every request fails at once.
+    pub offline: bool,
+}
+
+/// Makes a label in the current language, when the label is shown.
+type Label = fn() -> Signal<String>;
+
+impl NetworkConditions {
+    /// The most latency or jitter the panel accepts, a minute.
+    pub const MAX_DELAY_MS: u32 = 60_000;
+
+    /// A latency or jitter typed into the panel, kept within `0..=MAX_DELAY_MS`.
+    fn delay(value: f64) -> u32 {
+        value.clamp(0.0, f64::from(Self::MAX_DELAY_MS)) as u32
+    }
+
+    /// Each with its label.
+    const PRESETS: [(Label, NetworkConditions); 4] = [
+        (
+            || t!("network-fast"),
+            NetworkConditions {
+                latency_ms: 50,
+                jitter_ms: 50,
+                failure_rate: 0.0,
+                offline: false,
+            },
+        ),
+        (|| t!("network-normal"), NetworkConditions::NORMAL),
+        (
+            || t!("network-slow"),
+            NetworkConditions {
+                latency_ms: 3_000,
+                jitter_ms: 2_000,
+                failure_rate: 0.0,
+                offline: false,
+            },
+        ),
+        (
+            || t!("network-flaky"),
+            NetworkConditions {
+                latency_ms: 1_000,
+                jitter_ms: 1_500,
+                failure_rate: 0.3,
+                offline: false,
+            },
+        ),
+    ];
+
+    const NORMAL: NetworkConditions = NetworkConditions {
+        latency_ms: 1_000,
+        jitter_ms: 500,
+        failure_rate: 0.0,
+        offline: false,
+    };
+}
+
+impl Default for NetworkConditions {
+    fn default() -> Self {
+        NetworkConditions::NORMAL
+    }
+}
+
+/// The simulated network, shared by the whole app and kept across reloads.
+#[derive(Clone, Copy)]
+pub struct Network {
+    conditions: RwSignal<NetworkConditions>,
+}
+
+impl Network {
+    pub fn new() -> Self {
+        Network {
+            conditions: create_persisted_signal(
+                StorageKey::new("network", 1),
+                NetworkConditions::default(),
+            ),
+        }
+    }
+
+    /// The time `round_trips` round trips take under the current conditions, jitter included.
+    pub fn latency_ms(self, round_trips: u32) -> u32 {
+        let conditions = self.conditions.get_untracked();
+        (0..round_trips)
+            .map(|_| {
+                let jitter = f64::from(conditions.jitter_ms) * js_sys::Math::random();
+                conditions.latency_ms.saturating_add(jitter as u32)
+            })
+            .fold(0, u32::saturating_add)
+    }
+
+    /// Whether a request that made it to the server comes back fine, going by the current
+    /// conditions and the failures a demo injects.
+    pub fn outcome(self, failures: FailureMode) -> Result<(), ApiError> {
+        let conditions = self.conditions.get_untracked();
+        if conditions.offline {
+            return Err(ApiError::Offline);
+        }
+        if js_sys::Math::random() < conditions.failure_rate {
+            return Err(ApiError::Unavailable);
+        }
+        failures.roll()
+    }
+
+    /// Pretends to talk to the server for `round_trips` round trips.
+    ///
+    /// Offline, it fails at once, like a real request does.
+    pub async fn request(self, round_trips: u32, failures: FailureMode) -> Result<(), ApiError> {
+        if self
+            .conditions
+            .with_untracked(|conditions| conditions.offline)
+        {
+            return Err(ApiError::Offline);
+        }
+        TimeoutFuture::new(self.latency_ms(round_trips)).await;
+        self.outcome(failures)
+    }
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Network::new()
+    }
+}
+
+pub fn use_network() -> Network {
+    use_context::<Network>().expect("Network provided")
+}
+
+/// Settings of the simulated network, with a few presets.
+#[component]
+pub fn NetworkPanel() -> impl IntoView {
+    let conditions = use_network().conditions;
+    let latency_id = unique_id("network-latency");
+    let jitter_id = unique_id("network-jitter");
+    let failure_rate_id = unique_id("network-failure-rate");
+    let failure_percent = move || (conditions().failure_rate * 100.0).round();
+    let summary = t!(
+        "network-summary",
+        latency = conditions().latency_ms,
+        jitter = conditions().jitter_ms,
+        failures = failure_percent(),
+    );
+    let offline_summary = t!("network-offline-summary");
+    let set = move |update: fn(&mut NetworkConditions, f64), ev| {
+        if let Ok(value) = event_target_value(&ev).parse::<f64>() {
+            conditions.update(|conditions| update(conditions, value));
+        }
+    };
+
+    view! {
+        <BBox>
+            <details>
+                <summary>
+                    {move || {
+                        if conditions().offline { offline_summary.get() } else { summary.get() }
+                    }}
+                </summary>
+                <BButtons class="mt-3">
+                    {NetworkConditions::PRESETS
+                        .into_iter()
+                        .map(|(label, preset)| {
+                            view! {
+                                <BButton
+                                    size=BSize::Small
+                                    color=Signal::derive(move || {
+                                        if conditions() == preset {
+                                            BColor::Link
+                                        } else {
+                                            BColor::Default
+                                        }
+                                    })
+                                    on:click=move |_| conditions.set(preset)
+                                >
+                                    {label()}
+                                </BButton>
+                            }
+                        })
+                        .collect_view()}
+                </BButtons>
+                <div class="field is-grouped is-grouped-multiline">
+                    <div class="control">
+                        <label class="label is-small" for=latency_id.clone()>
+                            {t!("network-latency")}
+                        </label>
+                        <input
+                            id=latency_id
+                            class="input is-small"
+                            type="number"
+                            min="0"
+                            max=NetworkConditions::MAX_DELAY_MS
+                            step="50"
+                            prop:value=move || conditions().latency_ms
+                            on:input=move |ev| {
+                                set(|conditions, value| conditions.latency_ms = NetworkConditions::delay(value), ev)
+                            }
+                        />
+                    </div>
+                    <div class="control">
+                        <label class="label is-small" for=jitter_id.clone()>
+                            {t!("network-jitter")}
+                        </label>
+                        <input
+                            id=jitter_id
+                            class="input is-small"
+                            type="number"
+                            min="0"
+                            max=NetworkConditions::MAX_DELAY_MS
+                            step="50"
+                            prop:value=move || conditions().jitter_ms
+                            on:input=move |ev| {
+                                set(|conditions, value| conditions.jitter_ms = NetworkConditions::delay(value), ev)
+                            }
+                        />
+                    </div>
+                    <div class="control">
+                        <label class="label is-small" for=failure_rate_id.clone()>
+                            {t!("network-failure-rate", failures = failure_percent())}
+                        </label>
+                        <input
+                            id=failure_rate_id
+                            type="range"
+                            min="0"
+                            max="100"
+                            prop:value=move || conditions().failure_rate * 100.0
+                            on:input=move |ev| {
+                                set(|conditions, value| conditions.failure_rate = value / 100.0, ev)
+                            }
+                        />
+                    </div>
+                    <div class="control">
+                        <label class="checkbox">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || conditions().offline
+                                on:change=move |ev| {
+                                    conditions
+                                        .update(|conditions| {
+                                            conditions.offline = event_target_checked(&ev);
+                                        })
+                                }
+                            />
+                            " "
+                            {t!("network-offline")}
+                        </label>
+                    </div>
+                </div>
+            </details>
+        </BBox>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_delays_stay_within_bounds() {
+        assert_eq!(NetworkConditions::delay(250.0), 250);
+        assert_eq!(NetworkConditions::delay(-50.0), 0);
+        assert_eq!(
+            NetworkConditions::delay(1e12),
+            NetworkConditions::MAX_DELAY_MS
+        );
+    }
+}
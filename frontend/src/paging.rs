@@ -0,0 +1,307 @@
+use crate::fallible::{ApiError, LoadError};
+use crate::i18n::t;
+use futures::future::LocalBoxFuture;
+use futures::FutureExt;
+use leptos::html::Div;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::hash::Hash;
+use std::rc::Rc;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+/// One page of a paged collection.
+///
+/// `C` is whatever the server wants back to find the next page: a page number, or a cursor
+/// like the id of the last item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Page<T, C> {
+    pub items: Vec<T>,
+    /// `None` on the last page.
+    pub next: Option<C>,
+    /// Items in the whole collection.
+    pub total: usize,
+}
+
+impl<T, C> Page<T, C> {
+    pub fn page_count(&self, per_page: usize) -> usize {
+        self.total.div_ceil(per_page).max(1)
+    }
+}
+
+/// A page number for URLs and pagination, counting from 1.
+///
+/// It parses from query parameters, where anything but a positive number reads as page 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageNumber(usize);
+
+impl PageNumber {
+    /// Page `page`, or page 1 for 0.
+    pub fn new(page: usize) -> Self {
+        PageNumber(page.max(1))
+    }
+
+    pub fn get(self) -> usize {
+        self.0
+    }
+
+    /// The page counting from 0, to compute offsets with.
+    pub fn index(self) -> usize {
+        // 0 can still come in through `Deserialize`
+        self.0.saturating_sub(1)
+    }
+}
+
+impl Default for PageNumber {
+    fn default() -> Self {
+        PageNumber(1)
+    }
+}
+
+impl FromStr for PageNumber {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(page) if page >= 1 => Ok(PageNumber(page)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for PageNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+type PageLoader<T, C> =
+    Rc<dyn Fn(Option<C>) -> LocalBoxFuture<'static, Result<Page<T, C>, ApiError>>>;
+
+/// The pages of a collection loaded so far, one after the other, for infinite scrolling.
+pub struct InfiniteList<T: 'static, C: 'static> {
+    items: RwSignal<Vec<T>>,
+    next: StoredValue<Option<C>>,
+    total: RwSignal<Option<usize>>,
+    done: RwSignal<bool>,
+    loading: RwSignal<bool>,
+    error: RwSignal<Option<ApiError>>,
+    // bumped by `reset`, so pages asked for before then are dropped when they arrive
+    generation: StoredValue<usize>,
+    load: StoredValue<PageLoader<T, C>>,
+}
+
+impl<T, C> Clone for InfiniteList<T, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, C> Copy for InfiniteList<T, C> {}
+
+/// An empty `InfiniteList`. `load` gets the cursor of the page to load, `None` for the first.
+pub fn create_infinite_list<T, C, Fut>(
+    load: impl Fn(Option<C>) -> Fut + 'static,
+) -> InfiniteList<T, C>
+where
+    T: Clone + 'static,
+    C: Clone + 'static,
+    Fut: Future<Output = Result<Page<T, C>, ApiError>> + 'static,
+{
+    InfiniteList {
+        items: create_rw_signal(Vec::new()),
+        next: store_value(None),
+        total: create_rw_signal(None),
+        done: create_rw_signal(false),
+        loading: create_rw_signal(false),
+        error: create_rw_signal(None),
+        generation: store_value(0),
+        load: store_value(Rc::new(move |cursor| load(cursor).boxed_local())),
+    }
+}
+
+impl<T: Clone, C: Clone> InfiniteList<T, C> {
+    pub fn items(&self) -> Signal<Vec<T>> {
+        self.items.into()
+    }
+
+    /// Items in the whole collection, once the first page told.
+    pub fn total(&self) -> Signal<Option<usize>> {
+        self.total.into()
+    }
+
+    pub fn is_loading(&self) -> Signal<bool> {
+        self.loading.into()
+    }
+
+    /// Whether the last page was loaded.
+    pub fn is_done(&self) -> Signal<bool> {
+        self.done.into()
+    }
+
+    /// Why the last page asked for didn't load. `load_more` tries it again.
+    pub fn error(&self) -> Signal<Option<ApiError>> {
+        self.error.into()
+    }
+
+    /// Loads the next page, unless one is already on its way or there are no more.
+    pub fn load_more(&self) {
+        if self.loading.get_untracked() || self.done.get_untracked() {
+            return;
+        }
+        let list = *self;
+        let generation = self.generation.get_value();
+        let page = self.load.with_value(|load| load(self.next.get_value()));
+        self.loading.set(true);
+        self.error.set(None);
+
+        spawn_local(async move {
+            let page = page.await;
+            // the list was reset, or its owner is gone
+            if list.generation.try_get_value() != Some(generation) {
+                return;
+            }
+            match page {
+                Ok(page) => {
+                    list.done.set(page.next.is_none());
+                    list.next.set_value(page.next);
+                    list.total.set(Some(page.total));
+                    list.items.update(|items| items.extend(page.items));
+                }
+                Err(error) => list.error.set(Some(error)),
+            }
+            list.loading.set(false);
+        });
+    }
+
+    /// Drops every page loaded so far, to start over from the first.
+    pub fn reset(&self) {
+        self.generation.update_value(|generation| *generation += 1);
+        self.next.set_value(None);
+        self.items.set(Vec::new());
+        self.total.set(None);
+        self.done.set(false);
+        self.loading.set(false);
+        self.error.set(None);
+    }
+}
+
+/// A scrolling list that loads the next page of `list` whenever its end comes into view.
+#[component]
+pub fn InfiniteScroll<T, C, K, KF, CF, N>(
+    list: InfiniteList<T, C>,
+    /// Unique and stable per item, as for `<For/>`.
+    key: KF,
+    /// Renders one item.
+    children: CF,
+    /// Height of the scrolling viewport in pixels.
+    #[prop(default = 400.0)]
+    height: f64,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    C: Clone + 'static,
+    K: Eq + Hash + 'static,
+    KF: Fn(&T) -> K + 'static,
+    CF: Fn(T) -> N + 'static,
+    N: IntoView + 'static,
+{
+    let viewport = create_node_ref::<Div>();
+    let sentinel = create_node_ref::<Div>();
+    // whether the end of the list is in view
+    let (at_end, set_at_end) = create_signal(false);
+
+    let observer = store_value(None::<(IntersectionObserver, Closure<dyn Fn(js_sys::Array)>)>);
+    sentinel.on_load(move |sentinel| {
+        let on_intersect = Closure::<dyn Fn(js_sys::Array)>::new(move |entries: js_sys::Array| {
+            if let Ok(entry) = entries.pop().dyn_into::<IntersectionObserverEntry>() {
+                set_at_end.try_set(entry.is_intersecting());
+            }
+        });
+        let mut options = IntersectionObserverInit::new();
+        // start loading a little before the end is actually reached
+        options
+            .root(viewport.get_untracked().as_deref().map(AsRef::as_ref))
+            .root_margin("200px");
+        let Ok(intersection) =
+            IntersectionObserver::new_with_options(on_intersect.as_ref().unchecked_ref(), &options)
+        else {
+            return;
+        };
+        intersection.observe(&sentinel);
+        observer.set_value(Some((intersection, on_intersect)));
+    });
+    on_cleanup(move || {
+        if let Some((intersection, _)) = observer.try_update_value(Option::take).flatten() {
+            intersection.disconnect();
+        }
+    });
+
+    // the observer only reports changes, so keep going while a new page still leaves the end in view
+    create_effect(move |_| {
+        let waiting =
+            list.is_loading().get() || list.is_done().get() || list.error().get().is_some();
+        if at_end.get() && !waiting {
+            list.load_more();
+        }
+    });
+
+    view! {
+        <div
+            node_ref=viewport
+            style:height=format!("{height}px")
+            style="overflow-y: auto;"
+        >
+            <For each=move || list.items().get() key=key children=children/>
+            <div node_ref=sentinel></div>
+            {move || match list.error().get() {
+                Some(error) => view! { <LoadError error on_retry=Callback::new(move |_| list.load_more())/> }.into_view(),
+                None if list.is_loading().get() => view! { <p class="has-text-grey">{t!("list-loading")}</p> }.into_view(),
+                None if list.is_done().get() => view! { <p class="has-text-grey">{t!("list-end")}</p> }.into_view(),
+                None => ().into_view(),
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_numbers_start_at_one() {
+        assert_eq!(PageNumber::new(0), PageNumber::new(1));
+        assert_eq!(PageNumber::new(0).index(), 0);
+        assert_eq!(PageNumber::new(3).index(), 2);
+        assert_eq!(PageNumber::default().get(), 1);
+    }
+
+    #[test]
+    fn only_positive_numbers_parse() {
+        assert_eq!("7".parse(), Ok(PageNumber::new(7)));
+        assert_eq!("0".parse::<PageNumber>(), Err(()));
+        assert_eq!("-1".parse::<PageNumber>(), Err(()));
+        assert_eq!("two".parse::<PageNumber>(), Err(()));
+    }
+
+    #[test]
+    fn zero_deserializes_without_underflowing() {
+        let page: PageNumber = serde_json::from_str("0").unwrap();
+        assert_eq!(page.index(), 0);
+    }
+
+    #[test]
+    fn an_empty_collection_has_one_page() {
+        let page = Page::<u32, ()> {
+            items: Vec::new(),
+            next: None,
+            total: 0,
+        };
+        assert_eq!(page.page_count(10), 1);
+        let page = Page::<u32, ()> { total: 21, ..page };
+        assert_eq!(page.page_count(10), 3);
+    }
+}
@@ -0,0 +1,195 @@
+use leptos::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+
+/// Keeps this app's entries apart from anything else stored for the same origin.
+const KEY_PREFIX: &str = "leptos-demo:";
+
+thread_local! {
+    static SYNCING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the signal being set right now takes a change another tab made. Effects run while
+/// the signal is set, so they can tell those apart from the changes made here.
+pub fn is_syncing() -> bool {
+    SYNCING.get()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    /// Kept until cleared, and shared by every tab of the origin.
+    Local,
+    /// Kept until the tab is closed, and only seen by that tab.
+    Session,
+}
+
+impl StorageKind {
+    fn storage(self) -> Option<web_sys::Storage> {
+        let storage = match self {
+            StorageKind::Local => window().local_storage(),
+            StorageKind::Session => window().session_storage(),
+        };
+        // storage can be disabled, e.g. in private browsing, which just means nothing persists
+        storage.ok().flatten()
+    }
+}
+
+/// Where a value is stored, and which version of its type wrote it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageKey {
+    name: &'static str,
+    version: u32,
+    sync_tabs: bool,
+}
+
+impl StorageKey {
+    /// Bump `version` when the stored type changes in a way old entries can't be read as.
+    /// Entries written under another version are dropped and the default is used instead.
+    pub const fn new(name: &'static str, version: u32) -> Self {
+        StorageKey {
+            name,
+            version,
+            sync_tabs: true,
+        }
+    }
+
+    /// Stops other tabs' changes from reaching this tab while it is open. The stored value is
+    /// still what the next tab to open starts with.
+    pub const fn without_sync(self) -> Self {
+        StorageKey {
+            sync_tabs: false,
+            ..self
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry<T> {
+    version: u32,
+    value: T,
+}
+
+/// An `RwSignal` that survives reloads by living in `localStorage` under `key`.
+///
+/// Other open tabs see its changes as they are made, unless `key` is `without_sync`.
+pub fn create_persisted_signal<T>(key: StorageKey, default: T) -> RwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Clone + PartialEq + 'static,
+{
+    create_stored_signal(StorageKind::Local, key, default)
+}
+
+/// Like `create_persisted_signal`, but forgotten when the tab is closed.
+pub fn create_session_signal<T>(key: StorageKey, default: T) -> RwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Clone + PartialEq + 'static,
+{
+    create_stored_signal(StorageKind::Session, key, default)
+}
+
+pub fn create_stored_signal<T>(kind: StorageKind, key: StorageKey, default: T) -> RwSignal<T>
+where
+    T: Serialize + DeserializeOwned + Clone + PartialEq + 'static,
+{
+    let version = key.version;
+    let sync_tabs = key.sync_tabs;
+    let key = format!("{KEY_PREFIX}{}", key.name);
+    let signal = create_rw_signal(read(kind, &key, version).unwrap_or(default));
+
+    create_effect({
+        let key = key.clone();
+        move |_| signal.with(|value| write(kind, &key, version, value))
+    });
+
+    // another tab wrote the entry; sessionStorage is per tab, so only localStorage is shared
+    if kind == StorageKind::Local && sync_tabs {
+        let listener = window_event_listener(ev::storage, move |ev| {
+            if ev.key().as_deref() != Some(key.as_str()) {
+                return;
+            }
+            let value = ev
+                .new_value()
+                .and_then(|json| parse::<T>(&key, version, &json));
+            if let Some(value) = value {
+                if signal.with_untracked(|current| *current != value) {
+                    SYNCING.set(true);
+                    signal.set(value);
+                    SYNCING.set(false);
+                }
+            }
+        });
+        on_cleanup(move || listener.remove());
+    }
+
+    signal
+}
+
+fn read<T: DeserializeOwned>(kind: StorageKind, key: &str, version: u32) -> Option<T> {
+    let storage = kind.storage()?;
+    let json = storage.get_item(key).ok().flatten()?;
+    let value = parse(key, version, &json);
+    if value.is_none() {
+        _ = storage.remove_item(key);
+    }
+    value
+}
+
+fn parse<T: DeserializeOwned>(key: &str, version: u32, json: &str) -> Option<T> {
+    match serde_json::from_str::<Entry<serde_json::Value>>(json) {
+        Ok(entry) if entry.version == version => serde_json::from_value(entry.value)
+            .map_err(|error| logging::warn!("dropping stored {key}: {error}"))
+            .ok(),
+        Ok(entry) => {
+            logging::warn!(
+                "dropping stored {key}: written by version {}, this is version {version}",
+                entry.version
+            );
+            None
+        }
+        Err(error) => {
+            logging::warn!("dropping stored {key}: {error}");
+            None
+        }
+    }
+}
+
+fn write<T: Serialize>(kind: StorageKind, key: &str, version: u32, value: &T) {
+    let Some(storage) = kind.storage() else {
+        return;
+    };
+    let entry = Entry { version, value };
+    let Ok(json) = serde_json::to_string(&entry) else {
+        return;
+    };
+    // rewriting the same text would be a no-op anyway, but skip the work
+    if storage.get_item(key).ok().flatten().as_deref() != Some(json.as_str()) {
+        if let Err(error) = storage.set_item(key, &json) {
+            logging::warn!("could not store {key}: {error:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_its_own_version() {
+        let json = r#"{"version":2,"value":[1,2]}"#;
+        assert_eq!(parse::<Vec<u32>>("key", 2, json), Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn drops_other_versions() {
+        let json = r#"{"version":1,"value":[1,2]}"#;
+        assert_eq!(parse::<Vec<u32>>("key", 2, json), None);
+    }
+
+    #[test]
+    fn drops_values_of_another_shape() {
+        let json = r#"{"version":1,"value":"text"}"#;
+        assert_eq!(parse::<Vec<u32>>("key", 1, json), None);
+        assert_eq!(parse::<Vec<u32>>("key", 1, "not json"), None);
+    }
+}
@@ -0,0 +1,117 @@
+//! Keeps the app working offline with the service worker of `sw.js`, and offers new versions.
+
+use crate::bulma::{BButton, BSize, Color, Notification};
+use crate::i18n::t;
+use gloo_timers::callback::Interval;
+use leptos::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{ServiceWorker, ServiceWorkerRegistration, ServiceWorkerState};
+
+const SERVICE_WORKER_URL: &str = "/sw.js";
+/// How often a page left open looks for a new version. Every load looks too.
+const UPDATE_CHECK_MS: u32 = 60 * 60 * 1_000;
+/// Tells a waiting service worker to take over, see `sw.js`.
+const SKIP_WAITING: &str = "skip_waiting";
+
+/// Registers the service worker, and offers to reload once a new version of the app is ready.
+/// Put it once near the root of the app.
+///
+/// Only release builds register it: `trunk serve` rebuilds all the time, and a cache would only
+/// be in the way. Try it with `trunk serve --release`.
+#[component]
+pub fn UpdateBanner() -> impl IntoView {
+    // the service worker of the new version, installed and waiting for the old one to let go
+    let waiting = create_rw_signal(None::<ServiceWorker>);
+    let switching = store_value(false);
+    let dismissed = create_rw_signal(false);
+    if !cfg!(debug_assertions) {
+        register(waiting, switching);
+    }
+
+    let reload = move |_| {
+        if let Some(worker) = waiting.get_untracked() {
+            // the page reloads once the new version has taken over
+            switching.set_value(true);
+            _ = worker.post_message(&JsValue::from_str(SKIP_WAITING));
+        }
+    };
+
+    view! {
+        <Show when=move || waiting.with(Option::is_some) && !dismissed.get()>
+            <div style="position: fixed; bottom: 1rem; right: 1rem; z-index: 100; width: 22rem;">
+                <Notification color=Color::Info on_close=move |_| dismissed.set(true)>
+                    <p class="mb-2">{t!("update-available")}</p>
+                    <BButton size=BSize::Small on:click=reload>
+                        {t!("update-reload")}
+                    </BButton>
+                </Notification>
+            </div>
+        </Show>
+    }
+}
+
+fn register(waiting: RwSignal<Option<ServiceWorker>>, switching: StoredValue<bool>) {
+    let navigator = window().navigator();
+    // only secure pages have one, and localhost
+    if !js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        return;
+    }
+    let container = navigator.service_worker();
+
+    // the very first service worker takes over too, but that one needs no reload
+    let on_controller_change = Closure::<dyn FnMut()>::new(move || {
+        if switching.get_value() {
+            _ = window().location().reload();
+        }
+    });
+    container.set_oncontrollerchange(Some(on_controller_change.as_ref().unchecked_ref()));
+    // the handlers in here live as long as the page
+    on_controller_change.forget();
+
+    spawn_local(async move {
+        let registration = match JsFuture::from(container.register(SERVICE_WORKER_URL)).await {
+            Ok(registration) => registration.unchecked_into::<ServiceWorkerRegistration>(),
+            Err(error) => {
+                logging::warn!("no service worker: {error:?}");
+                return;
+            }
+        };
+        // a new version found on an earlier visit may be waiting already
+        if let Some(worker) = registration.waiting() {
+            waiting.set(Some(worker));
+        }
+
+        let found = registration.clone();
+        let on_update_found =
+            Closure::<dyn FnMut()>::new(move || watch_installing(&found, waiting));
+        registration.set_onupdatefound(Some(on_update_found.as_ref().unchecked_ref()));
+        on_update_found.forget();
+
+        Interval::new(UPDATE_CHECK_MS, move || {
+            _ = registration.update();
+        })
+        .forget();
+    });
+}
+
+/// Offers the service worker being installed once it is done, unless it is the first one.
+fn watch_installing(
+    registration: &ServiceWorkerRegistration,
+    waiting: RwSignal<Option<ServiceWorker>>,
+) {
+    let Some(worker) = registration.installing() else {
+        return;
+    };
+    let installed = worker.clone();
+    let on_state_change = Closure::<dyn FnMut()>::new(move || {
+        // without a controller the page has no version yet, so this one is no update
+        let has_controller = window().navigator().service_worker().controller().is_some();
+        if installed.state() == ServiceWorkerState::Installed && has_controller {
+            waiting.set(Some(installed.clone()));
+        }
+    });
+    worker.set_onstatechange(Some(on_state_change.as_ref().unchecked_ref()));
+    // one per new version, of which a page sees few
+    on_state_change.forget();
+}
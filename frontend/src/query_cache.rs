@@ -0,0 +1,467 @@
+use crate::bulma::{BBox, BButton, BSize};
+use crate::i18n::t;
+use futures::future::{LocalBoxFuture, Shared};
+use futures::FutureExt;
+use gloo_timers::callback::Interval;
+use leptos::*;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::rc::Rc;
+
+/// How long fetched data counts as fresh. Stale data is still shown, but refetched in the background.
+const STALE_MS: f64 = 10_000.0;
+/// How long fetched data is kept at all. Older entries are dropped and fetched from scratch.
+const EXPIRE_MS: f64 = 60_000.0;
+
+type CachedValue = Rc<dyn Any>;
+type InFlight = Shared<LocalBoxFuture<'static, CachedValue>>;
+/// When the cached value of a key was fetched and whether it was invalidated since.
+pub type Version = Option<(f64, bool)>;
+
+struct CacheEntry {
+    value: Option<CachedValue>,
+    fetched_at: f64,
+    invalidated: bool,
+    // counts the `invalidate` calls, so a request can tell whether one came while it loaded
+    generation: u64,
+    // every query asking for this key while it loads awaits the same future
+    in_flight: Option<InFlight>,
+}
+
+impl CacheEntry {
+    fn invalidate(&mut self) {
+        self.invalidated = true;
+        self.generation += 1;
+    }
+
+    /// Stores what a request started at `generation` loaded. It stays stale if the entry was
+    /// invalidated since. A value not worth `keep`ing leaves the entry as it was.
+    fn finish(&mut self, value: CachedValue, keep: bool, now: f64, generation: u64) {
+        self.in_flight = None;
+        if !keep {
+            return;
+        }
+        self.value = Some(value);
+        self.fetched_at = now;
+        self.invalidated = self.generation != generation;
+    }
+
+    /// Whether the value was fetched more than `expire_ms` before `now`.
+    fn expired(&self, now: f64, expire_ms: f64) -> bool {
+        now - self.fetched_at > expire_ms
+    }
+
+    /// The value at `now`, none once it expired.
+    fn value_at(&self, now: f64, expire_ms: f64) -> Option<&CachedValue> {
+        self.value
+            .as_ref()
+            .filter(|_| !self.expired(now, expire_ms))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryStatus {
+    Fetching,
+    Fresh,
+    Stale,
+}
+
+impl EntryStatus {
+    fn label(self) -> Signal<String> {
+        match self {
+            EntryStatus::Fetching => t!("query-fetching"),
+            EntryStatus::Fresh => t!("query-fresh"),
+            EntryStatus::Stale => t!("query-stale"),
+        }
+    }
+}
+
+/// A cache of async data by key, shared by every `create_query` below the `App`.
+///
+/// Queries with the same key share one request and one result. Data older than
+/// `stale_ms` is shown while it is refetched; data older than `expire_ms` is dropped.
+#[derive(Clone, Copy)]
+pub struct QueryClient {
+    entries: RwSignal<HashMap<String, CacheEntry>>,
+    stale_ms: f64,
+    expire_ms: f64,
+}
+
+impl QueryClient {
+    pub fn new(stale_ms: f64, expire_ms: f64) -> Self {
+        QueryClient {
+            entries: create_rw_signal(HashMap::new()),
+            stale_ms,
+            expire_ms,
+        }
+    }
+
+    /// Marks the entry `prefix` and every entry below it as stale, e.g. `"user"` marks
+    /// `"user/1"` but not `"users/1"`, and `""` marks them all. Queries showing one refetch it.
+    ///
+    /// Call it after an action changed the data behind those keys. A request loading one of
+    /// them right now may have read the old data, so its result comes in stale too.
+    pub fn invalidate(&self, prefix: &str) {
+        self.entries.update(|entries| {
+            for (_, entry) in entries.iter_mut().filter(|(key, _)| is_below(key, prefix)) {
+                entry.invalidate();
+            }
+        });
+    }
+
+    /// Whether a request for `key` is on its way, including a background refresh.
+    pub fn is_fetching(&self, key: &str) -> bool {
+        self.entries.with(|entries| {
+            entries
+                .get(key)
+                .is_some_and(|entry| entry.in_flight.is_some())
+        })
+    }
+
+    /// Changes whenever the value stored for `key` is replaced or invalidated.
+    fn version(&self, key: &str) -> Version {
+        self.entries.with(|entries| {
+            let entry = entries.get(key).filter(|entry| entry.value.is_some())?;
+            Some((entry.fetched_at, entry.invalidated))
+        })
+    }
+
+    /// The cached value for `key`, and whether it is still fresh. An expired value counts as
+    /// none, even before the next request drops it.
+    fn cached<T: Clone + 'static>(&self, key: &str) -> Option<(T, bool)> {
+        let now = js_sys::Date::now();
+        self.entries.with_untracked(|entries| {
+            let entry = entries.get(key)?;
+            let value = entry
+                .value_at(now, self.expire_ms)?
+                .downcast_ref::<T>()?
+                .clone();
+            Some((value, self.status(entry, now) == EntryStatus::Fresh))
+        })
+    }
+
+    fn status(&self, entry: &CacheEntry, now: f64) -> EntryStatus {
+        if entry.in_flight.is_some() {
+            EntryStatus::Fetching
+        } else if entry.invalidated || now - entry.fetched_at > self.stale_ms {
+            EntryStatus::Stale
+        } else {
+            EntryStatus::Fresh
+        }
+    }
+
+    /// Starts loading `key`, or joins the request already loading it. The result is only
+    /// stored if it is worth `keep`ing.
+    fn fetch<Fut>(
+        &self,
+        key: &str,
+        load: impl FnOnce() -> Fut,
+        keep: impl Fn(&CachedValue) -> bool + 'static,
+    ) -> InFlight
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static,
+    {
+        if let Some(in_flight) = self
+            .entries
+            .with_untracked(|entries| entries.get(key)?.in_flight.clone())
+        {
+            return in_flight;
+        }
+
+        let in_flight = load()
+            .map(|value| Rc::new(value) as CachedValue)
+            .boxed_local()
+            .shared();
+        let generation = self.entries.try_update(|entries| {
+            let entry = entries.entry(key.to_string()).or_insert(CacheEntry {
+                value: None,
+                fetched_at: f64::NEG_INFINITY,
+                invalidated: false,
+                generation: 0,
+                in_flight: None,
+            });
+            entry.in_flight = Some(in_flight.clone());
+            entry.generation
+        });
+        let generation = generation.unwrap_or_default();
+
+        let client = *self;
+        let key = key.to_string();
+        let request = in_flight.clone();
+        spawn_local(async move {
+            let value = request.await;
+            // the app may be gone by the time the request comes back
+            client.entries.try_update(|entries| {
+                let now = js_sys::Date::now();
+                let entry = entries.entry(key).or_insert(CacheEntry {
+                    value: None,
+                    fetched_at: now,
+                    invalidated: false,
+                    generation,
+                    in_flight: None,
+                });
+                let keep = keep(&value);
+                entry.finish(value, keep, now, generation);
+                entries.retain(|_, entry| {
+                    entry.in_flight.is_some() || !entry.expired(now, client.expire_ms)
+                });
+            });
+        });
+        in_flight
+    }
+}
+
+/// Whether `key` is `prefix` or below it, going by `/`-separated segments.
+fn is_below(key: &str, prefix: &str) -> bool {
+    prefix.is_empty()
+        || key
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+impl Default for QueryClient {
+    fn default() -> Self {
+        QueryClient::new(STALE_MS, EXPIRE_MS)
+    }
+}
+
+pub fn use_query_client() -> QueryClient {
+    use_context::<QueryClient>().expect("QueryClient provided")
+}
+
+/// A `create_local_resource` whose results live in the `QueryClient` under `"{name}/{key}"`.
+///
+/// Going back to a key that was already loaded resolves at once from the cache. Stale data
+/// resolves at once too, while it is refetched in the background; the resource then updates
+/// with the fresh data.
+///
+/// Only successful results are cached. A failed load is shown to the queries waiting for it,
+/// then forgotten, so refetching the resource tries again. If a background refresh fails, the
+/// cached data stays.
+pub fn create_query<K, T, E, Fut>(
+    name: &'static str,
+    key: impl Fn() -> K + 'static,
+    fetcher: impl Fn(K) -> Fut + 'static,
+) -> Resource<(K, Version), Result<T, E>>
+where
+    K: Display + Clone + PartialEq + 'static,
+    T: Clone + 'static,
+    E: Clone + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+{
+    let client = use_query_client();
+    let key = create_memo(move |_| key());
+    // re-runs the resource when the cached value changes, e.g. after a refresh or `invalidate`
+    let version = create_memo(move |_| key.with(|key| client.version(&format!("{name}/{key}"))));
+    let fetcher = Rc::new(fetcher);
+
+    create_local_resource(
+        move || (key.get(), version.get()),
+        move |(key, _)| {
+            let cache_key = format!("{name}/{key}");
+            let fetcher = Rc::clone(&fetcher);
+            let load = move || fetcher(key);
+            let keep = |value: &CachedValue| {
+                value
+                    .downcast_ref::<Result<T, E>>()
+                    .is_some_and(Result::is_ok)
+            };
+            async move {
+                match client.cached::<Result<T, E>>(&cache_key) {
+                    Some((value, true)) => value,
+                    Some((value, false)) => {
+                        // stale while revalidate: show it now, the refresh updates `version`
+                        _ = client.fetch(&cache_key, load, keep);
+                        value
+                    }
+                    None => {
+                        let value = client.fetch(&cache_key, load, keep).await;
+                        value
+                            .downcast_ref::<Result<T, E>>()
+                            .expect("one type per query name")
+                            .clone()
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Lists the entries of the `QueryClient`, with their age and status.
+#[component]
+pub fn QueryDevtools() -> impl IntoView {
+    let client = use_query_client();
+    // ages and statuses change with time alone, so re-read the clock every second
+    let (now, set_now) = create_signal(js_sys::Date::now());
+    let ticker = store_value(Some(Interval::new(1_000, move || {
+        set_now(js_sys::Date::now())
+    })));
+    on_cleanup(move || ticker.set_value(None));
+
+    let rows = move || {
+        let now = now();
+        client.entries.with(|entries| {
+            let mut rows = entries
+                .iter()
+                .map(|(key, entry)| {
+                    let age = (entry.value.is_some()).then(|| (now - entry.fetched_at) / 1_000.0);
+                    (key.clone(), client.status(entry, now), age)
+                })
+                .collect::<Vec<_>>();
+            rows.sort_by(|a, b| a.0.cmp(&b.0));
+            rows
+        })
+    };
+
+    view! {
+        <BBox>
+            <details>
+                <summary>{t!("query-cache")}</summary>
+                <table class="table is-narrow is-fullwidth">
+                    <thead>
+                        <tr>
+                            <th>{t!("query-key")}</th>
+                            <th>{t!("query-status")}</th>
+                            <th>{t!("query-age")}</th>
+                            <th></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            rows()
+                                .into_iter()
+                                .map(|(key, status, age)| {
+                                    let invalidate_key = key.clone();
+                                    view! {
+                                        <tr>
+                                            <td>
+                                                <code>{key}</code>
+                                            </td>
+                                            <td>{status.label()}</td>
+                                            <td>
+                                                {match age {
+                                                    Some(seconds) => {
+                                                        t!("query-age-seconds", seconds = seconds)
+                                                            .into_view()
+                                                    }
+                                                    None => "-".into_view(),
+                                                }}
+                                            </td>
+                                            <td>
+                                                <BButton
+                                                    size=BSize::Small
+                                                    on:click=move |_| client.invalidate(&invalidate_key)
+                                                >
+                                                    {t!("query-invalidate")}
+                                                </BButton>
+                                            </td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+                <BButton size=BSize::Small on:click=move |_| client.invalidate("")>
+                    {t!("query-invalidate-all")}
+                </BButton>
+            </details>
+        </BBox>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalidates_whole_segments() {
+        assert!(is_below("user/1", "user"));
+        assert!(is_below("user/1", "user/1"));
+        assert!(is_below("user/1/posts", "user/1"));
+        assert!(!is_below("users/1", "user"));
+        assert!(!is_below("user/10", "user/1"));
+        assert!(!is_below("user", "user/1"));
+    }
+
+    #[test]
+    fn empty_prefix_invalidates_everything() {
+        assert!(is_below("todos", ""));
+        assert!(is_below("user/1", ""));
+    }
+
+    fn entry() -> CacheEntry {
+        CacheEntry {
+            value: None,
+            fetched_at: f64::NEG_INFINITY,
+            invalidated: true,
+            generation: 0,
+            in_flight: None,
+        }
+    }
+
+    #[test]
+    fn a_finished_request_is_fresh() {
+        let mut entry = entry();
+        let generation = entry.generation;
+        entry.finish(Rc::new(1), true, 5.0, generation);
+        assert!(!entry.invalidated);
+        assert_eq!(entry.fetched_at, 5.0);
+    }
+
+    #[test]
+    fn invalidating_during_a_request_keeps_its_result_stale() {
+        let mut entry = entry();
+        let generation = entry.generation;
+        entry.invalidate();
+        entry.finish(Rc::new(1), true, 5.0, generation);
+        assert!(entry.invalidated);
+        assert!(entry.value.is_some());
+
+        // the refetch that follows started after the invalidation, so its result is fresh
+        let generation = entry.generation;
+        entry.finish(Rc::new(2), true, 6.0, generation);
+        assert!(!entry.invalidated);
+    }
+
+    #[test]
+    fn a_result_not_kept_leaves_the_entry_as_it_was() {
+        let mut entry = entry();
+        let generation = entry.generation;
+        entry.finish(Rc::new(1), true, 5.0, generation);
+        entry.invalidate();
+
+        let generation = entry.generation;
+        entry.in_flight = Some(async { Rc::new(2) as CachedValue }.boxed_local().shared());
+        entry.finish(Rc::new(2), false, 6.0, generation);
+        assert!(entry.in_flight.is_none());
+        assert_eq!(
+            entry.value.as_ref().and_then(|value| value.downcast_ref()),
+            Some(&1)
+        );
+        assert_eq!(entry.fetched_at, 5.0);
+        assert!(entry.invalidated);
+    }
+
+    #[test]
+    fn an_expired_value_counts_as_none() {
+        let mut entry = entry();
+        let generation = entry.generation;
+        entry.finish(Rc::new(1), true, 5.0, generation);
+        assert!(entry.value_at(65.0, 60.0).is_some());
+        assert!(!entry.expired(65.0, 60.0));
+
+        assert!(entry.value_at(65.5, 60.0).is_none());
+        assert!(entry.expired(65.5, 60.0));
+        // the value itself stays until the entry is dropped
+        assert!(entry.value.is_some());
+    }
+
+    #[test]
+    fn a_value_never_fetched_is_none() {
+        assert!(entry().value_at(0.0, 60.0).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::timing::create_debounced_callback;
+use leptos::*;
+use leptos_router::{use_location, use_navigate, NavigateOptions};
+use std::str::FromStr;
+
+/// How a query signal writes back to the URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryOptions {
+    /// Replace the current history entry instead of pushing one, so Back skips over the edits.
+    pub replace: bool,
+    /// Wait until writes have stopped for this long before touching the URL.
+    pub debounce_ms: u32,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        QueryOptions {
+            replace: true,
+            debounce_ms: 0,
+        }
+    }
+}
+
+/// A typed query parameter as a signal: `?name=...` in, `?name=...` out.
+///
+/// A missing or unparsable parameter reads as `T::default()`, and setting the default
+/// removes the parameter, so URLs only carry what differs from the defaults.
+pub fn create_query_signal<T>(name: &'static str) -> (Signal<T>, SignalSetter<T>)
+where
+    T: FromStr + ToString + Default + Clone + PartialEq + 'static,
+{
+    create_query_signal_with_options(name, QueryOptions::default())
+}
+
+pub fn create_query_signal_with_options<T>(
+    name: &'static str,
+    options: QueryOptions,
+) -> (Signal<T>, SignalSetter<T>)
+where
+    T: FromStr + ToString + Default + Clone + PartialEq + 'static,
+{
+    let location = use_location();
+    let navigate = use_navigate();
+
+    let from_url = create_memo(move |_| {
+        location.query.with(|query| {
+            query
+                .get(name)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or_default()
+        })
+    });
+    // what the page shows; it runs ahead of the URL while a debounced write is pending
+    let value = create_rw_signal(from_url.get_untracked());
+    // the URL changed on its own, e.g. Back or a pasted link
+    create_effect(move |_| {
+        let from_url = from_url.get();
+        if value.with_untracked(|value| *value != from_url) {
+            value.set(from_url);
+        }
+    });
+
+    let write_url = create_debounced_callback(options.debounce_ms, move |new_value: T| {
+        let mut query = location.query.get_untracked();
+        if new_value == T::default() {
+            query.remove(name);
+        } else {
+            query.insert(name.to_string(), new_value.to_string());
+        }
+        let url = format!(
+            "{}{}{}",
+            location.pathname.get_untracked(),
+            query.to_query_string(),
+            location.hash.get_untracked()
+        );
+        navigate(
+            &url,
+            NavigateOptions {
+                replace: options.replace,
+                scroll: false,
+                ..Default::default()
+            },
+        );
+    });
+
+    let set = SignalSetter::map(move |new_value: T| {
+        value.set(new_value.clone());
+        write_url(new_value);
+    });
+
+    (value.into(), set)
+}
@@ -0,0 +1,186 @@
+use crate::i18n::t;
+use leptos::ev::{KeyboardEvent, PointerEvent};
+use leptos::html::Button;
+use leptos::*;
+use uuid::Uuid;
+
+/// Moves the item at `from` so that it ends up where the item at `to` was.
+pub fn move_item<T>(items: &mut Vec<T>, from: usize, to: usize) {
+    if from == to || from >= items.len() || to >= items.len() {
+        return;
+    }
+    let item = items.remove(from);
+    items.insert(to, item);
+}
+
+/// Drag state shared by the rows of one reorderable `<For/>` list.
+///
+/// Each row carries `reorder.row_id()` as `data-reorder-list` and its index as
+/// `data-reorder-index`, and contains a `ReorderHandle`. The list itself is only changed
+/// through `on_move(from, to)`, so rows keep their keys, and with them their state.
+#[derive(Clone, Copy)]
+pub struct Reorder {
+    list: StoredValue<String>,
+    dragging: RwSignal<Option<usize>>,
+    // the row under the pointer, and whether the pointer is in its lower half
+    target: RwSignal<Option<(usize, bool)>>,
+    on_move: Callback<(usize, usize)>,
+}
+
+impl Reorder {
+    pub fn new(on_move: impl Fn((usize, usize)) + 'static) -> Self {
+        Reorder {
+            list: store_value(Uuid::new_v4().to_string()),
+            dragging: create_rw_signal(None),
+            target: create_rw_signal(None),
+            on_move: Callback::new(on_move),
+        }
+    }
+
+    /// Tells this list's rows apart from the rows of any other list on the page.
+    pub fn row_id(self) -> String {
+        self.list.get_value()
+    }
+
+    pub fn is_dragging(self, index: usize) -> bool {
+        self.dragging.get() == Some(index)
+    }
+
+    /// Whether the drop indicator goes above (`false`) or below (`true`) the row at `index`.
+    pub fn indicator(self, index: usize) -> Option<bool> {
+        let from = self.dragging.get()?;
+        let (target, below) = self.target.get().filter(|(target, _)| *target == index)?;
+        // dropping a row right next to itself wouldn't move it
+        (self.destination(from, target, below) != from).then_some(below)
+    }
+
+    /// The `style` for the row at `index`, showing where the dragged row would land.
+    pub fn row_style(self, index: Signal<usize>) -> impl Fn() -> String {
+        move || {
+            let index = index();
+            let mut style = String::from(
+                "border-top: 2px solid transparent; border-bottom: 2px solid transparent;",
+            );
+            match self.indicator(index) {
+                Some(false) => style.push_str("border-top-color: #485fc7;"),
+                Some(true) => style.push_str("border-bottom-color: #485fc7;"),
+                None => {}
+            }
+            if self.is_dragging(index) {
+                style.push_str("opacity: 0.5;");
+            }
+            style
+        }
+    }
+
+    fn destination(self, from: usize, target: usize, below: bool) -> usize {
+        let before = if below { target + 1 } else { target };
+        if before > from {
+            before - 1
+        } else {
+            before
+        }
+    }
+
+    fn start(self, index: usize, ev: &PointerEvent) {
+        if ev.button() != 0 {
+            return;
+        }
+        // keep receiving the pointer events even once it leaves the handle
+        let handle = event_target::<web_sys::Element>(ev);
+        _ = handle.set_pointer_capture(ev.pointer_id());
+        self.dragging.set(Some(index));
+        self.target.set(None);
+    }
+
+    fn track(self, ev: &PointerEvent) {
+        if self.dragging.get_untracked().is_none() {
+            return;
+        }
+        let (x, y) = (ev.client_x() as f32, ev.client_y() as f32);
+        let row = document()
+            .element_from_point(x, y)
+            .and_then(|element| element.closest("[data-reorder-index]").ok().flatten())
+            .filter(|row| row.get_attribute("data-reorder-list") == Some(self.row_id()));
+        let target = row.and_then(|row| {
+            let index = row.get_attribute("data-reorder-index")?.parse().ok()?;
+            let rect = row.get_bounding_client_rect();
+            Some((index, f64::from(y) > rect.top() + rect.height() / 2.0))
+        });
+        if target.is_some() {
+            self.target.set(target);
+        }
+    }
+
+    fn finish(self) {
+        let dragging = self.dragging.get_untracked();
+        let target = self.target.get_untracked();
+        self.dragging.set(None);
+        self.target.set(None);
+        if let (Some(from), Some((target, below))) = (dragging, target) {
+            let to = self.destination(from, target, below);
+            if to != from {
+                (self.on_move)((from, to));
+            }
+        }
+    }
+
+    fn cancel(self) {
+        self.dragging.set(None);
+        self.target.set(None);
+    }
+}
+
+/// The grip of a reorderable row: drag it with a mouse or a finger, or focus it and use
+/// the up and down arrow keys.
+#[component]
+pub fn ReorderHandle(
+    reorder: Reorder,
+    /// The row's current position in the list.
+    #[prop(into)]
+    index: Signal<usize>,
+    /// The length of the list, so the keyboard can't move a row past its end.
+    #[prop(into)]
+    len: Signal<usize>,
+) -> impl IntoView {
+    let handle = create_node_ref::<Button>();
+
+    let on_keydown = move |ev: KeyboardEvent| {
+        let from = index.get_untracked();
+        let to = match ev.key().as_str() {
+            "ArrowUp" if from > 0 => from - 1,
+            "ArrowDown" if from + 1 < len.get_untracked() => from + 1,
+            "Escape" => {
+                reorder.cancel();
+                return;
+            }
+            _ => return,
+        };
+        ev.prevent_default();
+        (reorder.on_move)((from, to));
+        // moving the row moves its DOM node, which takes the focus away from it
+        request_animation_frame(move || {
+            if let Some(handle) = handle.get_untracked() {
+                _ = handle.focus();
+            }
+        });
+    };
+
+    view! {
+        <button
+            node_ref=handle
+            type="button"
+            class="button is-small is-white"
+            style="cursor: grab; touch-action: none;"
+            title=t!("reorder-hint")
+            aria-label=t!("reorder-move", row = index() + 1, rows = len())
+            on:pointerdown=move |ev| reorder.start(index.get_untracked(), &ev)
+            on:pointermove=move |ev| reorder.track(&ev)
+            on:pointerup=move |_| reorder.finish()
+            on:pointercancel=move |_| reorder.cancel()
+            on:keydown=on_keydown
+        >
+            "☰"
+        </button>
+    }
+}
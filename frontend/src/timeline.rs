@@ -0,0 +1,254 @@
+use crate::bulma::{
+    BBox, BButton, BColor, BSize, BTag, BTags, Column, ColumnSize, Columns, ColumnsBreakpoint,
+};
+use crate::i18n::t;
+use gloo_timers::callback::Interval;
+use leptos::*;
+
+/// How often a running timeline is redrawn.
+const REDRAW_MS: u32 = 100;
+
+/// What a lane of the timeline records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// A resource is loading.
+    Loading,
+    /// The user sees a loading fallback instead of the content.
+    Fallback,
+    /// The user still sees the previous content while new data loads.
+    Stale,
+}
+
+impl Phase {
+    const ALL: [Phase; 3] = [Phase::Loading, Phase::Fallback, Phase::Stale];
+
+    fn color(self) -> BColor {
+        match self {
+            Phase::Loading => BColor::Info,
+            Phase::Fallback => BColor::Warning,
+            Phase::Stale => BColor::Success,
+        }
+    }
+
+    fn background(self) -> &'static str {
+        match self {
+            Phase::Loading => "has-background-info",
+            Phase::Fallback => "has-background-warning",
+            Phase::Stale => "has-background-success",
+        }
+    }
+
+    fn label(self) -> Signal<String> {
+        match self {
+            Phase::Loading => t!("timeline-loading"),
+            Phase::Fallback => t!("timeline-fallback"),
+            Phase::Stale => t!("timeline-stale"),
+        }
+    }
+}
+
+struct Lane {
+    name: &'static str,
+    phase: Phase,
+    // start and end in ms since the origin; the last span has no end while it is running
+    spans: Vec<(f64, Option<f64>)>,
+}
+
+/// Records when things start and stop, to draw them with `TimelineChart`.
+#[derive(Clone, Copy)]
+pub struct Timeline {
+    lanes: RwSignal<Vec<Lane>>,
+    origin: RwSignal<f64>,
+}
+
+pub fn create_timeline() -> Timeline {
+    Timeline {
+        lanes: create_rw_signal(Vec::new()),
+        origin: create_rw_signal(js_sys::Date::now()),
+    }
+}
+
+impl Timeline {
+    /// Adds a lane named `name` with a span for every stretch of time `active` is true.
+    pub fn track(&self, name: &'static str, phase: Phase, active: impl Fn() -> bool + 'static) {
+        let lanes = self.lanes;
+        let origin = self.origin;
+        let index = lanes.with_untracked(Vec::len);
+        lanes.update(|lanes| {
+            lanes.push(Lane {
+                name,
+                phase,
+                spans: Vec::new(),
+            })
+        });
+
+        create_effect(move |was_active: Option<bool>| {
+            let active = active();
+            if was_active != Some(active) {
+                let now = js_sys::Date::now() - origin.get_untracked();
+                lanes.update(|lanes| {
+                    let spans = &mut lanes[index].spans;
+                    if active {
+                        spans.push((now, None));
+                    } else if let Some((_, end @ None)) = spans.last_mut() {
+                        *end = Some(now);
+                    }
+                });
+            }
+            active
+        });
+    }
+
+    /// Adds a lane for the loads of `resource`.
+    pub fn track_resource<S, T>(&self, name: &'static str, resource: Resource<S, T>)
+    where
+        S: Clone + 'static,
+        T: 'static,
+    {
+        let loading = resource.loading();
+        self.track(name, Phase::Loading, move || loading.get());
+    }
+
+    /// Drops everything recorded so far and starts over from now. Running spans carry on.
+    pub fn clear(&self) {
+        self.origin.set(js_sys::Date::now());
+        self.lanes.update(|lanes| {
+            for lane in lanes {
+                let running = matches!(lane.spans.last(), Some((_, None)));
+                lane.spans.clear();
+                if running {
+                    lane.spans.push((0.0, None));
+                }
+            }
+        });
+    }
+}
+
+/// Records what the `<Suspense/>` or `<Transition/>` it is placed in shows while it waits.
+///
+/// Place it among the children. A suspense shows its fallback every time; a transition only
+/// the first time, and keeps the previous content after that.
+#[component]
+pub fn SuspenseProbe(
+    timeline: Timeline,
+    name: &'static str,
+    #[prop(optional)] transition: bool,
+) -> impl IntoView {
+    let Some(suspense) = use_context::<SuspenseContext>() else {
+        return;
+    };
+    let ready = suspense.ready();
+    // whether the content was shown before, so a transition keeps it
+    let was_ready = store_value(false);
+    create_effect(move |_| {
+        if ready.get() {
+            was_ready.set_value(true);
+        }
+    });
+
+    if transition {
+        timeline.track(name, Phase::Fallback, move || {
+            !ready.get() && !was_ready.get_value()
+        });
+        timeline.track(name, Phase::Stale, move || {
+            !ready.get() && was_ready.get_value()
+        });
+    } else {
+        timeline.track(name, Phase::Fallback, move || !ready.get());
+    }
+}
+
+/// Draws a `Timeline` as a Gantt chart, one row per lane.
+#[component]
+pub fn TimelineChart(timeline: Timeline) -> impl IntoView {
+    let (now, set_now) = create_signal(js_sys::Date::now());
+    let running = move || {
+        timeline.lanes.with(|lanes| {
+            lanes
+                .iter()
+                .any(|lane| matches!(lane.spans.last(), Some((_, None))))
+        })
+    };
+    // only running spans grow with time alone
+    let ticker = store_value(Some(Interval::new(REDRAW_MS, move || {
+        if running() {
+            set_now(js_sys::Date::now());
+        }
+    })));
+    on_cleanup(move || ticker.set_value(None));
+
+    // the chart spans the whole recording, and at least a second
+    let elapsed = move || (now() - timeline.origin.get()).max(1_000.0);
+    let percent = move |ms: f64| format!("{:.2}%", ms / elapsed() * 100.0);
+
+    let rows = move || {
+        timeline.lanes.with(|lanes| {
+            lanes
+                .iter()
+                .map(|lane| {
+                    let spans = lane
+                        .spans
+                        .iter()
+                        .map(|&(start, end)| {
+                            let end = end.unwrap_or_else(|| now() - timeline.origin.get());
+                            let phase = lane.phase.label();
+                            let title = t!(
+                                "timeline-span",
+                                phase = phase.get(),
+                                start = start,
+                                end = end,
+                                duration = end - start,
+                            );
+                            view! {
+                                <div
+                                    class=lane.phase.background()
+                                    title=title
+                                    style:left=percent(start)
+                                    style:width=percent((end - start).max(elapsed() / 200.0))
+                                    style="position: absolute; top: 0; bottom: 0;"
+                                ></div>
+                            }
+                        })
+                        .collect_view();
+                    let name = lane.name;
+                    view! {
+                        <Columns breakpoint=ColumnsBreakpoint::Mobile gapless=true class="mb-1">
+                            <Column size=ColumnSize::Is3 class="is-size-7">{name}</Column>
+                            <Column class="has-background-light">
+                                <div style="position: relative; height: 1.25rem;">{spans}</div>
+                            </Column>
+                        </Columns>
+                    }
+                })
+                .collect_view()
+        })
+    };
+
+    view! {
+        <BBox>
+            <div class="is-flex is-justify-content-space-between is-align-items-center mb-2">
+                <BTags class="mb-0">
+                    {Phase::ALL
+                        .into_iter()
+                        .map(|phase| view! { <BTag color=phase.color()>{phase.label()}</BTag> })
+                        .collect_view()}
+                </BTags>
+                <BButton size=BSize::Small on:click=move |_| timeline.clear()>
+                    {t!("timeline-clear")}
+                </BButton>
+            </div>
+            {rows}
+            <Columns
+                breakpoint=ColumnsBreakpoint::Mobile
+                gapless=true
+                class="is-size-7 has-text-grey"
+            >
+                <Column size=ColumnSize::Is3>""</Column>
+                <Column>{t!("timeline-seconds", seconds = 0)}</Column>
+                <Column class="has-text-right">
+                    {t!("timeline-seconds", seconds = elapsed() / 1_000.0)}
+                </Column>
+            </Columns>
+        </BBox>
+    }
+}
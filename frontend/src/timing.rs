@@ -0,0 +1,294 @@
+use gloo_timers::callback::Timeout;
+use leptos::*;
+
+/// A one-shot timer that is cancelled when dropped. The browser's `Timeout` is the real one;
+/// the tests run the same code on a fake clock.
+trait Timer: 'static {
+    fn start(ms: u32, callback: impl FnOnce() + 'static) -> Self;
+}
+
+impl Timer for Timeout {
+    fn start(ms: u32, callback: impl FnOnce() + 'static) -> Self {
+        Timeout::new(ms, callback)
+    }
+}
+
+/// A signal that follows `source` once it has stopped changing for `ms`.
+///
+/// Use it for work that shouldn't run on every keystroke, e.g. as the source of a resource.
+/// A pending update is dropped with the owner, so it never fires after the component is gone.
+pub fn debounced<T>(source: impl Into<Signal<T>>, ms: u32) -> Signal<T>
+where
+    T: Clone + 'static,
+{
+    debounced_with::<Timeout, T>(source.into(), ms)
+}
+
+fn debounced_with<Tm: Timer, T: Clone + 'static>(source: Signal<T>, ms: u32) -> Signal<T> {
+    let settled = create_rw_signal(source.get_untracked());
+    let update = debounced_callback_with::<Tm, T>(ms, move |value: T| settled.set(value));
+
+    create_effect(move |first_run: Option<()>| {
+        let value = source.get();
+        // the initial value is already there
+        if first_run.is_some() {
+            update(value);
+        }
+    });
+
+    settled.into()
+}
+
+/// A signal that follows `source` at most once every `ms`.
+///
+/// The first change goes through at once and the last change of a burst is never lost, so the
+/// signal ends up at the same value as `source`. Use it for continuous input like scrolling.
+pub fn throttled<T>(source: impl Into<Signal<T>>, ms: u32) -> Signal<T>
+where
+    T: Clone + 'static,
+{
+    throttled_with::<Timeout, T>(source.into(), ms)
+}
+
+fn throttled_with<Tm: Timer, T: Clone + 'static>(source: Signal<T>, ms: u32) -> Signal<T> {
+    let throttled = create_rw_signal(source.get_untracked());
+    // the timer of the current window, and the latest value that arrived during it
+    let window = store_value(None::<Tm>);
+    let latest = store_value(None::<T>);
+    on_cleanup(move || window.set_value(None));
+
+    fn open_window<Tm: Timer, T: Clone + 'static>(
+        throttled: RwSignal<T>,
+        window: StoredValue<Option<Tm>>,
+        latest: StoredValue<Option<T>>,
+        ms: u32,
+        value: T,
+    ) {
+        throttled.set(value);
+        window.set_value(Some(Tm::start(ms, move || {
+            window.set_value(None);
+            if let Some(value) = latest.try_update_value(Option::take).flatten() {
+                open_window(throttled, window, latest, ms, value);
+            }
+        })));
+    }
+
+    create_effect(move |first_run: Option<()>| {
+        let value = source.get();
+        if first_run.is_none() {
+            return;
+        }
+        if window.with_value(Option::is_some) {
+            latest.set_value(Some(value));
+        } else {
+            open_window(throttled, window, latest, ms, value);
+        }
+    });
+
+    throttled.into()
+}
+
+/// Wraps `callback` so it only runs once calls have stopped for `ms`, with the last argument.
+///
+/// Calling it again before then restarts the wait. A pending call is dropped with the owner.
+pub fn create_debounced_callback<T: 'static>(
+    ms: u32,
+    callback: impl Fn(T) + 'static,
+) -> Callback<T> {
+    debounced_callback_with::<Timeout, T>(ms, callback)
+}
+
+fn debounced_callback_with<Tm: Timer, T: 'static>(
+    ms: u32,
+    callback: impl Fn(T) + 'static,
+) -> Callback<T> {
+    let callback = Callback::new(callback);
+    if ms == 0 {
+        return callback;
+    }
+    let pending = store_value(None::<Tm>);
+    on_cleanup(move || {
+        // dropping a `Timeout` cancels it
+        pending.set_value(None);
+    });
+    Callback::new(move |value: T| {
+        let timeout = Tm::start(ms, move || callback(value));
+        pending.set_value(Some(timeout));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    struct Pending {
+        due: u32,
+        cancelled: Rc<Cell<bool>>,
+        callback: Box<dyn FnOnce()>,
+    }
+
+    thread_local! {
+        static NOW: Cell<u32> = const { Cell::new(0) };
+        static PENDING: RefCell<Vec<Pending>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// A timer on a clock that only moves when a test calls `advance`.
+    struct FakeTimer(Rc<Cell<bool>>);
+
+    impl Timer for FakeTimer {
+        fn start(ms: u32, callback: impl FnOnce() + 'static) -> Self {
+            let cancelled = Rc::new(Cell::new(false));
+            PENDING.with_borrow_mut(|pending| {
+                pending.push(Pending {
+                    due: NOW.get() + ms,
+                    cancelled: cancelled.clone(),
+                    callback: Box::new(callback),
+                })
+            });
+            FakeTimer(cancelled)
+        }
+    }
+
+    impl Drop for FakeTimer {
+        fn drop(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    /// Moves the clock `ms` on, firing the timers that come due in the order they do.
+    fn advance(ms: u32) {
+        let until = NOW.get() + ms;
+        loop {
+            let next = PENDING.with_borrow_mut(|pending| {
+                pending.retain(|timer| !timer.cancelled.get());
+                let index = (0..pending.len())
+                    .filter(|&index| pending[index].due <= until)
+                    .min_by_key(|&index| pending[index].due)?;
+                Some(pending.remove(index))
+            });
+            let Some(timer) = next else {
+                break;
+            };
+            NOW.set(timer.due);
+            (timer.callback)();
+        }
+        NOW.set(until);
+    }
+
+    fn run(test: impl FnOnce()) {
+        NOW.set(0);
+        PENDING.with_borrow_mut(Vec::clear);
+        let runtime = create_runtime();
+        test();
+        runtime.dispose();
+    }
+
+    fn recorder() -> (Rc<RefCell<Vec<u32>>>, impl Fn(u32) + 'static) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let record = {
+            let calls = calls.clone();
+            move |value| calls.borrow_mut().push(value)
+        };
+        (calls, record)
+    }
+
+    #[test]
+    fn debounced_callback_runs_once_calls_stop() {
+        run(|| {
+            let (calls, record) = recorder();
+            let callback = debounced_callback_with::<FakeTimer, _>(100, record);
+            callback(1);
+            advance(60);
+            callback(2);
+            advance(60);
+            assert!(calls.borrow().is_empty());
+            advance(40);
+            assert_eq!(*calls.borrow(), [2]);
+            advance(1000);
+            assert_eq!(*calls.borrow(), [2]);
+        });
+    }
+
+    #[test]
+    fn debounced_callback_without_a_wait_runs_at_once() {
+        run(|| {
+            let (calls, record) = recorder();
+            let callback = debounced_callback_with::<FakeTimer, _>(0, record);
+            callback(1);
+            callback(2);
+            assert_eq!(*calls.borrow(), [1, 2]);
+        });
+    }
+
+    #[test]
+    fn debounced_callback_is_dropped_with_its_owner() {
+        run(|| {
+            let (calls, record) = recorder();
+            let record = Rc::new(record);
+            // a child scope that goes away on its own, as a component's does
+            let (callback, disposer) = as_child_of_current_owner(move |_| {
+                let record = record.clone();
+                debounced_callback_with::<FakeTimer, _>(100, move |value| record(value))
+            })(());
+            callback(1);
+            drop(disposer);
+            advance(1000);
+            assert!(calls.borrow().is_empty());
+        });
+    }
+
+    #[test]
+    fn debounced_signal_settles_on_the_last_value() {
+        run(|| {
+            let source = create_rw_signal(0);
+            let settled = debounced_with::<FakeTimer, _>(source.into(), 100);
+            assert_eq!(settled.get_untracked(), 0);
+            for value in 1..=3 {
+                source.set(value);
+                advance(50);
+            }
+            assert_eq!(settled.get_untracked(), 0);
+            advance(50);
+            assert_eq!(settled.get_untracked(), 3);
+        });
+    }
+
+    #[test]
+    fn throttled_signal_lets_the_first_change_through_and_keeps_the_last() {
+        run(|| {
+            let source = create_rw_signal(0);
+            let throttled = throttled_with::<FakeTimer, _>(source.into(), 100);
+            source.set(1);
+            assert_eq!(throttled.get_untracked(), 1);
+            source.set(2);
+            advance(30);
+            source.set(3);
+            assert_eq!(throttled.get_untracked(), 1);
+            advance(70);
+            assert_eq!(throttled.get_untracked(), 3);
+            // nothing came during the window 3 opened, so once it closes a change goes through
+            advance(100);
+            source.set(4);
+            assert_eq!(throttled.get_untracked(), 4);
+        });
+    }
+
+    #[test]
+    fn throttled_signal_follows_at_most_once_per_window() {
+        run(|| {
+            let source = create_rw_signal(0);
+            let throttled = throttled_with::<FakeTimer, _>(source.into(), 100);
+            let (seen, record) = recorder();
+            create_effect(move |_| record(throttled.get()));
+            for value in 1..=20 {
+                source.set(value);
+                advance(25);
+            }
+            advance(1000);
+            // the initial value, then one value per 100ms of the 500ms burst, ending on the last
+            assert_eq!(*seen.borrow(), [0, 1, 4, 8, 12, 16, 20]);
+        });
+    }
+}
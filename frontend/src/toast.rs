@@ -0,0 +1,122 @@
+use crate::bulma::{Color, Notification};
+use gloo_timers::callback::Timeout;
+use leptos::*;
+
+/// How many toasts can be on screen at once. Older ones are dropped first.
+const MAX_VISIBLE_TOASTS: usize = 5;
+/// How long a toast stays before it dismisses itself.
+const AUTO_DISMISS_MS: u32 = 4_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl NotificationLevel {
+    pub fn color(self) -> Color {
+        match self {
+            NotificationLevel::Info => Color::Info,
+            NotificationLevel::Success => Color::Success,
+            NotificationLevel::Warning => Color::Warning,
+            NotificationLevel::Danger => Color::Danger,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Toast {
+    id: usize,
+    level: NotificationLevel,
+    message: String,
+}
+
+/// The notification service. It is `Copy`, so grab it once with `use_notifier()`
+/// while the component is set up and call `notify` from event handlers or effects.
+#[derive(Clone, Copy)]
+pub struct Notifier {
+    toasts: RwSignal<Vec<Toast>>,
+    next_id: StoredValue<usize>,
+    max_visible: usize,
+    auto_dismiss_ms: u32,
+}
+
+impl Notifier {
+    pub fn new(max_visible: usize, auto_dismiss_ms: u32) -> Self {
+        Notifier {
+            toasts: create_rw_signal(Vec::new()),
+            next_id: store_value(0),
+            max_visible,
+            auto_dismiss_ms,
+        }
+    }
+
+    pub fn notify(&self, level: NotificationLevel, message: impl Into<String>) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+
+        let max_visible = self.max_visible;
+        self.toasts.update(|toasts| {
+            toasts.push(Toast {
+                id,
+                level,
+                message: message.into(),
+            });
+            // drop the oldest toasts once we are over the cap
+            if toasts.len() > max_visible {
+                let overflow = toasts.len() - max_visible;
+                toasts.drain(..overflow);
+            }
+        });
+
+        // the timer doesn't care whether the toast is still there:
+        // dismissing an id that was already closed is a no-op
+        let notifier = *self;
+        Timeout::new(self.auto_dismiss_ms, move || notifier.dismiss(id)).forget();
+    }
+
+    pub fn dismiss(&self, id: usize) {
+        // `try_update` because the app may have been torn down before the timer fires
+        self.toasts
+            .try_update(|toasts| toasts.retain(|toast| toast.id != id));
+    }
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Notifier::new(MAX_VISIBLE_TOASTS, AUTO_DISMISS_MS)
+    }
+}
+
+pub fn use_notifier() -> Notifier {
+    use_context::<Notifier>().expect("Notifier provided")
+}
+
+/// Renders the toasts of the `Notifier` found in context.
+/// Put it once near the root of the app.
+#[component]
+pub fn NotificationStack() -> impl IntoView {
+    let notifier = use_notifier();
+
+    view! {
+        <div style="position: fixed; top: 1rem; right: 1rem; z-index: 100; width: 22rem;">
+            <For
+                each=move || notifier.toasts.get()
+                key=|toast| toast.id
+                children=move |toast| {
+                    view! {
+                        <Notification
+                            color=toast.level.color()
+                            on_close=move |_| notifier.dismiss(toast.id)
+                        >
+                            {toast.message}
+                        </Notification>
+                    }
+                }
+            />
+
+        </div>
+    }
+}
@@ -0,0 +1,347 @@
+use leptos::html::Div;
+use leptos::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where every row starts, so a scroll position can be turned into a range of rows.
+#[derive(Debug, Clone, PartialEq)]
+enum RowOffsets {
+    /// Every row has the same height: positions are computed, nothing is stored.
+    Fixed { row_height: f64, len: usize },
+    /// The row heights in a Fenwick tree, so one height changes and one position is found in
+    /// O(log n), however long the list.
+    Measured(HeightTree),
+}
+
+impl RowOffsets {
+    fn len(&self) -> usize {
+        match self {
+            RowOffsets::Fixed { len, .. } => *len,
+            RowOffsets::Measured(tree) => tree.heights.len(),
+        }
+    }
+
+    fn top(&self, index: usize) -> f64 {
+        match self {
+            RowOffsets::Fixed { row_height, .. } => index as f64 * row_height,
+            RowOffsets::Measured(tree) => tree.sum_before(index),
+        }
+    }
+
+    fn total_height(&self) -> f64 {
+        self.top(self.len())
+    }
+
+    /// The row that covers the vertical position `y`.
+    fn index_at(&self, y: f64) -> usize {
+        let index = match self {
+            RowOffsets::Fixed { row_height, .. } => (y / row_height).max(0.0) as usize,
+            RowOffsets::Measured(tree) => tree.rows_above(y),
+        };
+        index.min(self.len().saturating_sub(1))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct HeightTree {
+    heights: Vec<f64>,
+    // 1-based: entry `i` sums the `i & -i` heights ending with row `i - 1`
+    sums: Vec<f64>,
+}
+
+impl HeightTree {
+    fn new(heights: Vec<f64>) -> Self {
+        let mut sums = vec![0.0; heights.len() + 1];
+        sums[1..].copy_from_slice(&heights);
+        for i in 1..sums.len() {
+            let parent = i + lowest_bit(i);
+            if parent < sums.len() {
+                sums[parent] += sums[i];
+            }
+        }
+        HeightTree { heights, sums }
+    }
+
+    fn set(&mut self, index: usize, height: f64) {
+        let delta = height - self.heights[index];
+        self.heights[index] = height;
+        let mut i = index + 1;
+        while i < self.sums.len() {
+            self.sums[i] += delta;
+            i += lowest_bit(i);
+        }
+    }
+
+    /// The height of the rows before `index`, which is where row `index` starts.
+    fn sum_before(&self, index: usize) -> f64 {
+        let mut sum = 0.0;
+        let mut i = index;
+        while i > 0 {
+            sum += self.sums[i];
+            i -= lowest_bit(i);
+        }
+        sum
+    }
+
+    /// How many rows end at or above `y`.
+    fn rows_above(&self, y: f64) -> usize {
+        let mut rows = 0;
+        let mut remaining = y;
+        let mut step = (self.sums.len() - 1)
+            .checked_next_power_of_two()
+            .unwrap_or(0);
+        while step > 0 {
+            if rows + step < self.sums.len() && self.sums[rows + step] <= remaining {
+                rows += step;
+                remaining -= self.sums[rows];
+            }
+            step /= 2;
+        }
+        rows
+    }
+}
+
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+/// A keyed list like `<For/>` that only mounts the rows inside the scrolled viewport.
+///
+/// Rows keep their identity through `key` exactly as with `<For/>`, so a row that stays
+/// visible while the list changes is not re-rendered.
+#[component]
+pub fn VirtualList<T, K, KF, CF, N>(
+    /// The whole collection. It is only cloned for the rows in view.
+    #[prop(into)]
+    items: Signal<Vec<T>>,
+    /// Unique and stable per row, as for `<For/>`.
+    key: KF,
+    /// Renders one row.
+    children: CF,
+    /// Height of a row in pixels. With `measure` it is only the estimate for rows never rendered.
+    row_height: f64,
+    /// Measure each row once it is mounted, for rows whose height depends on their content.
+    #[prop(optional)]
+    measure: bool,
+    /// Height of the scrolling viewport in pixels.
+    #[prop(default = 400.0)]
+    height: f64,
+    /// Extra rows mounted above and below the viewport, so fast scrolling doesn't show gaps.
+    #[prop(default = 5)]
+    overscan: usize,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    CF: Fn(T) -> N + 'static,
+    N: IntoView + 'static,
+{
+    let (scroll_top, set_scroll_top) = create_signal(0.0);
+    // heights of the rows measured so far, by key, so they survive the row being unmounted
+    let measured = store_value(HashMap::<K, f64>::new());
+    // the index of every row by key, for a measured row to find its place in `offsets`
+    let indices = store_value(HashMap::<K, usize>::new());
+    let offsets = create_rw_signal(RowOffsets::Fixed { row_height, len: 0 });
+
+    // only a change of the items goes through all of them; a measured row updates its own height
+    create_render_effect({
+        let key = key.clone();
+        move |_| {
+            if !measure {
+                offsets.set(RowOffsets::Fixed {
+                    row_height,
+                    len: items.with(Vec::len),
+                });
+                return;
+            }
+            items.with(|items| {
+                let keys = items.iter().map(&key).collect::<Vec<_>>();
+                let heights = measured.with_value(|measured| {
+                    keys.iter()
+                        .map(|key| measured.get(key).copied().unwrap_or(row_height))
+                        .collect()
+                });
+                indices.set_value(
+                    keys.into_iter()
+                        .enumerate()
+                        .map(|(i, key)| (key, i))
+                        .collect(),
+                );
+                offsets.set(RowOffsets::Measured(HeightTree::new(heights)));
+            });
+        }
+    });
+
+    // only this memo depends on the scroll position: it changes when a row enters or leaves
+    let visible_range = create_memo(move |_| {
+        offsets.with(|offsets| {
+            if offsets.len() == 0 {
+                return (0, 0);
+            }
+            let first = offsets.index_at(scroll_top());
+            let last = offsets.index_at(scroll_top() + height);
+            (
+                first.saturating_sub(overscan),
+                (last + 1 + overscan).min(offsets.len()),
+            )
+        })
+    });
+
+    let visible_items = move || {
+        let (start, end) = visible_range();
+        items.with(|items| items[start..end].to_vec())
+    };
+    let rows_top = move || offsets.with(|offsets| offsets.top(visible_range().0));
+    let total_height = move || offsets.with(RowOffsets::total_height);
+
+    let row_key = key.clone();
+    let render_row = move |item: T| {
+        if !measure {
+            return view! {
+                <div style:height=format!("{row_height}px") style="overflow: hidden;">
+                    {children(item)}
+                </div>
+            };
+        }
+
+        let row_ref = create_node_ref::<Div>();
+        let item_key = key(&item);
+        row_ref.on_load(move |row| {
+            // wait for layout, otherwise the row still reports a height of 0
+            request_animation_frame(move || {
+                let row_height = row.offset_height() as f64;
+                // the list may be gone by the next frame
+                let Some(previous) = measured
+                    .try_update_value(|measured| measured.insert(item_key.clone(), row_height))
+                else {
+                    return;
+                };
+                if previous == Some(row_height) {
+                    return;
+                }
+                if let Some(index) = indices
+                    .try_with_value(|indices| indices.get(&item_key).copied())
+                    .flatten()
+                {
+                    offsets.update(|offsets| {
+                        if let RowOffsets::Measured(tree) = offsets {
+                            tree.set(index, row_height);
+                        }
+                    });
+                }
+            });
+        });
+        view! { <div node_ref=row_ref>{children(item)}</div> }
+    };
+
+    view! {
+        <div
+            style:height=format!("{height}px")
+            style="overflow-y: auto; position: relative;"
+            on:scroll=move |ev| {
+                set_scroll_top(event_target::<web_sys::Element>(&ev).scroll_top() as f64)
+            }
+        >
+
+            // the spacer has the height of the whole list, so the scrollbar behaves as if every row were there
+            <div style:height=move || format!("{}px", total_height()) style="position: relative;">
+                <div
+                    style="position: absolute; top: 0; left: 0; right: 0;"
+                    style:transform=move || format!("translateY({}px)", rows_top())
+                >
+                    <For each=visible_items key=row_key children=render_row/>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measured(heights: &[f64]) -> RowOffsets {
+        RowOffsets::Measured(HeightTree::new(heights.to_vec()))
+    }
+
+    #[test]
+    fn fixed_rows() {
+        let offsets = RowOffsets::Fixed {
+            row_height: 20.0,
+            len: 10,
+        };
+        assert_eq!(offsets.top(3), 60.0);
+        assert_eq!(offsets.total_height(), 200.0);
+        assert_eq!(offsets.index_at(0.0), 0);
+        assert_eq!(offsets.index_at(59.9), 2);
+        assert_eq!(offsets.index_at(60.0), 3);
+        assert_eq!(offsets.index_at(-5.0), 0);
+        assert_eq!(offsets.index_at(10_000.0), 9);
+    }
+
+    #[test]
+    fn measured_rows() {
+        let offsets = measured(&[10.0, 30.0, 20.0, 40.0, 5.0]);
+        let tops = [0.0, 10.0, 40.0, 60.0, 100.0, 105.0];
+        for (index, top) in tops.into_iter().enumerate() {
+            assert_eq!(offsets.top(index), top, "top of row {index}");
+        }
+        assert_eq!(offsets.total_height(), 105.0);
+        assert_eq!(offsets.index_at(0.0), 0);
+        assert_eq!(offsets.index_at(9.9), 0);
+        assert_eq!(offsets.index_at(10.0), 1);
+        assert_eq!(offsets.index_at(39.9), 1);
+        assert_eq!(offsets.index_at(40.0), 2);
+        assert_eq!(offsets.index_at(104.0), 4);
+        assert_eq!(offsets.index_at(1_000.0), 4);
+    }
+
+    #[test]
+    fn zero_height_rows_are_skipped() {
+        let offsets = measured(&[10.0, 0.0, 0.0, 10.0]);
+        assert_eq!(offsets.index_at(10.0), 3);
+    }
+
+    #[test]
+    fn empty_list() {
+        let offsets = measured(&[]);
+        assert_eq!(offsets.total_height(), 0.0);
+        assert_eq!(offsets.index_at(50.0), 0);
+    }
+
+    #[test]
+    fn changed_heights_match_a_rebuild() {
+        // heights and changes from a small linear congruential generator, so the test is repeatable
+        let mut seed = 7_u64;
+        let mut next = move || {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            seed >> 33
+        };
+        let mut heights = (0..100).map(|_| (next() % 50) as f64).collect::<Vec<_>>();
+        let mut tree = HeightTree::new(heights.clone());
+        for _ in 0..200 {
+            let index = next() as usize % heights.len();
+            let height = (next() % 50) as f64;
+            heights[index] = height;
+            tree.set(index, height);
+        }
+
+        let rebuilt = HeightTree::new(heights.clone());
+        let mut top = 0.0;
+        for (index, height) in heights.iter().enumerate() {
+            assert_eq!(tree.sum_before(index), top);
+            assert_eq!(rebuilt.sum_before(index), top);
+            top += height;
+        }
+        let offsets = RowOffsets::Measured(tree);
+        for y in (0..top as usize).step_by(7) {
+            let y = y as f64;
+            let expected = (0..heights.len())
+                .rev()
+                .find(|index| offsets.top(*index) <= y)
+                .unwrap();
+            assert_eq!(offsets.index_at(y), expected, "row at {y}");
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::bulma::{BColor, BTag};
+use crate::fallible::{ApiError, RetryPolicy};
+use crate::i18n::t;
+use gloo_timers::callback::Timeout;
+use leptos::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use web_sys::{CloseEvent, MessageEvent, WebSocket};
+
+/// How a lost connection is retried. After the last retry the socket stays `Closed`.
+pub const RECONNECT_POLICY: RetryPolicy = RetryPolicy {
+    retries: 10,
+    base_delay_ms: 500,
+    max_delay_ms: 10_000,
+    jitter: 0.5,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connecting,
+    Open,
+    /// The connection was lost or couldn't be made; retry number `attempt` is coming up.
+    Reconnecting {
+        attempt: u32,
+    },
+    /// Given up on. `reconnect` starts over.
+    Closed,
+}
+
+/// Shows a `ConnectionState` as a tag.
+#[component]
+pub fn ConnectionStateTag(#[prop(into)] state: Signal<ConnectionState>) -> impl IntoView {
+    move || {
+        let (color, label) = match state.get() {
+            ConnectionState::Connecting => (BColor::Info, t!("socket-connecting")),
+            ConnectionState::Open => (BColor::Success, t!("socket-connected")),
+            ConnectionState::Reconnecting { attempt } => (
+                BColor::Warning,
+                t!("socket-reconnecting", attempt = attempt),
+            ),
+            ConnectionState::Closed => (BColor::Danger, t!("socket-disconnected")),
+        };
+        view! { <BTag color>{label}</BTag> }
+    }
+}
+
+struct Connection {
+    socket: WebSocket,
+    // the handlers must live as long as the socket may call them
+    _on_open: Closure<dyn FnMut()>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        _ = self.socket.close();
+    }
+}
+
+/// A WebSocket carrying JSON: `In` is what the server sends, `Out` what is sent to it.
+///
+/// It reconnects on its own when the connection drops, and closes with its owner.
+pub struct Socket<In: 'static, Out: 'static> {
+    url: StoredValue<String>,
+    policy: RetryPolicy,
+    state: RwSignal<ConnectionState>,
+    connection: StoredValue<Option<Connection>>,
+    retry: StoredValue<Option<Timeout>>,
+    failed_attempts: StoredValue<u32>,
+    on_message: StoredValue<Rc<dyn Fn(In)>>,
+    _out: PhantomData<fn(Out)>,
+}
+
+impl<In, Out> Clone for Socket<In, Out> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<In, Out> Copy for Socket<In, Out> {}
+
+/// Connects to `url`, calling `on_message` with every message that parses as `In`.
+pub fn create_socket<In, Out>(
+    url: impl Into<String>,
+    policy: RetryPolicy,
+    on_message: impl Fn(In) + 'static,
+) -> Socket<In, Out>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+{
+    let socket = Socket {
+        url: store_value(url.into()),
+        policy,
+        state: create_rw_signal(ConnectionState::Connecting),
+        connection: store_value(None),
+        retry: store_value(None),
+        failed_attempts: store_value(0),
+        on_message: store_value(Rc::new(on_message) as Rc<dyn Fn(In)>),
+        _out: PhantomData,
+    };
+    on_cleanup(move || {
+        // dropping them cancels the retry and closes the socket without a reconnect
+        socket.retry.set_value(None);
+        socket.connection.set_value(None);
+    });
+    socket.connect();
+    socket
+}
+
+impl<In, Out> Socket<In, Out>
+where
+    In: DeserializeOwned + 'static,
+    Out: Serialize + 'static,
+{
+    pub fn state(&self) -> Signal<ConnectionState> {
+        self.state.into()
+    }
+
+    /// Sends `message`, if the connection is open.
+    pub fn send(&self, message: &Out) -> Result<(), ApiError> {
+        if self.state.get_untracked() != ConnectionState::Open {
+            return Err(ApiError::Offline);
+        }
+        let json = serde_json::to_string(message).expect("messages serialize");
+        self.connection.with_value(|connection| match connection {
+            Some(connection) => connection
+                .socket
+                .send_with_str(&json)
+                .map_err(|_| ApiError::Offline),
+            None => Err(ApiError::Offline),
+        })
+    }
+
+    /// Connects again right away, e.g. after the socket gave up.
+    pub fn reconnect(&self) {
+        self.retry.set_value(None);
+        self.failed_attempts.set_value(0);
+        self.state.set(ConnectionState::Connecting);
+        self.connect();
+    }
+
+    fn connect(self) {
+        let socket = match WebSocket::new(&self.url.get_value()) {
+            Ok(socket) => socket,
+            Err(_) => {
+                self.retry_later();
+                return;
+            }
+        };
+
+        let on_open = Closure::<dyn FnMut()>::new(move || {
+            self.failed_attempts.set_value(0);
+            self.state.set(ConnectionState::Open);
+        });
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let Some(text) = event.data().as_string() else {
+                return;
+            };
+            match serde_json::from_str::<In>(&text) {
+                Ok(message) => self.on_message.with_value(|on_message| on_message(message)),
+                Err(error) => logging::warn!("ignoring message {text:?}: {error}"),
+            }
+        });
+        // also called when the connection couldn't be made at all
+        let on_close = Closure::<dyn FnMut(CloseEvent)>::new(move |_| self.retry_later());
+
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        self.connection.set_value(Some(Connection {
+            socket,
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_close: on_close,
+        }));
+    }
+
+    fn retry_later(self) {
+        let attempt = self.failed_attempts.get_value();
+        if attempt >= self.policy.retries {
+            self.state.set(ConnectionState::Closed);
+            return;
+        }
+        self.failed_attempts.set_value(attempt + 1);
+        self.state.set(ConnectionState::Reconnecting {
+            attempt: attempt + 1,
+        });
+        // connect from the timer, not from the handler of the old socket, which this replaces
+        let timeout = Timeout::new(self.policy.delay_ms(attempt), move || self.connect());
+        self.retry.set_value(Some(timeout));
+    }
+}
@@ -0,0 +1,393 @@
+//! Runs CPU-heavy work in a web worker, so the page stays responsive meanwhile.
+
+pub mod tasks;
+
+use crate::i18n::t;
+use futures::channel::oneshot;
+use futures::future::{FutureExt, LocalBoxFuture};
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use tasks::{Encoded, Request, Response, Task};
+use wasm_bindgen::prelude::*;
+use web_sys::{MessageEvent, Worker};
+
+/// The script Trunk writes to start the worker, see the `data-type="worker"` link in `index.html`.
+const WORKER_URL: &str = "/worker_loader.js";
+
+/// Why a task didn't finish.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkerError {
+    /// The worker couldn't be started, or a task panicked in it.
+    Crashed,
+    /// `cancel` or `cancel_all` stopped the task, or the worker's owner is gone.
+    Cancelled,
+    /// The worker didn't understand the request.
+    Rejected(String),
+}
+
+impl WorkerError {
+    /// What went wrong, in the current locale. `Display` is for logs.
+    pub fn message(&self) -> Signal<String> {
+        match self {
+            WorkerError::Crashed => t!("worker-crashed"),
+            WorkerError::Cancelled => t!("worker-cancelled"),
+            WorkerError::Rejected(error) => {
+                let error = error.clone();
+                t!("worker-rejected", error = error.as_str())
+            }
+        }
+    }
+}
+
+impl fmt::Display for WorkerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkerError::Crashed => write!(f, "the worker crashed"),
+            WorkerError::Cancelled => write!(f, "the task was cancelled"),
+            WorkerError::Rejected(error) => write!(f, "the worker rejected the task: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for WorkerError {}
+
+/// Tells the tasks of a `TaskWorker` apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskId(u32);
+
+/// A task `TaskWorker::run` started. Await it for the output, or pass its `id` to
+/// `TaskWorker::cancel`.
+pub struct TaskRun<O> {
+    id: TaskId,
+    output: LocalBoxFuture<'static, Result<O, WorkerError>>,
+}
+
+impl<O> TaskRun<O> {
+    pub fn id(&self) -> TaskId {
+        self.id
+    }
+}
+
+impl<O> Future for TaskRun<O> {
+    type Output = Result<O, WorkerError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.output.poll_unpin(cx)
+    }
+}
+
+struct Pending {
+    on_progress: Rc<dyn Fn(TaskId, f64)>,
+    done: oneshot::Sender<Result<Encoded, WorkerError>>,
+}
+
+struct Spawned {
+    worker: Worker,
+    // the handlers must live as long as the worker may call them
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut()>,
+}
+
+impl Drop for Spawned {
+    fn drop(&mut self) {
+        self.worker.set_onmessage(None);
+        self.worker.set_onerror(None);
+        self.worker.terminate();
+    }
+}
+
+/// A web worker running `Task`s one after the other, in the order they were asked for.
+///
+/// The worker is handed one task at a time, the others wait here. So a task can be cancelled
+/// while it waits, and while it runs too, by starting over with a new worker for the tasks after
+/// it. The worker is terminated with its owner.
+#[derive(Clone, Copy)]
+pub struct TaskWorker {
+    spawned: StoredValue<Option<Spawned>>,
+    /// `false` after a crash, until the next task starts a new worker.
+    alive: StoredValue<bool>,
+    /// Whether the worker has started and can take tasks.
+    ready: StoredValue<bool>,
+    /// The requests after the running one.
+    queue: StoredValue<VecDeque<(TaskId, String)>>,
+    running: StoredValue<Option<TaskId>>,
+    pending: StoredValue<HashMap<TaskId, Pending>>,
+    next_id: StoredValue<u32>,
+}
+
+pub fn create_task_worker() -> TaskWorker {
+    let worker = TaskWorker {
+        spawned: store_value(None),
+        alive: store_value(false),
+        ready: store_value(false),
+        queue: store_value(VecDeque::new()),
+        running: store_value(None),
+        pending: store_value(HashMap::new()),
+        next_id: store_value(0),
+    };
+    on_cleanup(move || worker.spawned.set_value(None));
+    worker.spawn();
+    worker
+}
+
+/// A resource `worker` computes from `source`, and how much of its computation is done, 0.0 to
+/// 1.0.
+///
+/// A new computation cancels the one it supersedes, so the worker doesn't spend its time on an
+/// output nobody will see, and only the progress of the latest one shows.
+#[allow(clippy::type_complexity)]
+pub fn create_worker_resource<S, T>(
+    worker: TaskWorker,
+    source: impl Fn() -> S + 'static,
+    task: impl Fn(S) -> T + 'static,
+) -> (
+    Resource<S, Result<T::Output, WorkerError>>,
+    Signal<Option<f64>>,
+)
+where
+    S: Clone + PartialEq + 'static,
+    T: Task + 'static,
+    T::Output: 'static,
+{
+    let current = create_rw_signal(None::<TaskId>);
+    // tagged with the task it is about
+    let progress = create_rw_signal(None::<(TaskId, f64)>);
+    on_cleanup(move || {
+        if let Some(id) = current.try_get_untracked().flatten() {
+            worker.cancel(id);
+        }
+    });
+
+    let resource = create_local_resource(source, move |source| {
+        if let Some(previous) = current.get_untracked() {
+            worker.cancel(previous);
+        }
+        let run = worker.run(task(source), move |id, done| {
+            progress.try_set(Some((id, done)));
+        });
+        current.set(Some(run.id()));
+        progress.set(Some((run.id(), 0.0)));
+        run
+    });
+    let progress = Signal::derive(move || {
+        let current = current.get()?;
+        progress
+            .get()
+            .filter(|(id, _)| *id == current)
+            .map(|(_, done)| done)
+    });
+    (resource, progress)
+}
+
+impl TaskWorker {
+    /// Runs `task` in the worker, calling `on_progress` with how much of it is done, 0.0 to 1.0,
+    /// while it runs.
+    ///
+    /// Dropping the `TaskRun` doesn't stop the task, its output is just thrown away; `cancel`
+    /// does stop it.
+    pub fn run<T: Task>(
+        &self,
+        task: T,
+        on_progress: impl Fn(TaskId, f64) + 'static,
+    ) -> TaskRun<T::Output> {
+        if !self.alive.get_value() {
+            self.spawn();
+        }
+        let id = TaskId(self.next_id.get_value());
+        self.next_id.set_value(id.0.wrapping_add(1));
+        if !self.alive.get_value() {
+            // the worker couldn't be started, so nothing would ever finish the task
+            return TaskRun {
+                id,
+                output: async { Err(WorkerError::Crashed) }.boxed_local(),
+            };
+        }
+
+        let (done, finished) = oneshot::channel();
+        self.pending.update_value(|pending| {
+            pending.insert(
+                id,
+                Pending {
+                    on_progress: Rc::new(on_progress),
+                    done,
+                },
+            );
+        });
+        let request = Request {
+            id: id.0,
+            task: T::NAME.to_string(),
+            input: serde_json::to_string(&task).expect("tasks serialize"),
+        };
+        let json = serde_json::to_string(&request).expect("requests serialize");
+        self.queue.update_value(|queue| queue.push_back((id, json)));
+        self.start_next();
+
+        let output = async move {
+            // a dropped sender means the worker's owner is gone
+            let output = finished.await.unwrap_or(Err(WorkerError::Cancelled))?;
+            T::decode(output).map_err(WorkerError::Rejected)
+        };
+        TaskRun {
+            id,
+            output: output.boxed_local(),
+        }
+    }
+
+    /// Fails the task `id` with `WorkerError::Cancelled`, unless it is done already.
+    ///
+    /// A waiting task is just dropped. A running one can't be interrupted, so the worker is
+    /// terminated with it, and a new one takes the tasks after it.
+    pub fn cancel(&self, id: TaskId) {
+        let running = self.running.try_get_value().flatten() == Some(id);
+        self.queue
+            .try_update_value(|queue| queue.retain(|(queued, _)| *queued != id));
+        self.settle(id, Err(WorkerError::Cancelled));
+        // unless the worker is gone with its owner already
+        if running && self.spawned.try_with_value(Option::is_some) == Some(true) {
+            self.spawn();
+        }
+    }
+
+    /// Fails every task, running or waiting, with `WorkerError::Cancelled`, and starts over with
+    /// a new worker.
+    pub fn cancel_all(&self) {
+        self.fail_all(WorkerError::Cancelled);
+        self.spawn();
+    }
+
+    fn spawn(self) {
+        // dropping the old worker terminates it, along with the task it was running
+        self.spawned.set_value(None);
+        self.alive.set_value(false);
+        self.ready.set_value(false);
+        self.running.set_value(None);
+        let worker = match Worker::new(WORKER_URL) {
+            Ok(worker) => worker,
+            Err(_) => {
+                self.fail_all(WorkerError::Crashed);
+                return;
+            }
+        };
+
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let data = event.data();
+            // a task's bytes come as `[response, buffer]`, everything else as JSON text
+            let (text, bytes) = match data.dyn_ref::<js_sys::Array>() {
+                Some(parts) => (
+                    parts.get(0).as_string(),
+                    Some(js_sys::Uint8Array::new(&parts.get(1)).to_vec()),
+                ),
+                None => (data.as_string(), None),
+            };
+            let Some(text) = text else {
+                return;
+            };
+            match serde_json::from_str::<Response>(&text) {
+                Ok(response) => self.receive(response, bytes),
+                Err(error) => logging::warn!("ignoring worker message {text:?}: {error}"),
+            }
+        });
+        // a task panicked, or the worker script didn't load; either way the worker is done for
+        let on_error = Closure::<dyn FnMut()>::new(move || {
+            self.alive.set_value(false);
+            self.fail_all(WorkerError::Crashed);
+        });
+
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        self.alive.set_value(true);
+        self.spawned.set_value(Some(Spawned {
+            worker,
+            _on_message: on_message,
+            _on_error: on_error,
+        }));
+    }
+
+    /// Hands the worker the next task, if it is ready and done with the previous one.
+    fn start_next(self) {
+        let free =
+            self.ready.try_get_value() == Some(true) && self.running.try_get_value() == Some(None);
+        if !free {
+            return;
+        }
+        let Some((id, json)) = self.queue.try_update_value(VecDeque::pop_front).flatten() else {
+            return;
+        };
+        self.running.set_value(Some(id));
+        self.spawned.with_value(|spawned| {
+            if let Some(spawned) = spawned {
+                _ = spawned.worker.post_message(&JsValue::from_str(&json));
+            }
+        });
+    }
+
+    fn receive(self, response: Response, bytes: Option<Vec<u8>>) {
+        match response {
+            Response::Ready => {
+                self.ready.set_value(true);
+                self.start_next();
+            }
+            Response::Progress { id, done } => {
+                let id = TaskId(id);
+                // not while borrowed: the callback may well start another task
+                let on_progress = self.pending.try_with_value(|pending| {
+                    pending
+                        .get(&id)
+                        .filter(|task| !task.done.is_canceled())
+                        .map(|task| task.on_progress.clone())
+                });
+                if let Some(on_progress) = on_progress.flatten() {
+                    on_progress(id, done);
+                }
+            }
+            Response::Done { id, output } => self.finish(TaskId(id), Ok(Encoded::Json(output))),
+            Response::DoneBytes { id } => {
+                let output = bytes.map(Encoded::Bytes).ok_or_else(|| {
+                    WorkerError::Rejected("the output came without its bytes".to_string())
+                });
+                self.finish(TaskId(id), output);
+            }
+            Response::Failed { id, error } => {
+                self.finish(TaskId(id), Err(WorkerError::Rejected(error)))
+            }
+        }
+    }
+
+    /// Settles the running task, and moves on to the next.
+    fn finish(self, id: TaskId, result: Result<Encoded, WorkerError>) {
+        if self.running.try_get_value() == Some(Some(id)) {
+            self.running.set_value(None);
+        }
+        self.settle(id, result);
+        self.start_next();
+    }
+
+    fn settle(self, id: TaskId, result: Result<Encoded, WorkerError>) {
+        let task = self
+            .pending
+            .try_update_value(|pending| pending.remove(&id))
+            .flatten();
+        if let Some(task) = task {
+            // nobody waiting any more is fine
+            _ = task.done.send(result);
+        }
+    }
+
+    fn fail_all(self, error: WorkerError) {
+        self.queue.try_update_value(VecDeque::clear);
+        self.running.try_update_value(|running| *running = None);
+        let pending = self
+            .pending
+            .try_update_value(std::mem::take)
+            .unwrap_or_default();
+        for (_, task) in pending {
+            _ = task.done.send(Err(error.clone()));
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! What the worker can do, and the messages to ask it with.
+//!
+//! Shared by the app and the worker itself, `src/bin/worker.rs`, which includes this file as a
+//! module of its own. So it only uses `serde` and the standard library.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Work for the worker: something slow enough to freeze the page if run on it.
+pub trait Task: Serialize + DeserializeOwned {
+    /// Tells the tasks apart in requests.
+    const NAME: &'static str;
+    type Output;
+
+    /// Does the work, calling `progress` with how much of it is done, 0.0 to 1.0, along the way.
+    fn run(self, progress: &mut dyn FnMut(f64)) -> Self::Output;
+
+    /// The output, ready to go back to the app. Only the worker calls it.
+    #[allow(dead_code)]
+    fn encode(output: Self::Output) -> Encoded;
+
+    /// Only the app calls it.
+    #[allow(dead_code)]
+    fn decode(encoded: Encoded) -> Result<Self::Output, String>;
+}
+
+/// The output of a task on its way back to the app.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Encoded {
+    Json(String),
+    /// Sent in an `ArrayBuffer` that is transferred, not copied, and never parsed: for large
+    /// outputs like images.
+    Bytes(Vec<u8>),
+}
+
+impl Encoded {
+    pub fn json<T: Serialize>(value: &T) -> Self {
+        Encoded::Json(serde_json::to_string(value).expect("task outputs serialize"))
+    }
+
+    pub fn parse_json<T: DeserializeOwned>(self) -> Result<T, String> {
+        match self {
+            Encoded::Json(json) => serde_json::from_str(&json).map_err(|error| error.to_string()),
+            Encoded::Bytes(_) => Err("expected JSON, got bytes".to_string()),
+        }
+    }
+
+    pub fn into_bytes(self) -> Result<Vec<u8>, String> {
+        match self {
+            Encoded::Bytes(bytes) => Ok(bytes),
+            Encoded::Json(_) => Err("expected bytes, got JSON".to_string()),
+        }
+    }
+}
+
+/// From the app to the worker.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Request {
+    pub id: u32,
+    /// The `Task::NAME` of the task.
+    pub task: String,
+    /// The task, as JSON.
+    pub input: String,
+}
+
+/// From the worker to the app.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Response {
+    /// The worker has started and takes requests. Requests sent before this are lost.
+    Ready,
+    Progress {
+        id: u32,
+        done: f64,
+    },
+    Done {
+        id: u32,
+        /// The `Task::Output`, as JSON.
+        output: String,
+    },
+    /// Done, with the `Task::Output` in the `ArrayBuffer` posted along with this response.
+    DoneBytes {
+        id: u32,
+    },
+    /// The request made no sense, like a task the worker doesn't know.
+    Failed {
+        id: u32,
+        error: String,
+    },
+}
+
+/// Calls `progress` at most once per percent, not for every step of a long loop.
+pub fn throttled(progress: &mut dyn FnMut(f64)) -> impl FnMut(f64) + '_ {
+    let mut reported = -1.0;
+    move |done: f64| {
+        if done - reported >= 0.01 || done >= 1.0 {
+            reported = done;
+            progress(done);
+        }
+    }
+}
+
+/// Counts the primes below `below`, by trial division: slow on purpose.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CountPrimes {
+    pub below: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PrimeCount {
+    pub below: u32,
+    pub count: u32,
+    pub largest: Option<u32>,
+}
+
+fn is_prime(n: u32) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+impl Task for CountPrimes {
+    const NAME: &'static str = "count_primes";
+    type Output = PrimeCount;
+
+    fn encode(output: PrimeCount) -> Encoded {
+        Encoded::json(&output)
+    }
+
+    fn decode(encoded: Encoded) -> Result<PrimeCount, String> {
+        encoded.parse_json()
+    }
+
+    fn run(self, progress: &mut dyn FnMut(f64)) -> PrimeCount {
+        let mut progress = throttled(progress);
+        let mut result = PrimeCount {
+            below: self.below,
+            count: 0,
+            largest: None,
+        };
+        for n in 0..self.below {
+            if is_prime(n) {
+                result.count += 1;
+                result.largest = Some(n);
+            }
+            if n % 1024 == 0 {
+                progress(f64::from(n) / f64::from(self.below));
+            }
+        }
+        progress(1.0);
+        result
+    }
+}
+
+/// Renders a `width` by `height` pixel tile of the Mandelbrot set.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MandelbrotTile {
+    /// The point of the complex plane in the middle of the tile.
+    pub center: (f64, f64),
+    /// The width of a pixel on the complex plane.
+    pub scale: f64,
+    pub width: u32,
+    pub height: u32,
+    /// More show more detail at the edge of the set, and take longer.
+    pub max_iterations: u32,
+}
+
+impl Task for MandelbrotTile {
+    const NAME: &'static str = "mandelbrot_tile";
+    /// RGBA, row by row, for an `ImageData`.
+    type Output = Vec<u8>;
+
+    fn encode(output: Vec<u8>) -> Encoded {
+        Encoded::Bytes(output)
+    }
+
+    fn decode(encoded: Encoded) -> Result<Vec<u8>, String> {
+        encoded.into_bytes()
+    }
+
+    fn run(self, progress: &mut dyn FnMut(f64)) -> Vec<u8> {
+        let mut progress = throttled(progress);
+        let mut pixels = Vec::with_capacity((self.width * self.height * 4) as usize);
+        for row in 0..self.height {
+            for column in 0..self.width {
+                let (x, y) = self.point(f64::from(column), f64::from(row));
+                pixels.extend(self.color(self.escape_time(x, y)));
+            }
+            progress(f64::from(row + 1) / f64::from(self.height));
+        }
+        pixels
+    }
+}
+
+impl MandelbrotTile {
+    /// The point of the complex plane at pixel (`column`, `row`) of the tile.
+    pub fn point(&self, column: f64, row: f64) -> (f64, f64) {
+        (
+            self.center.0 + (column - f64::from(self.width) / 2.0) * self.scale,
+            self.center.1 + (row - f64::from(self.height) / 2.0) * self.scale,
+        )
+    }
+
+    /// Iterations until the point leaves the circle of radius 2, `max_iterations` if it doesn't.
+    fn escape_time(&self, x: f64, y: f64) -> u32 {
+        let (mut re, mut im) = (0.0, 0.0);
+        for iteration in 0..self.max_iterations {
+            if re * re + im * im > 4.0 {
+                return iteration;
+            }
+            (re, im) = (re * re - im * im + x, 2.0 * re * im + y);
+        }
+        self.max_iterations
+    }
+
+    fn color(&self, iterations: u32) -> [u8; 4] {
+        if iterations == self.max_iterations {
+            return [0, 0, 0, 255];
+        }
+        // on a log scale, or most of the picture is as dark as the few first iterations
+        let t = f64::from(iterations + 1).ln() / f64::from(self.max_iterations + 1).ln();
+        let channel = |value: f64| (value * 255.0).clamp(0.0, 255.0) as u8;
+        [
+            channel(9.0 * (1.0 - t) * t * t * t),
+            channel(15.0 * (1.0 - t) * (1.0 - t) * t * t),
+            channel(8.5 * (1.0 - t) * (1.0 - t) * (1.0 - t) * t),
+            255,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_primes() {
+        let count = CountPrimes { below: 100 }.run(&mut |_| {});
+        let expected = PrimeCount {
+            below: 100,
+            count: 25,
+            largest: Some(97),
+        };
+        assert_eq!(count, expected);
+        assert_eq!(
+            CountPrimes::decode(CountPrimes::encode(count)),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn tiles_travel_as_bytes() {
+        let tile = MandelbrotTile {
+            center: (-0.6, 0.0),
+            scale: 0.1,
+            width: 8,
+            height: 4,
+            max_iterations: 50,
+        };
+        let pixels = tile.run(&mut |_| {});
+        assert_eq!(pixels.len(), 8 * 4 * 4);
+        let encoded = MandelbrotTile::encode(pixels.clone());
+        assert_eq!(encoded, Encoded::Bytes(pixels.clone()));
+        assert_eq!(MandelbrotTile::decode(encoded), Ok(pixels));
+    }
+
+    #[test]
+    fn outputs_in_the_wrong_encoding_are_rejected() {
+        assert!(MandelbrotTile::decode(Encoded::Json("[]".to_string())).is_err());
+        assert!(CountPrimes::decode(Encoded::Bytes(Vec::new())).is_err());
+    }
+
+    #[test]
+    fn progress_is_reported_at_most_once_per_percent() {
+        let mut reports = Vec::new();
+        let mut report = |done| reports.push(done);
+        let mut progress = throttled(&mut report);
+        for step in 0..=1_000 {
+            progress(f64::from(step) / 1_000.0);
+        }
+        drop(progress);
+        // floating point steps may land a hair short of a percent
+        assert!(
+            (50..=101).contains(&reports.len()),
+            "{} reports",
+            reports.len()
+        );
+        assert_eq!(reports.last(), Some(&1.0));
+    }
+}
@@ -0,0 +1,55 @@
+// The service worker: keeps the app working offline. Registered by `src/pwa.rs`, in release builds.
+//
+// The service-worker post_build hook fills in the build's files and a hash of them, so every
+// build that changes anything is a new service worker, and a new version for the browser.
+
+const BUILD_VERSION = "{{BUILD_VERSION}}";
+const PRECACHE_URLS = {{PRECACHE_URLS}};
+const CACHE = `app-${BUILD_VERSION}`;
+// every route of the app is this page, which the router takes from there
+const APP_SHELL = PRECACHE_URLS.find((url) => url.endsWith("index.html"));
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE)
+      // past the HTTP cache, which may still have files of the previous build under the same name
+      .then((cache) => cache.addAll(PRECACHE_URLS.map((url) => new Request(url, { cache: "reload" })))),
+  );
+  // no skipWaiting() here: the new version waits until the page's "Reload" button asks it to take over
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) => Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key))))
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("message", (event) => {
+  if (event.data === "skip_waiting") {
+    self.skipWaiting();
+  }
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+  // the demo server's streams and anything not ours go straight to the network
+  if (
+    request.method !== "GET" ||
+    url.origin !== self.location.origin ||
+    request.headers.get("Accept") === "text/event-stream"
+  ) {
+    return;
+  }
+
+  if (request.mode === "navigate") {
+    // the latest page when online, the cached one when not
+    event.respondWith(fetch(request).catch(() => caches.match(APP_SHELL)));
+    return;
+  }
+  event.respondWith(caches.match(request).then((cached) => cached || fetch(request)));
+});